@@ -0,0 +1,132 @@
+//! Lint throughput of the `default` preset over synthetic documents, one per YAML shape,
+//! so a regression in any rule's hot path shows up against a stable baseline.
+//!
+//! Run with `cargo bench --bench lint`.
+
+use std::fmt::Write as _;
+use std::hint::black_box;
+use std::path::Path;
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use ryl::config::YamlLintConfig;
+use ryl::lint_str;
+
+const ENTRIES: usize = 2_000;
+
+fn nested_mappings() -> String {
+    let mut out = String::from("---\n");
+    for idx in 0..ENTRIES {
+        writeln!(
+            out,
+            "service-{idx}:\n  name: service {idx}\n  enabled: true\n  limits:\n    \
+             cpu: {idx}m\n    memory: 128Mi  # per replica"
+        )
+        .unwrap();
+    }
+    out
+}
+
+fn block_sequences() -> String {
+    let mut out = String::from("---\nsteps:\n");
+    for idx in 0..ENTRIES {
+        writeln!(
+            out,
+            "  - name: step {idx}\n    args:\n      - --flag-{idx}\n      - \"value {idx}\""
+        )
+        .unwrap();
+    }
+    out
+}
+
+fn block_scalars() -> String {
+    let mut out = String::from("---\n");
+    for idx in 0..ENTRIES {
+        writeln!(
+            out,
+            "script-{idx}: |\n  echo \"start {idx}\"\n  run --step {idx}\nnote-{idx}: >-\n  \
+             folded text for\n  entry {idx}"
+        )
+        .unwrap();
+    }
+    out
+}
+
+fn flow_collections() -> String {
+    let mut out = String::from("---\n");
+    for idx in 0..ENTRIES {
+        writeln!(
+            out,
+            "row-{idx}: {{id: {idx}, tags: [a, b, c], pos: {{x: 1, y: 2}}, empty: []}}"
+        )
+        .unwrap();
+    }
+    out
+}
+
+fn lint_default_preset(c: &mut Criterion) {
+    let cfg = YamlLintConfig::from_yaml_str("extends: default\n")
+        .expect("default preset parses");
+    let path = Path::new("bench.yaml");
+    let base_dir = Path::new(".");
+    let corpus = [
+        ("mappings", nested_mappings()),
+        ("sequences", block_sequences()),
+        ("block-scalars", block_scalars()),
+        ("flow-collections", flow_collections()),
+    ];
+
+    let mut group = c.benchmark_group("lint-default");
+    for (name, content) in &corpus {
+        group.throughput(Throughput::Bytes(content.len() as u64));
+        group.bench_function(*name, |b| {
+            b.iter(|| lint_str(black_box(content), path, &cfg, base_dir));
+        });
+    }
+    group.finish();
+}
+
+const EVENT_RULES: [&str; 15] = [
+    "document-start",
+    "document-end",
+    "commas",
+    "colons",
+    "braces",
+    "brackets",
+    "tags",
+    "octal-values",
+    "float-values",
+    "empty-values",
+    "quoted-strings",
+    "truthy",
+    "key-duplicates",
+    "key-ordering",
+    "merge-keys",
+];
+
+/// Every event-driven rule enabled at once, the case the shared per-buffer parse serves:
+/// each of these rules replays one recorded event stream instead of re-parsing.
+fn lint_event_rules(c: &mut Criterion) {
+    let mut toml = String::from("[rules]\n");
+    for rule in EVENT_RULES {
+        writeln!(toml, "{rule} = 'enable'").unwrap();
+    }
+    let cfg = YamlLintConfig::from_toml_str(&toml).expect("event-rule config parses");
+    let content = nested_mappings();
+
+    let mut group = c.benchmark_group("lint-event-rules");
+    group.throughput(Throughput::Bytes(content.len() as u64));
+    group.bench_function("mappings", |b| {
+        b.iter(|| {
+            lint_str(
+                black_box(&content),
+                Path::new("bench.yaml"),
+                &cfg,
+                Path::new("."),
+            )
+        });
+    });
+    group.finish();
+}
+
+criterion_group!(benches, lint_default_preset, lint_event_rules);
+criterion_main!(benches);
@@ -0,0 +1,203 @@
+//! The `--cache-dir` result cache: one JSON entry per linted file, reused while the file's
+//! modification time and size, its effective config, and the ryl version are unchanged.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::UNIX_EPOCH;
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::config::{SourceKind, YamlLintConfig};
+use crate::decoder;
+use crate::lint::{LintProblem, Severity};
+use crate::pattern_rules::PatternRule;
+use crate::rules::ALL_RULE_IDS;
+
+pub struct ResultCache {
+    dir: PathBuf,
+    hits: AtomicUsize,
+    misses: AtomicUsize,
+}
+
+#[derive(Serialize, Deserialize)]
+struct Entry {
+    key: String,
+    problems: Vec<CachedProblem>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CachedProblem {
+    line: usize,
+    column: usize,
+    level: String,
+    message: String,
+    rule: Option<String>,
+}
+
+impl ResultCache {
+    /// Use `dir` as the cache, creating it if needed.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `dir` cannot be created.
+    pub fn open(dir: &Path) -> Result<Self, String> {
+        fs::create_dir_all(dir).map_err(|err| {
+            format!(
+                "error: failed to create cache directory {}: {err}",
+                dir.display()
+            )
+        })?;
+        Ok(Self {
+            dir: dir.to_path_buf(),
+            hits: AtomicUsize::new(0),
+            misses: AtomicUsize::new(0),
+        })
+    }
+
+    /// `path`'s cached diagnostics when its entry is current, else the result of `lint`,
+    /// which is stored for next time. A read error is never cached, and a failure to read
+    /// or write an entry only costs the cache hit.
+    ///
+    /// # Errors
+    ///
+    /// Returns `lint`'s error.
+    pub fn get_or_lint(
+        &self,
+        path: &Path,
+        base_dir: &Path,
+        cfg: &YamlLintConfig,
+        kind: SourceKind,
+        lint: impl FnOnce() -> Result<Vec<LintProblem>, String>,
+    ) -> Result<Vec<LintProblem>, String> {
+        // Stat before reading: a file edited mid-lint is then stored under its old key and
+        // misses next time, rather than caching stale diagnostics under the new one.
+        let Some((entry, key)) = self.locate(path, base_dir, cfg, kind) else {
+            self.misses.fetch_add(1, Ordering::Relaxed);
+            return lint();
+        };
+        if let Some(problems) = load(&entry, &key, cfg) {
+            self.hits.fetch_add(1, Ordering::Relaxed);
+            return Ok(problems);
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        let result = lint();
+        if let Ok(problems) = &result {
+            store(&entry, key, problems);
+        }
+        result
+    }
+
+    #[must_use]
+    pub fn hits(&self) -> usize {
+        self.hits.load(Ordering::Relaxed)
+    }
+
+    #[must_use]
+    pub fn misses(&self) -> usize {
+        self.misses.load(Ordering::Relaxed)
+    }
+
+    /// The entry file for `path` (named by its absolute path) and the key it must hold.
+    fn locate(
+        &self,
+        path: &Path,
+        base_dir: &Path,
+        cfg: &YamlLintConfig,
+        kind: SourceKind,
+    ) -> Option<(PathBuf, String)> {
+        let meta = fs::metadata(path).ok()?;
+        let mtime = meta.modified().ok()?.duration_since(UNIX_EPOCH).ok()?;
+        let absolute = std::path::absolute(path).ok()?;
+        let entry = self.dir.join(format!(
+            "{}.json",
+            digest(&[absolute.to_string_lossy().as_bytes()])
+        ));
+        let key = digest(&[
+            env!("CARGO_PKG_VERSION").as_bytes(),
+            cfg.to_toml_string().as_bytes(),
+            format!("{:?}", cfg.pattern_rules()).as_bytes(),
+            format!("{kind:?}").as_bytes(),
+            base_dir.to_string_lossy().as_bytes(),
+            decoder::input_encoding_label()
+                .unwrap_or_default()
+                .as_bytes(),
+            &mtime.as_nanos().to_le_bytes(),
+            &meta.len().to_le_bytes(),
+        ]);
+        Some((entry, key))
+    }
+}
+
+fn load(entry: &Path, key: &str, cfg: &YamlLintConfig) -> Option<Vec<LintProblem>> {
+    let entry: Entry = serde_json::from_slice(&fs::read(entry).ok()?).ok()?;
+    if entry.key != key {
+        return None;
+    }
+    entry
+        .problems
+        .into_iter()
+        .map(|problem| {
+            let level = [Severity::Error, Severity::Warning]
+                .into_iter()
+                .find(|level| level.as_str() == problem.level)?;
+            // A rule id the current config cannot produce (a removed `--rulesdir` rule)
+            // makes the whole entry stale.
+            let rule = match problem.rule {
+                Some(name) => Some(
+                    ALL_RULE_IDS
+                        .iter()
+                        .copied()
+                        .chain(cfg.pattern_rules().iter().map(PatternRule::id))
+                        .find(|id| *id == name)?,
+                ),
+                None => None,
+            };
+            Some(LintProblem {
+                line: problem.line,
+                column: problem.column,
+                level,
+                message: problem.message,
+                rule,
+            })
+        })
+        .collect()
+}
+
+fn store(entry: &Path, key: String, problems: &[LintProblem]) {
+    let record = Entry {
+        key,
+        problems: problems
+            .iter()
+            .map(|problem| CachedProblem {
+                line: problem.line,
+                column: problem.column,
+                level: problem.level.as_str().to_string(),
+                message: problem.message.clone(),
+                rule: problem.rule.map(str::to_string),
+            })
+            .collect(),
+    };
+    // Written aside and renamed into place so a concurrent run never reads a partial entry.
+    let staged = entry.with_extension(format!("{}.tmp", std::process::id()));
+    let bytes = serde_json::to_vec(&record).expect("a cache entry always serializes");
+    if fs::write(&staged, bytes).is_ok() {
+        let _ = fs::rename(&staged, entry);
+    }
+}
+
+/// SHA-256 hex over the NUL-separated `parts`.
+fn digest(parts: &[&[u8]]) -> String {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part);
+        hasher.update([0u8]);
+    }
+    let mut out = String::with_capacity(64);
+    for byte in hasher.finalize() {
+        write!(out, "{byte:02x}").expect("writing to a String is infallible");
+    }
+    out
+}
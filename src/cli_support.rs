@@ -4,7 +4,8 @@ use std::fmt::Write as _;
 use std::hash::BuildHasher;
 use std::path::{Path, PathBuf};
 
-use crate::config::{ConfigContext, YamlLintConfig, discover_per_file};
+use crate::LintProblem;
+use crate::config::{ConfigContext, SystemEnv, YamlLintConfig, discover_per_file_with};
 
 /// Replace control characters with a visible `\u{..}` escape, so a crafted key, anchor,
 /// or filename cannot inject terminal escape sequences or, via a newline, a GitHub
@@ -27,6 +28,21 @@ pub fn sanitize_control(text: &str) -> Cow<'_, str> {
     Cow::Owned(out)
 }
 
+/// `path` without its `.` components (`././.yamllint` -> `.yamllint`), for display. `..`
+/// is kept: dropping it lexically could name another file when a parent is a symlink.
+#[must_use]
+pub fn lexical_clean(path: &Path) -> PathBuf {
+    let cleaned: PathBuf = path
+        .components()
+        .filter(|component| *component != std::path::Component::CurDir)
+        .collect();
+    if cleaned.as_os_str().is_empty() {
+        path.to_path_buf()
+    } else {
+        cleaned
+    }
+}
+
 /// Absolute, lexically-normalized form of `path` (`a/../b` -> `b`). Purely lexical:
 /// symlinks are **not** resolved, so a symlink stays distinct from its target (matching
 /// ruff, preserving the `--fix`/`--diff` symlink skip).
@@ -76,6 +92,107 @@ pub fn github_escape(value: &str, property: bool) -> String {
     out
 }
 
+/// A `--format-template` layout, parsed once so each diagnostic renders without
+/// re-validating it. `{path}`, `{line}`, `{col}`, `{level}`, `{message}` and `{rule}` name
+/// the diagnostic's fields; `{{` and `}}` are literal braces.
+#[derive(Clone, Debug)]
+pub struct FormatTemplate {
+    segments: Vec<TemplateSegment>,
+}
+
+#[derive(Clone, Debug)]
+enum TemplateSegment {
+    Literal(String),
+    Path,
+    Line,
+    Col,
+    Level,
+    Message,
+    Rule,
+}
+
+impl FormatTemplate {
+    /// Parse `template` into its literal and placeholder segments.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error naming an unknown placeholder, an unclosed `{`, or a stray `}`.
+    pub fn parse(template: &str) -> Result<Self, String> {
+        let mut segments = Vec::new();
+        let mut literal = String::new();
+        let mut rest = template;
+        while let Some(ch) = rest.chars().next() {
+            rest = &rest[ch.len_utf8()..];
+            match ch {
+                '{' | '}' if rest.starts_with(ch) => {
+                    literal.push(ch);
+                    rest = &rest[1..];
+                }
+                '{' => {
+                    let end = rest
+                        .find('}')
+                        .ok_or("unclosed `{` (write `{{` for a literal brace)")?;
+                    let segment = match &rest[..end] {
+                        "path" => TemplateSegment::Path,
+                        "line" => TemplateSegment::Line,
+                        "col" => TemplateSegment::Col,
+                        "level" => TemplateSegment::Level,
+                        "message" => TemplateSegment::Message,
+                        "rule" => TemplateSegment::Rule,
+                        name => {
+                            return Err(format!(
+                                "unknown placeholder {{{}}} (expected path, line, col, level, \
+                                 message, or rule)",
+                                sanitize_control(name)
+                            ));
+                        }
+                    };
+                    if !literal.is_empty() {
+                        segments.push(TemplateSegment::Literal(std::mem::take(
+                            &mut literal,
+                        )));
+                    }
+                    segments.push(segment);
+                    rest = &rest[end + 1..];
+                }
+                '}' => {
+                    return Err(
+                        "unmatched `}` (write `}}` for a literal brace)".to_string()
+                    );
+                }
+                _ => literal.push(ch),
+            }
+        }
+        if !literal.is_empty() {
+            segments.push(TemplateSegment::Literal(literal));
+        }
+        Ok(Self { segments })
+    }
+
+    /// Render `problem` found in the already-sanitized `path`. The message is sanitized
+    /// here, and a problem with no rule (a syntax error) renders `{rule}` empty.
+    #[must_use]
+    pub fn render(&self, path: &str, problem: &LintProblem) -> String {
+        let mut out = String::new();
+        for segment in &self.segments {
+            match segment {
+                TemplateSegment::Literal(text) => out.push_str(text),
+                TemplateSegment::Path => out.push_str(path),
+                TemplateSegment::Line => write!(out, "{}", problem.line)
+                    .expect("writing to a String is infallible"),
+                TemplateSegment::Col => write!(out, "{}", problem.column)
+                    .expect("writing to a String is infallible"),
+                TemplateSegment::Level => out.push_str(problem.level.as_str()),
+                TemplateSegment::Message => {
+                    out.push_str(&sanitize_control(&problem.message));
+                }
+                TemplateSegment::Rule => out.push_str(problem.rule.unwrap_or_default()),
+            }
+        }
+        out
+    }
+}
+
 /// `display` made relative to `project_root`, forward-slashed, no `./` prefix (GitLab's
 /// requirement), `..` segments for a path outside the root. Control chars are stripped
 /// so a crafted filename cannot inject.
@@ -106,7 +223,8 @@ fn relativize(target: &Path, base: &Path) -> PathBuf {
     relative
 }
 
-/// Resolve the configuration context for `path`, reusing `global_cfg` when present.
+/// Resolve the configuration context for `path`, reusing `global_cfg` when present. A
+/// freshly-discovered config has the `--config-overlay` data (if any) layered on.
 ///
 /// # Errors
 /// Returns an error when configuration discovery fails for `path`.
@@ -114,10 +232,12 @@ pub fn resolve_ctx<S: BuildHasher>(
     path: &Path,
     global_cfg: Option<&ConfigContext>,
     markdown: bool,
+    overlay: Option<&str>,
     cache: &mut HashMap<PathBuf, (PathBuf, YamlLintConfig, bool), S>,
 ) -> Result<(PathBuf, YamlLintConfig, Vec<String>, bool), String> {
-    // The global config is markdown-enabled once by the caller; only a freshly-discovered
-    // config needs enabling, done before caching so the matcher is built once per directory.
+    // The global config is markdown-enabled and overlaid once by the caller; only a
+    // freshly-discovered config needs both, done before caching so the matchers are built
+    // once per directory.
     if let Some(gc) = global_cfg {
         return Ok((
             gc.base_dir.clone(),
@@ -132,8 +252,11 @@ pub fn resolve_ctx<S: BuildHasher>(
     if let Some(entry) = cache.get(&start).cloned() {
         return Ok((entry.0, entry.1, Vec::new(), entry.2));
     }
-    let ctx = discover_per_file(path)?;
+    let ctx = discover_per_file_with(path, &SystemEnv)?;
     let mut cfg = ctx.config;
+    if let Some(data) = overlay {
+        cfg.apply_overlay(data, &ctx.base_dir, &SystemEnv)?;
+    }
     if markdown {
         cfg.enable_default_markdown(&ctx.base_dir);
     }
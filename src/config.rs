@@ -3,8 +3,10 @@ use std::collections::BTreeMap;
 use std::env;
 use std::fs;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 
 use crate::directives::PerLineRuleApply;
+use crate::pattern_rules::PatternRule;
 use crate::yaml_dom::{ScalarOwned, YamlOwned};
 use globset::{Glob, GlobMatcher, escape as glob_escape};
 use ignore::gitignore::{Gitignore, GitignoreBuilder};
@@ -21,7 +23,8 @@ use crate::{conf, decoder};
 
 pub use crate::config_schema::RuleLevel;
 
-/// Bounds `extends` recursion: a cyclic `extends` would otherwise overflow the stack.
+/// Bounds `extends` recursion. A cycle is normally caught by name, but one that re-enters a
+/// file under another path (through a symlink) would otherwise overflow the stack.
 const MAX_EXTENDS_DEPTH: usize = 32;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -144,6 +147,11 @@ pub struct YamlLintConfig {
     output: Option<OutputTable>,
     locale: Option<String>,
     fix: FixConfig,
+    /// `--rulesdir` pattern rules, shared by every per-file clone of the config.
+    pattern_rules: Arc<[PatternRule]>,
+    /// The config file this config was discovered from (`None` for `--config-data`, the
+    /// built-in defaults, or a config built in memory); shown by `--annotate-config`.
+    source: Option<PathBuf>,
 }
 
 const DEFAULT_YAML_FILE_PATTERNS: [&str; 3] = ["*.yaml", "*.yml", ".yamllint"];
@@ -491,6 +499,8 @@ impl Default for YamlLintConfig {
             output: None,
             locale: None,
             fix: FixConfig::default(),
+            pattern_rules: Arc::from([]),
+            source: None,
         }
     }
 }
@@ -505,9 +515,12 @@ impl YamlLintConfig {
     /// Parse configuration data without filesystem access.
     ///
     /// # Errors
-    /// Returns an error when `extends` is used and the config requires filesystem access.
+    /// Returns an error when `extends` is used and the config requires filesystem access,
+    /// or when a rule name is unknown.
     pub fn from_yaml_str(s: &str) -> Result<Self, String> {
-        Self::from_yaml_str_with_env(s, None, None)
+        let cfg = Self::from_yaml_str_with_env(s, None, None, None)?;
+        cfg.reject_unknown_rules()?;
+        Ok(cfg)
     }
 
     /// Parse standalone TOML config text without filesystem access (like
@@ -515,14 +528,17 @@ impl YamlLintConfig {
     /// are not built here; the lint-ready config comes from `discover_config`.
     ///
     /// # Errors
-    /// Returns an error when the TOML is empty or cannot be parsed into a valid config.
+    /// Returns an error when the TOML is empty or cannot be parsed into a valid config, or
+    /// when a rule name is unknown.
     ///
     /// # Panics
     /// Cannot panic in practice: the `None` result is reserved for an absent `[tool.ryl]`
     /// table, which standalone parsing (`pyproject = false`) never produces.
     pub fn from_toml_str(s: &str) -> Result<Self, String> {
-        Self::from_toml_str_with_env(s, None, None, false)
-            .map(|config| config.expect("standalone TOML config is never absent"))
+        let cfg = Self::from_toml_str_with_env(s, None, None, false)?
+            .expect("standalone TOML config is never absent");
+        cfg.reject_unknown_rules()?;
+        Ok(cfg)
     }
 
     fn extend_from_entry(
@@ -530,8 +546,10 @@ impl YamlLintConfig {
         entry: &str,
         envx: Option<&dyn Env>,
         base_dir: &Path,
-        depth: usize,
+        chain: &[PathBuf],
     ) -> Result<(), String> {
+        // Builtins never reach the filesystem and only extend other builtins, so they
+        // cannot close a cycle and stay off `chain`.
         if let Some(builtin) = conf::builtin(entry) {
             let base = Self::from_yaml_str(builtin).expect("builtin preset must parse");
             self.merge_from(base);
@@ -545,6 +563,19 @@ impl YamlLintConfig {
         };
 
         let resolved = resolve_extend_path(entry, envx, Some(base_dir));
+        let identity = extends_identity(&resolved, envx);
+        if chain.contains(&identity) {
+            let cycle: Vec<String> = chain
+                .iter()
+                .chain([&identity])
+                .map(|path| path.display().to_string())
+                .collect();
+            return Err(format!(
+                "invalid config: cyclic extends detected at {} ({})",
+                identity.display(),
+                cycle.join(" -> ")
+            ));
+        }
         if is_toml_path(&resolved) {
             return Err(format!(
                 "invalid config: extends cannot reference TOML configuration {}",
@@ -563,12 +594,10 @@ impl YamlLintConfig {
         let parent_dir = resolved
             .parent()
             .map_or_else(|| base_dir.to_path_buf(), Path::to_path_buf);
-        let base = Self::from_yaml_str_with_env_depth(
-            &data,
-            Some(envx),
-            Some(&parent_dir),
-            depth + 1,
-        )?;
+        let mut chain = chain.to_vec();
+        chain.push(identity);
+        let base =
+            Self::from_yaml_str_in_chain(&data, Some(envx), Some(&parent_dir), &chain)?;
         self.merge_from(base);
         Ok(())
     }
@@ -608,7 +637,8 @@ impl YamlLintConfig {
     /// (e.g. for migration) does not.
     #[must_use]
     pub fn enables_any_rule(&self) -> bool {
-        self.rules.values().any(|rule| rule.level().is_some())
+        !self.pattern_rules.is_empty()
+            || self.rules.values().any(|rule| rule.level().is_some())
     }
 
     #[must_use]
@@ -779,6 +809,50 @@ impl YamlLintConfig {
         }
     }
 
+    /// Layer inline YAML config over this resolved config, as if the overlay `extends` it:
+    /// its rules merge into the existing ones and any other key it sets replaces this
+    /// config's. Backs `--config-overlay`; matchers are rebuilt against `base_dir`, reading
+    /// any `ignore-from-file` through `envx` like discovery does.
+    ///
+    /// # Errors
+    /// Returns an error when the overlay does not parse, uses `extends`, or names an
+    /// unknown rule.
+    pub fn apply_overlay(
+        &mut self,
+        data: &str,
+        base_dir: &Path,
+        envx: &dyn Env,
+    ) -> Result<(), String> {
+        let docs = YamlOwned::load_from_str(data)
+            .map_err(|e| format!("failed to parse config overlay: {e}"))?;
+        let parsed = parse_yaml_config(docs.first().unwrap_or(&YamlOwned::BadValue))?;
+        if !parsed.extends.is_empty() {
+            return Err(
+                "invalid config: a config overlay cannot use extends (it layers onto the \
+                 discovered config)"
+                    .to_string(),
+            );
+        }
+        self.apply_normalized_config(parsed.normalized);
+        self.finalize(envx, base_dir)
+    }
+
+    /// Attach the pattern rules loaded from `--rulesdir`; they run after the built-in rules.
+    pub fn set_pattern_rules(&mut self, rules: Arc<[PatternRule]>) {
+        self.pattern_rules = rules;
+    }
+
+    #[must_use]
+    pub fn pattern_rules(&self) -> &[PatternRule] {
+        &self.pattern_rules
+    }
+
+    /// The config file this config was discovered from, if any.
+    #[must_use]
+    pub fn source(&self) -> Option<&Path> {
+        self.source.as_deref()
+    }
+
     #[must_use]
     pub fn markdown_front_matter(&self) -> bool {
         self.lint_markdown_front_matter
@@ -796,23 +870,31 @@ impl YamlLintConfig {
         self.output.as_ref()
     }
 
+    /// `source` is the file `s` was read from, if any, so an `extends` back to it is
+    /// reported as a cycle.
     fn from_yaml_str_with_env(
         s: &str,
         envx: Option<&dyn Env>,
         base_dir: Option<&Path>,
+        source: Option<&Path>,
     ) -> Result<Self, String> {
-        Self::from_yaml_str_with_env_depth(s, envx, base_dir, 0)
+        let chain: Vec<PathBuf> = source
+            .zip(envx)
+            .map(|(path, envx)| extends_identity(path, envx))
+            .into_iter()
+            .collect();
+        Self::from_yaml_str_in_chain(s, envx, base_dir, &chain)
     }
 
-    /// `depth` tracks `extends` recursion so a cycle is rejected (see
-    /// [`MAX_EXTENDS_DEPTH`]) rather than overflowing the stack.
-    fn from_yaml_str_with_env_depth(
+    /// `chain` holds the files whose `extends` led to `s`, outermost first, so extending
+    /// any of them again is rejected as a cycle rather than recursing.
+    fn from_yaml_str_in_chain(
         s: &str,
         envx: Option<&dyn Env>,
         base_dir: Option<&Path>,
-        depth: usize,
+        chain: &[PathBuf],
     ) -> Result<Self, String> {
-        if depth > MAX_EXTENDS_DEPTH {
+        if chain.len() > MAX_EXTENDS_DEPTH {
             return Err(
                 "invalid config: extends nested too deeply (possible cyclic extends)"
                     .to_string(),
@@ -827,7 +909,7 @@ impl YamlLintConfig {
             docs.first().unwrap_or(&YamlOwned::BadValue),
             envx,
             base_dir,
-            depth,
+            chain,
         )
     }
 
@@ -856,13 +938,13 @@ impl YamlLintConfig {
         doc: &YamlOwned,
         envx: Option<&dyn Env>,
         base_dir: Option<&Path>,
-        depth: usize,
+        chain: &[PathBuf],
     ) -> Result<Self, String> {
         let parsed = parse_yaml_config(doc)?;
         let mut cfg = Self::default();
         let base_path = base_dir.unwrap_or_else(|| Path::new(""));
         for entry in &parsed.extends {
-            cfg.extend_from_entry(entry, envx, base_path, depth)?;
+            cfg.extend_from_entry(entry, envx, base_path, chain)?;
         }
         cfg.apply_normalized_config(parsed.normalized);
 
@@ -951,17 +1033,24 @@ impl YamlLintConfig {
             .expect("serializing TOML Value should not fail")
     }
 
-    fn finalize(&mut self, envx: &dyn Env, base_dir: &Path) -> Result<(), String> {
-        // Reject unknown rule names (matching yamllint's "no such rule"): an unknown rule
-        // is never dispatched by `lint_str`, so without this a typo lints nothing and a
-        // config whose only entries are unknown slips past the "no rules enabled" guard.
-        if let Some(unknown) = self
+    /// Reject unknown rule names (matching yamllint's "no such rule"): an unknown rule is
+    /// never dispatched by `lint_str`, so without this a typo lints nothing and a config
+    /// whose only entries are unknown slips past the "no rules enabled" guard.
+    fn reject_unknown_rules(&self) -> Result<(), String> {
+        match self
             .rule_names
             .iter()
             .find(|name| !crate::rules::ALL_RULE_IDS.contains(&name.as_str()))
         {
-            return Err(format!("invalid config: no such rule: \"{unknown}\""));
+            Some(unknown) => {
+                Err(format!("invalid config: no such rule: \"{unknown}\""))
+            }
+            None => Ok(()),
         }
+    }
+
+    fn finalize(&mut self, envx: &dyn Env, base_dir: &Path) -> Result<(), String> {
+        self.reject_unknown_rules()?;
 
         let (matcher, extra_patterns) = build_ignore_matcher(
             &self.ignore_patterns,
@@ -1137,6 +1226,12 @@ fn resolve_extend_path(
     }
 }
 
+/// The path `extends` cycle detection compares: absolute against the env's working
+/// directory and lexically normalized, so `./a.yml` and `sub/../a.yml` name one file.
+fn extends_identity(path: &Path, envx: &dyn Env) -> PathBuf {
+    crate::cli_support::lexical_abspath(&envx.current_dir().join(path))
+}
+
 fn deep_merge_yaml_owned(dst: &mut YamlOwned, src: &YamlOwned) {
     if let (Some(_), Some(src_map)) = (dst.as_mapping(), src.as_mapping()) {
         for (k, v) in src_map {
@@ -1339,7 +1434,14 @@ fn finalize_context(
     config_found: bool,
 ) -> Result<ConfigContext, String> {
     let base_dir = base_dir.into();
-    cfg.finalize(envx, &base_dir)?;
+    // Discovery joins the start directory as given, so `ryl .` would otherwise name
+    // `././.yamllint` in error prefixes and `--annotate-config` headers.
+    let source = source.map(|path| crate::cli_support::lexical_clean(&path));
+    cfg.finalize(envx, &base_dir).map_err(|err| match &source {
+        Some(path) => config_file_error(path, &err),
+        None => err,
+    })?;
+    cfg.source.clone_from(&source);
     Ok(ConfigContext {
         config: cfg,
         base_dir,
@@ -1376,8 +1478,12 @@ pub fn discover_config_with(
 ) -> Result<ConfigContext, String> {
     if let Some(ref data) = overrides.config_data {
         let base_dir = envx.current_dir();
-        let cfg =
-            YamlLintConfig::from_yaml_str_with_env(data, Some(envx), Some(&base_dir))?;
+        let cfg = YamlLintConfig::from_yaml_str_with_env(
+            data,
+            Some(envx),
+            Some(&base_dir),
+            None,
+        )?;
         return finalize_context(envx, cfg, base_dir, None, Vec::new(), true);
     }
     if let Some(ref file) = overrides.config_file {
@@ -1636,6 +1742,7 @@ fn try_yamllint_user_global_core(
                 &data,
                 Some(envx),
                 Some(base_dir),
+                Some(&p),
             )?;
             finalize_context(
                 envx,
@@ -1678,6 +1785,7 @@ fn load_config_from_path_core(
     allow_missing_pyproject: bool,
 ) -> Result<Option<YamlLintConfig>, String> {
     let data = envx.read_to_string(path)?;
+    let in_file = |err: String| config_file_error(path, &err);
     if path
         .file_name()
         .is_some_and(|name| name == "pyproject.toml")
@@ -1687,7 +1795,8 @@ fn load_config_from_path_core(
             Some(envx),
             Some(base_dir),
             true,
-        )?;
+        )
+        .map_err(in_file)?;
         if cfg.is_none() && !allow_missing_pyproject {
             return Err(format!(
                 "failed to parse config file {}: missing [tool.ryl] section",
@@ -1697,17 +1806,28 @@ fn load_config_from_path_core(
         return Ok(cfg);
     }
     if is_toml_path(path) {
-        let cfg = YamlLintConfig::from_toml_str_with_env(
+        return YamlLintConfig::from_toml_str_with_env(
             &data,
             Some(envx),
             Some(base_dir),
             false,
-        )?;
-        return Ok(cfg);
+        )
+        .map_err(in_file);
     }
-    let cfg =
-        YamlLintConfig::from_yaml_str_with_env(&data, Some(envx), Some(base_dir))?;
-    Ok(Some(cfg))
+    YamlLintConfig::from_yaml_str_with_env(
+        &data,
+        Some(envx),
+        Some(base_dir),
+        Some(path),
+    )
+    .map(Some)
+    .map_err(in_file)
+}
+
+/// Name the config file in `err`: with per-directory discovery, the error alone does not
+/// say which of several configs is at fault.
+fn config_file_error(path: &Path, err: &str) -> String {
+    format!("{}: {err}", path.display())
 }
 
 fn is_toml_path(path: &Path) -> bool {
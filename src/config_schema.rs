@@ -1,3 +1,4 @@
+mod rule_options;
 mod serialization;
 mod validation;
 
@@ -46,6 +47,8 @@ pub struct TomlConfig {
             TomlAnchorsOptions,
             CommentsIndentationOptions,
             TomlHyphensOptions,
+            TomlDocumentEndOptions,
+            TomlCommentsOptions,
         >,
     >,
     #[serde(flatten, default)]
@@ -93,10 +96,20 @@ pub struct OutputTable {
     pub github: Option<OutputDestination>,
     /// One `path:line:col: [level] message (rule)` line per diagnostic.
     pub parsable: Option<OutputDestination>,
+    /// One `path:line:col rule message` line per diagnostic, for pre-commit hook logs.
+    pub concise: Option<OutputDestination>,
     /// `JUnit` XML test report.
     pub junit: Option<OutputDestination>,
     /// `GitLab` Code Quality JSON report.
     pub gitlab: Option<OutputDestination>,
+    /// Code Climate issue JSON report, with a `content.body` per issue.
+    pub codeclimate: Option<OutputDestination>,
+    /// SARIF 2.1.0 log, with rule metadata for each reported rule.
+    pub sarif: Option<OutputDestination>,
+    /// GitHub pull-request review comments (`path`, `line`, `side`, `body`) as JSON.
+    pub review: Option<OutputDestination>,
+    /// A flat JSON array of `{path, line, column, level, rule, message}` problems.
+    pub json: Option<OutputDestination>,
 }
 
 impl OutputTable {
@@ -104,22 +117,27 @@ impl OutputTable {
     /// authoritative enumeration of the table's fields, so validation and the CLI don't
     /// hand-maintain a parallel list.
     #[must_use]
-    pub fn entries(&self) -> [(&'static str, Option<&OutputDestination>); 7] {
+    pub fn entries(&self) -> [(&'static str, Option<&OutputDestination>); 12] {
         [
             ("auto", self.auto.as_ref()),
             ("standard", self.standard.as_ref()),
             ("colored", self.colored.as_ref()),
             ("github", self.github.as_ref()),
             ("parsable", self.parsable.as_ref()),
+            ("concise", self.concise.as_ref()),
             ("junit", self.junit.as_ref()),
             ("gitlab", self.gitlab.as_ref()),
+            ("codeclimate", self.codeclimate.as_ref()),
+            ("sarif", self.sarif.as_ref()),
+            ("review", self.review.as_ref()),
+            ("json", self.json.as_ref()),
         ]
     }
 }
 
 /// Where one format's output goes. An absent `path` means the format's default stream
-/// (stderr for the console formats, stdout for `junit`/`gitlab`); `"-"` means stdout; any
-/// other value is a file path.
+/// (stderr for the console formats, stdout for the report formats);
+/// `"-"` means stdout; any other value is a file path.
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct OutputDestination {
@@ -423,6 +441,8 @@ pub struct RulesTable<
     A = AnchorsOptions,
     C = NoOptions,
     H = HyphensOptions,
+    D = DocumentPresenceOptions,
+    M = CommentsOptions,
 > {
     pub anchors: Option<RuleEntry<A>>,
     #[serde(rename = "block-scalar-chomping")]
@@ -431,11 +451,11 @@ pub struct RulesTable<
     pub brackets: Option<RuleEntry<BraceLikeOptions>>,
     pub colons: Option<RuleEntry<ColonsOptions>>,
     pub commas: Option<RuleEntry<CommasOptions>>,
-    pub comments: Option<RuleEntry<CommentsOptions>>,
+    pub comments: Option<RuleEntry<M>>,
     #[serde(rename = "comments-indentation")]
     pub comments_indentation: Option<RuleEntry<C>>,
     #[serde(rename = "document-end")]
-    pub document_end: Option<RuleEntry<DocumentPresenceOptions>>,
+    pub document_end: Option<RuleEntry<D>>,
     #[serde(rename = "document-start")]
     pub document_start: Option<RuleEntry<DocumentPresenceOptions>>,
     #[serde(rename = "empty-lines")]
@@ -566,12 +586,38 @@ pub struct CommentsOptions {
     pub min_spaces_from_content: Option<i64>,
 }
 
+/// TOML-only `comments` options: the yamllint-compatible ones plus ryl's
+/// `require-content-after-hash`, which has no YAML-config equivalent.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TomlCommentsOptions {
+    #[serde(rename = "require-starting-space")]
+    pub require_starting_space: Option<bool>,
+    #[serde(rename = "ignore-shebangs")]
+    pub ignore_shebangs: Option<bool>,
+    #[serde(rename = "min-spaces-from-content")]
+    pub min_spaces_from_content: Option<i64>,
+    /// Regex the comment text (after the `#` and any spaces) must match.
+    #[serde(rename = "require-content-after-hash")]
+    pub require_content_after_hash: Option<String>,
+}
+
 #[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct DocumentPresenceOptions {
     pub present: Option<bool>,
 }
 
+/// TOML-only `document-end` options: the yamllint-compatible `present` plus ryl's
+/// `last-only`, which has no YAML-config equivalent.
+#[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct TomlDocumentEndOptions {
+    pub present: Option<bool>,
+    #[serde(rename = "last-only")]
+    pub last_only: Option<bool>,
+}
+
 #[derive(Debug, Clone, Default, Deserialize, Serialize, JsonSchema)]
 #[serde(deny_unknown_fields)]
 pub struct EmptyLinesOptions {
@@ -634,11 +680,15 @@ pub struct IndentationOptions {
     pub check_multi_line_strings: Option<bool>,
 }
 
+// `Invalid` catches any other value so validation can name the option, rather than serde
+// reporting only that the whole `indentation` entry matched no variant.
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(untagged)]
 pub enum SpacesSetting {
-    Int(i64),
+    Int(#[schemars(range(min = 1))] i64),
     Mode(SpacesMode),
+    #[schemars(skip)]
+    Invalid(toml::Value),
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
@@ -647,11 +697,14 @@ pub enum SpacesMode {
     Consistent,
 }
 
+// `Invalid` as for `SpacesSetting`.
 #[derive(Debug, Clone, Deserialize, Serialize, JsonSchema)]
 #[serde(untagged)]
 pub enum IndentSequencesSetting {
     Bool(bool),
     Mode(IndentSequencesMode),
+    #[schemars(skip)]
+    Invalid(toml::Value),
 }
 
 #[derive(Debug, Clone, Copy, Deserialize, Serialize, JsonSchema)]
@@ -1082,10 +1135,18 @@ pub fn validate_yaml_config(config: &YamlConfig) -> Result<(), String> {
     )
 }
 
-fn validate_common_config<Q: validation::QuotedStringsOptionSet, K, A, C, H>(
+fn validate_common_config<
+    Q: validation::QuotedStringsOptionSet,
+    K,
+    A,
+    C,
+    H,
+    D,
+    M: validation::CommentsOptionSet,
+>(
     ignore: Option<&StringOrVec>,
     ignore_from_file: Option<&StringOrVec>,
-    rules: Option<&RulesTable<Q, K, A, C, H>>,
+    rules: Option<&RulesTable<Q, K, A, C, H, D, M>>,
 ) -> Result<(), String> {
     if ignore.is_some() && ignore_from_file.is_some() {
         return Err(
@@ -1238,6 +1299,9 @@ fn parse_typed_yaml_config(doc: &YamlOwned) -> Result<YamlConfig, String> {
     }
     let value = yaml_owned_to_toml_value(&YamlOwned::Mapping(map))
         .map_err(|err| format!("failed to parse config data: {err}"))?;
+    if let Some(rules) = value.get("rules") {
+        rule_options::validate_rule_options(rules)?;
+    }
     value
         .try_into::<YamlConfig>()
         .map_err(|err| format!("failed to parse config data: {err}"))
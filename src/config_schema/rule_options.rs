@@ -0,0 +1,263 @@
+//! The yamllint-compatible option names and value kinds of each rule, checked on the raw
+//! YAML config before typed parsing so a misspelled option or a wrong-typed value is
+//! reported by name (as yamllint does) instead of as an unmatched `rules.<rule>` entry.
+
+use toml::Value;
+
+#[derive(Clone, Copy)]
+enum OptionKind {
+    Bool,
+    Int,
+    /// An integer of at least 1, or one of the listed strings.
+    PositiveIntOr(&'static [&'static str]),
+    /// One of the listed strings, or (when `bool` is set) `true`/`false`.
+    Choice {
+        bool: bool,
+        values: &'static [&'static str],
+    },
+    /// A list of strings.
+    StrList,
+    /// A list drawn from the listed strings.
+    Flags(&'static [&'static str]),
+}
+
+use OptionKind::{Bool, Choice, Flags, Int, PositiveIntOr, StrList};
+
+/// Keys every rule accepts besides its own options.
+const COMMON_OPTIONS: [&str; 3] = ["level", "ignore", "ignore-from-file"];
+
+const BRACE_LIKE_OPTIONS: &[(&str, OptionKind)] = &[
+    (
+        "forbid",
+        Choice {
+            bool: true,
+            values: &["non-empty"],
+        },
+    ),
+    ("min-spaces-inside", Int),
+    ("max-spaces-inside", Int),
+    ("min-spaces-inside-empty", Int),
+    ("max-spaces-inside-empty", Int),
+];
+
+const TRUTHY_VALUES: &[&str] = &[
+    "YES", "Yes", "yes", "NO", "No", "no", "TRUE", "True", "true", "FALSE", "False",
+    "false", "ON", "On", "on", "OFF", "Off", "off",
+];
+
+// ryl-only rules are absent: YAML config rejects them outright. The
+// `config_rule_options` tests check this table against `ALL_RULE_IDS` and the typed
+// option structs.
+const RULE_OPTIONS: &[(&str, &[(&str, OptionKind)])] = &[
+    (
+        "anchors",
+        &[
+            ("forbid-undeclared-aliases", Bool),
+            ("forbid-duplicated-anchors", Bool),
+            ("forbid-unused-anchors", Bool),
+        ],
+    ),
+    ("braces", BRACE_LIKE_OPTIONS),
+    ("brackets", BRACE_LIKE_OPTIONS),
+    (
+        "colons",
+        &[("max-spaces-before", Int), ("max-spaces-after", Int)],
+    ),
+    (
+        "commas",
+        &[
+            ("max-spaces-before", Int),
+            ("min-spaces-after", Int),
+            ("max-spaces-after", Int),
+        ],
+    ),
+    (
+        "comments",
+        &[
+            ("require-starting-space", Bool),
+            ("ignore-shebangs", Bool),
+            ("min-spaces-from-content", Int),
+        ],
+    ),
+    ("comments-indentation", &[]),
+    ("document-end", &[("present", Bool)]),
+    ("document-start", &[("present", Bool)]),
+    (
+        "empty-lines",
+        &[("max", Int), ("max-start", Int), ("max-end", Int)],
+    ),
+    (
+        "empty-values",
+        &[
+            ("forbid-in-block-mappings", Bool),
+            ("forbid-in-flow-mappings", Bool),
+            ("forbid-in-block-sequences", Bool),
+        ],
+    ),
+    (
+        "float-values",
+        &[
+            ("require-numeral-before-decimal", Bool),
+            ("forbid-scientific-notation", Bool),
+            ("forbid-nan", Bool),
+            ("forbid-inf", Bool),
+        ],
+    ),
+    ("hyphens", &[("max-spaces-after", Int)]),
+    (
+        "indentation",
+        &[
+            ("spaces", PositiveIntOr(&["consistent"])),
+            (
+                "indent-sequences",
+                Choice {
+                    bool: true,
+                    values: &["whatever", "consistent"],
+                },
+            ),
+            ("check-multi-line-strings", Bool),
+        ],
+    ),
+    ("key-duplicates", &[("forbid-duplicated-merge-keys", Bool)]),
+    ("key-ordering", &[("ignored-keys", StrList)]),
+    (
+        "line-length",
+        &[
+            ("max", Int),
+            ("allow-non-breakable-words", Bool),
+            ("allow-non-breakable-inline-mappings", Bool),
+        ],
+    ),
+    ("new-line-at-end-of-file", &[]),
+    (
+        "new-lines",
+        &[(
+            "type",
+            Choice {
+                bool: false,
+                values: &["unix", "dos", "platform"],
+            },
+        )],
+    ),
+    (
+        "octal-values",
+        &[
+            ("forbid-implicit-octal", Bool),
+            ("forbid-explicit-octal", Bool),
+        ],
+    ),
+    (
+        "quoted-strings",
+        &[
+            (
+                "quote-type",
+                Choice {
+                    bool: false,
+                    values: &["any", "single", "double", "consistent"],
+                },
+            ),
+            (
+                "required",
+                Choice {
+                    bool: true,
+                    values: &["only-when-needed"],
+                },
+            ),
+            ("extra-required", StrList),
+            ("extra-allowed", StrList),
+            ("allow-quoted-quotes", Bool),
+            ("check-keys", Bool),
+        ],
+    ),
+    ("trailing-spaces", &[]),
+    (
+        "truthy",
+        &[
+            ("allowed-values", Flags(TRUTHY_VALUES)),
+            ("check-keys", Bool),
+        ],
+    ),
+];
+
+/// Check each option of each known rule in the raw `rules` table; unknown rules and
+/// non-table entries are left to typed parsing.
+///
+/// # Errors
+/// Returns `invalid config: unknown option "X" for rule "Y"` for an option the rule does
+/// not have, or names the expected kind for a value of the wrong type.
+pub fn validate_rule_options(rules: &Value) -> Result<(), String> {
+    let Some(rules) = rules.as_table() else {
+        return Ok(());
+    };
+    for (rule, options) in RULE_OPTIONS {
+        let Some(conf) = rules.get(*rule).and_then(Value::as_table) else {
+            continue;
+        };
+        for (key, value) in conf {
+            if COMMON_OPTIONS.contains(&key.as_str()) {
+                continue;
+            }
+            let Some((_, kind)) = options.iter().find(|(name, _)| name == key) else {
+                return Err(format!(
+                    "invalid config: unknown option \"{key}\" for rule \"{rule}\""
+                ));
+            };
+            if let Some(expected) = mismatch(*kind, value) {
+                return Err(format!(
+                    "invalid config: option \"{key}\" of \"{rule}\" {expected}"
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// What `value` should have been, if it does not fit `kind`.
+fn mismatch(kind: OptionKind, value: &Value) -> Option<String> {
+    let fits = match kind {
+        Bool => value.is_bool(),
+        Int => value.is_integer(),
+        PositiveIntOr(values) => {
+            value.as_integer().is_some_and(|int| int >= 1) || is_one_of(value, values)
+        }
+        Choice { bool, values } => {
+            (bool && value.is_bool()) || is_one_of(value, values)
+        }
+        StrList => value
+            .as_array()
+            .is_some_and(|items| items.iter().all(Value::is_str)),
+        Flags(values) => value
+            .as_array()
+            .is_some_and(|items| items.iter().all(|item| is_one_of(item, values))),
+    };
+    if fits {
+        return None;
+    }
+    Some(match kind {
+        Bool => "should be bool".to_string(),
+        Int => "should be int".to_string(),
+        PositiveIntOr(values) => {
+            format!("should be a positive integer or {}", quoted(values, " or "))
+        }
+        Choice { bool, values } => {
+            let bools = if bool { "true, false, " } else { "" };
+            format!("should be in ({bools}{})", quoted(values, ", "))
+        }
+        StrList => "should be a list of str".to_string(),
+        Flags(values) => {
+            format!("should only contain values in ({})", quoted(values, ", "))
+        }
+    })
+}
+
+fn is_one_of(value: &Value, values: &[&str]) -> bool {
+    value.as_str().is_some_and(|text| values.contains(&text))
+}
+
+fn quoted(values: &[&str], separator: &str) -> String {
+    values
+        .iter()
+        .map(|value| format!("\"{value}\""))
+        .collect::<Vec<_>>()
+        .join(separator)
+}
@@ -200,8 +200,10 @@ fn normalized_rules_from_table<
     A: Serialize,
     C: Serialize,
     H: Serialize,
+    D: Serialize,
+    M: Serialize,
 >(
-    rules: &RulesTable<Q, K, A, C, H>,
+    rules: &RulesTable<Q, K, A, C, H, D, M>,
 ) -> std::collections::BTreeMap<String, YamlOwned> {
     rules_table_to_value(rules)
         .as_table()
@@ -232,8 +234,10 @@ fn rules_table_to_value<
     A: Serialize,
     C: Serialize,
     H: Serialize,
+    D: Serialize,
+    M: Serialize,
 >(
-    rules: &RulesTable<Q, K, A, C, H>,
+    rules: &RulesTable<Q, K, A, C, H, D, M>,
 ) -> toml::Value {
     let mut table = toml::map::Map::new();
     insert_serialized(&mut table, "anchors", rules.anchors.as_ref());
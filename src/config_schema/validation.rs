@@ -2,9 +2,10 @@ use globset::Glob;
 use regex::Regex;
 
 use super::{
-    KeyOrderingOptions, PerLineIgnore, QuotedStringsOptions, QuotedStringsRequired,
-    QuotedStringsRequiredMode, RuleEntry, RuleOptions, RulesTable,
-    TomlQuotedStringsOptions,
+    CommentsOptions, IndentSequencesSetting, IndentationOptions, KeyOrderingOptions,
+    PerLineIgnore, QuotedStringsOptions, QuotedStringsRequired,
+    QuotedStringsRequiredMode, RuleEntry, RuleOptions, RulesTable, SpacesSetting,
+    TomlCommentsOptions, TomlQuotedStringsOptions,
 };
 
 /// Validate `per-line-ignores` entries: each needs at least one of `regex`/`path`, a
@@ -83,14 +84,73 @@ impl QuotedStringsOptionSet for TomlQuotedStringsOptions {
     }
 }
 
-impl<Q: QuotedStringsOptionSet, K, A, C, H> RulesTable<Q, K, A, C, H> {
+pub trait CommentsOptionSet {
+    fn require_content_after_hash(&self) -> Option<&str>;
+}
+
+impl CommentsOptionSet for CommentsOptions {
+    fn require_content_after_hash(&self) -> Option<&str> {
+        None
+    }
+}
+
+impl CommentsOptionSet for TomlCommentsOptions {
+    fn require_content_after_hash(&self) -> Option<&str> {
+        self.require_content_after_hash.as_deref()
+    }
+}
+
+impl<Q: QuotedStringsOptionSet, K, A, C, H, D, M: CommentsOptionSet>
+    RulesTable<Q, K, A, C, H, D, M>
+{
     pub(super) fn validate(&self) -> Result<(), String> {
+        validate_comments_rule(self.comments.as_ref())?;
+        validate_indentation_rule(self.indentation.as_ref())?;
         validate_key_ordering_rule(self.key_ordering.as_ref())?;
         validate_quoted_strings_rule(self.quoted_strings.as_ref())?;
         Ok(())
     }
 }
 
+fn validate_comments_rule(
+    entry: Option<&RuleEntry<impl CommentsOptionSet>>,
+) -> Result<(), String> {
+    let Some(pattern) = rule_options(entry)
+        .and_then(|options| options.specific.require_content_after_hash())
+    else {
+        return Ok(());
+    };
+    Regex::new(pattern).map(drop).map_err(|err| {
+        format!(
+            "invalid config: option \"require-content-after-hash\" of \"comments\" contains invalid regex '{pattern}': {err}"
+        )
+    })
+}
+
+fn validate_indentation_rule(
+    entry: Option<&RuleEntry<IndentationOptions>>,
+) -> Result<(), String> {
+    let Some(options) = rule_options(entry) else {
+        return Ok(());
+    };
+    if let Some(SpacesSetting::Int(..=0) | SpacesSetting::Invalid(_)) =
+        options.specific.spaces
+    {
+        return Err(
+            "invalid config: option \"spaces\" of \"indentation\" should be a positive integer or \"consistent\""
+                .to_string(),
+        );
+    }
+    if let Some(IndentSequencesSetting::Invalid(_)) = options.specific.indent_sequences
+    {
+        return Err(
+            "invalid config: option \"indent-sequences\" of \"indentation\" should be in (true, false, \"whatever\", \"consistent\")"
+                .to_string(),
+        );
+    }
+    Ok(())
+}
+
 fn validate_key_ordering_rule(
     entry: Option<&RuleEntry<KeyOrderingOptions>>,
 ) -> Result<(), String> {
@@ -1,9 +1,15 @@
 use std::char;
 use std::env;
 use std::path::Path;
+use std::sync::OnceLock;
 
 use encoding_rs::Encoding;
 
+/// The `--input-encoding` label, forced on every input file and stdin (not config files).
+static INPUT_ENCODING: OnceLock<String> = OnceLock::new();
+
+const UTF8_BOM: [u8; 3] = [0xEF, 0xBB, 0xBF];
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum Endian {
     Big,
@@ -27,7 +33,7 @@ impl FileEncoding {
             Self::Utf8 => content.as_bytes().to_vec(),
             Self::Utf8WithBom => {
                 let mut out = Vec::with_capacity(content.len() + 3);
-                out.extend_from_slice(&[0xEF, 0xBB, 0xBF]);
+                out.extend_from_slice(&UTF8_BOM);
                 out.extend_from_slice(content.as_bytes());
                 out
             }
@@ -75,6 +81,33 @@ impl FileEncoding {
     }
 }
 
+/// Where a file read as UTF-8 first stops being valid UTF-8 (1-based line and column).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InvalidUtf8 {
+    pub line: usize,
+    pub column: usize,
+    error: std::str::Utf8Error,
+}
+
+impl InvalidUtf8 {
+    fn locate(bytes: &[u8], err: std::str::Utf8Error) -> Self {
+        let valid = std::str::from_utf8(&bytes[..err.valid_up_to()])
+            .expect("bytes up to `valid_up_to` are valid UTF-8");
+        let line_start = valid.rfind('\n').map_or(0, |idx| idx + 1);
+        Self {
+            line: valid.matches('\n').count() + 1,
+            column: valid[line_start..].chars().count() + 1,
+            error: err,
+        }
+    }
+}
+
+impl std::fmt::Display for InvalidUtf8 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&decode_error("utf-8 data", self.error.to_string()))
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub(crate) struct DecodedFile {
     content: String,
@@ -256,7 +289,7 @@ fn detect_encoding_with_override(
             skip_bom: false,
         });
     }
-    if bytes.starts_with(&[0xEF, 0xBB, 0xBF]) {
+    if bytes.starts_with(&UTF8_BOM) {
         return Ok(FileEncoding::Utf8WithBom);
     }
     Ok(FileEncoding::Utf8)
@@ -269,7 +302,7 @@ fn decode_utf8(bytes: &[u8]) -> Result<String, String> {
 }
 
 fn decode_utf8_bom(bytes: &[u8]) -> Result<String, String> {
-    let sliced = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(bytes);
+    let sliced = bytes.strip_prefix(&UTF8_BOM).unwrap_or(bytes);
     decode_utf8(sliced)
 }
 
@@ -385,6 +418,59 @@ fn decode_bytes_with_encoding(bytes: &[u8]) -> Result<(String, FileEncoding), St
     decode_with_kind(bytes, encoding).map(|s| (s, encoding))
 }
 
+/// Like [`decode_bytes_with_encoding`], but a `--input-encoding` label replaces detection
+/// (BOM sniffing and `YAMLLINT_FILE_ENCODING` alike).
+fn decode_input_with_encoding(bytes: &[u8]) -> Result<(String, FileEncoding), String> {
+    let encoding = input_encoding(bytes)?;
+    decode_with_kind(bytes, encoding).map(|s| (s, encoding))
+}
+
+fn input_encoding(bytes: &[u8]) -> Result<FileEncoding, String> {
+    INPUT_ENCODING.get().map_or_else(
+        || detect_encoding(bytes),
+        |label| parse_override(bytes, label),
+    )
+}
+
+/// [`decode_input_with_encoding`] for an owned buffer: UTF-8 input is validated in place
+/// and becomes the `String` without a second copy of the file. The inner `Err` is input
+/// read as UTF-8 that is not.
+fn decode_owned_input(
+    mut bytes: Vec<u8>,
+) -> Result<Result<(String, FileEncoding), InvalidUtf8>, String> {
+    let encoding = input_encoding(&bytes)?;
+    match encoding {
+        FileEncoding::Utf8WithBom if bytes.starts_with(&UTF8_BOM) => {
+            bytes.drain(..UTF8_BOM.len());
+        }
+        FileEncoding::Utf8 | FileEncoding::Utf8WithBom => {}
+        _ => return decode_with_kind(&bytes, encoding).map(|s| Ok((s, encoding))),
+    }
+    Ok(String::from_utf8(bytes)
+        .map(|content| (content, encoding))
+        .map_err(|err| InvalidUtf8::locate(err.as_bytes(), err.utf8_error())))
+}
+
+/// Force every input read through [`read_file`] or [`decode_input_bytes`] to decode as
+/// `label` (any `YAMLLINT_FILE_ENCODING` label), for the rest of the process.
+///
+/// # Errors
+/// Returns an error string when the label is empty or unsupported, or was already set.
+pub fn set_input_encoding(label: &str) -> Result<(), String> {
+    if label.trim().is_empty() {
+        return Err(decode_error("encoding", "--input-encoding cannot be empty"));
+    }
+    parse_override(&[], label)?;
+    INPUT_ENCODING
+        .set(label.to_string())
+        .map_err(|_| "input encoding is already set".to_string())
+}
+
+/// The label set by [`set_input_encoding`], if any.
+pub(crate) fn input_encoding_label() -> Option<&'static str> {
+    INPUT_ENCODING.get().map(String::as_str)
+}
+
 /// Decode raw bytes using yamllint-compatible encoding detection.
 ///
 /// # Errors
@@ -393,6 +479,14 @@ pub fn decode_bytes(bytes: &[u8]) -> Result<String, String> {
     decode_bytes_with_encoding(bytes).map(|(content, _)| content)
 }
 
+/// Decode input bytes (such as stdin), honouring [`set_input_encoding`].
+///
+/// # Errors
+/// Returns an error string when decoding fails.
+pub fn decode_input_bytes(bytes: &[u8]) -> Result<String, String> {
+    decode_input_with_encoding(bytes).map(|(content, _)| content)
+}
+
 /// Decode bytes with an explicit override, bypassing the environment lookup.
 ///
 /// # Errors
@@ -405,7 +499,8 @@ pub fn decode_bytes_with_override(
     decode_with_kind(bytes, encoding)
 }
 
-/// Read and decode a file using yamllint-compatible detection.
+/// Read and decode a file using yamllint-compatible detection, or the encoding forced by
+/// [`set_input_encoding`].
 ///
 /// # Errors
 /// Returns an error string when the file cannot be read or decoded.
@@ -420,7 +515,31 @@ pub fn read_file(path: &Path) -> Result<String, String> {
 pub(crate) fn read_file_lossless(path: &Path) -> Result<DecodedFile, String> {
     let data = std::fs::read(path)
         .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
-    decode_bytes_with_encoding(&data)
+    decode_owned_input(data)
+        .and_then(|decoded| decoded.map_err(|invalid| invalid.to_string()))
         .map(|(content, encoding)| DecodedFile { content, encoding })
         .map_err(|err| format!("failed to read {}: {err}", path.display()))
 }
+
+/// [`read_file`] for linting: the inner `Err` is a file read as UTF-8 that is not, which
+/// the linter reports as a diagnostic rather than a read error.
+///
+/// # Errors
+/// Returns an error string when the file cannot be read or otherwise decoded.
+pub fn read_file_text(path: &Path) -> Result<Result<String, InvalidUtf8>, String> {
+    let data = std::fs::read(path)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    decode_input_text(data)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))
+}
+
+/// [`decode_input_bytes`] for linting, splitting out input read as UTF-8 that is not, as
+/// [`read_file_text`] does.
+///
+/// # Errors
+/// Returns an error string when decoding fails other than as invalid UTF-8.
+pub fn decode_input_text(
+    bytes: Vec<u8>,
+) -> Result<Result<String, InvalidUtf8>, String> {
+    decode_owned_input(bytes).map(|decoded| decoded.map(|(content, _)| content))
+}
@@ -36,6 +36,9 @@ static RULE_TOKEN: LazyLock<Regex> =
 // mirroring yamllint's `^#\s*yamllint disable-file`.
 static DISABLE_FILE: LazyLock<Regex> =
     LazyLock::new(|| Regex::new(r"^#\s*(?:yamllint|ryl) disable-file\s*$").unwrap());
+// ryl-only (yamllint has no equivalent), so there is no `yamllint` spelling.
+static LINE_LENGTH_MAX: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"^# ryl line-length max: ?(\d+)\s*$").unwrap());
 
 /// Whether the buffer's first line is a `disable-file` directive, which skips the whole
 /// buffer (no diagnostics, not even syntax errors, no `--fix`), matching yamllint. For
@@ -49,6 +52,29 @@ pub fn disables_file(buffer: &str) -> bool {
     DISABLE_FILE.is_match(first_line)
 }
 
+/// The `max` set by a `# ryl line-length max: N` directive in the buffer's leading
+/// comment block (the comment and blank lines before any content), overriding the
+/// configured `line-length` max for this buffer only. The first such directive wins.
+#[must_use]
+pub fn line_length_max(buffer: &str) -> Option<i64> {
+    if !buffer.contains("ryl line-length") {
+        return None;
+    }
+    for (_, content, _) in split_lines_preserve_endings(buffer) {
+        let trimmed = content.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+        if !trimmed.starts_with('#') {
+            return None;
+        }
+        if let Some(caps) = LINE_LENGTH_MAX.captures(trimmed) {
+            return caps[1].parse().ok();
+        }
+    }
+    None
+}
+
 enum Action {
     Disable,
     Enable,
@@ -61,7 +87,7 @@ struct Parsed {
     rules: Option<Vec<&'static str>>,
 }
 
-fn parse_comment(text: &str) -> Option<Parsed> {
+fn parse_comment(text: &str, pattern_rules: &[&'static str]) -> Option<Parsed> {
     let action = if DISABLE_LINE.is_match(text) {
         Action::DisableLine
     } else if DISABLE.is_match(text) {
@@ -75,7 +101,7 @@ fn parse_comment(text: &str) -> Option<Parsed> {
         Some(
             RULE_TOKEN
                 .captures_iter(text)
-                .filter_map(|caps| resolve_rule(&caps[1]))
+                .filter_map(|caps| resolve_rule(&caps[1], pattern_rules))
                 .collect(),
         )
     } else {
@@ -84,10 +110,14 @@ fn parse_comment(text: &str) -> Option<Parsed> {
     Some(Parsed { action, rules })
 }
 
-/// Resolve a `rule:` token to the canonical rule id; an unknown id resolves to nothing
-/// (inert either way), matching yamllint's `if id in all_rules` guard.
-fn resolve_rule(token: &str) -> Option<&'static str> {
-    ALL_RULE_IDS.into_iter().find(|&id| id == token)
+/// Resolve a `rule:` token to the canonical rule id, built-in or `--rulesdir` pattern rule;
+/// an unknown id resolves to nothing (inert either way), matching yamllint's `if id in
+/// all_rules` guard.
+fn resolve_rule(token: &str, pattern_rules: &[&'static str]) -> Option<&'static str> {
+    ALL_RULE_IDS
+        .into_iter()
+        .chain(pattern_rules.iter().copied())
+        .find(|&id| id == token)
 }
 
 /// A resolved `per-line-ignores` entry to layer onto a buffer's directives: suppress
@@ -116,6 +146,10 @@ impl Directives {
     /// comment scan and returns an empty set, so directive-less files pay no parsing cost.
     #[must_use]
     pub fn parse(buffer: &str) -> Self {
+        Self::parse_inline(buffer, &[])
+    }
+
+    fn parse_inline(buffer: &str, pattern_rules: &[&'static str]) -> Self {
         if !buffer.contains("yamllint ") && !buffer.contains("ryl ") {
             return Self::default();
         }
@@ -123,13 +157,13 @@ impl Directives {
         let mut block: HashSet<&'static str> = HashSet::new();
         let mut directives = Self::default();
         for comment in collect_comments(buffer) {
-            let Some(parsed) = parse_comment(&comment.text) else {
+            let Some(parsed) = parse_comment(&comment.text, pattern_rules) else {
                 continue;
             };
             let line = comment.span.start.line();
             match parsed.action {
                 Action::Disable => {
-                    insert_rules(&mut block, parsed.rules.as_deref());
+                    insert_rules(&mut block, parsed.rules.as_deref(), pattern_rules);
                     directives.block_snapshots.push((line, block.clone()));
                 }
                 Action::Enable => {
@@ -152,6 +186,7 @@ impl Directives {
                     insert_rules(
                         directives.line_disabled.entry(target).or_default(),
                         parsed.rules.as_deref(),
+                        pattern_rules,
                     );
                 }
             }
@@ -170,14 +205,26 @@ impl Directives {
         buffer: &str,
         per_line: &[PerLineRuleApply<'_>],
     ) -> Self {
-        let mut directives = Self::parse(buffer);
+        Self::parse_with_pattern_rules(buffer, per_line, &[])
+    }
+
+    /// [`Self::parse_with_per_line`] for a buffer also linted by the `--rulesdir`
+    /// `pattern_rules` (their ids): `rule:` tokens may name them, and a bare directive or
+    /// an `ALL` per-line entry covers them along with the built-in rules.
+    #[must_use]
+    pub fn parse_with_pattern_rules(
+        buffer: &str,
+        per_line: &[PerLineRuleApply<'_>],
+        pattern_rules: &[&'static str],
+    ) -> Self {
+        let mut directives = Self::parse_inline(buffer, pattern_rules);
         if per_line.is_empty() {
             return directives;
         }
         let mut has_regex_entry = false;
         for entry in per_line {
             if entry.regex.is_none() {
-                insert_rules(&mut directives.file_wide, entry.rules);
+                insert_rules(&mut directives.file_wide, entry.rules, pattern_rules);
             } else {
                 has_regex_entry = true;
             }
@@ -189,6 +236,7 @@ impl Directives {
                         insert_rules(
                             directives.line_disabled.entry(index + 1).or_default(),
                             entry.rules,
+                            pattern_rules,
                         );
                     }
                 }
@@ -242,9 +290,17 @@ impl Directives {
     }
 }
 
-fn insert_rules(set: &mut HashSet<&'static str>, rules: Option<&[&'static str]>) {
+fn insert_rules(
+    set: &mut HashSet<&'static str>,
+    rules: Option<&[&'static str]>,
+    pattern_rules: &[&'static str],
+) {
     match rules {
-        None => set.extend(ALL_RULE_IDS),
+        None => set.extend(
+            ALL_RULE_IDS
+                .into_iter()
+                .chain(pattern_rules.iter().copied()),
+        ),
         Some(ids) => set.extend(ids.iter().copied()),
     }
 }
@@ -1,5 +1,7 @@
 use std::ffi::OsStr;
+use std::io::Read;
 use std::path::{Path, PathBuf};
+use std::process::Command;
 // Only the LSP's workspace pull needs this; gate it out of `--no-default-features` so
 // it is not flagged dead.
 #[cfg(feature = "lsp")]
@@ -26,6 +28,62 @@ fn yaml_walker(dir: &Path) -> Walk {
         .build()
 }
 
+/// Files changed between `rev` and `HEAD` (`git diff <rev>...HEAD`), relative to and
+/// limited to the working directory. Deleted files are dropped, having nothing to lint.
+///
+/// # Errors
+///
+/// Returns an error when git cannot be run or the diff fails (e.g. an unknown `rev` or
+/// not inside a git repository).
+pub fn git_changed_files(rev: &str) -> Result<Vec<PathBuf>, String> {
+    let output = Command::new("git")
+        .args(["diff", "--name-only", "-z", "--relative", "--diff-filter=d"])
+        .arg("--end-of-options")
+        .arg(format!("{rev}...HEAD"))
+        .output()
+        .map_err(|err| format!("error: --since requires git on PATH: {err}"))?;
+    if !output.status.success() {
+        // Only git's first line: the rest is usage advice, and the caller's control-char
+        // sanitizing would print each newline as a literal `\u{a}`.
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(format!(
+            "error: --since {rev}: git diff failed: {}",
+            stderr.lines().next().unwrap_or_default().trim()
+        ));
+    }
+    Ok(output
+        .stdout
+        .split(|byte| *byte == 0)
+        .filter(|name| !name.is_empty())
+        .map(|name| PathBuf::from(String::from_utf8_lossy(name).into_owned()))
+        .filter(|path| path.is_file())
+        .collect())
+}
+
+/// Paths listed one per line in `source` (`-` reads stdin), for `--files-from`. Blank
+/// lines and lines starting with `#` are skipped, and a trailing `\r` is dropped so a
+/// CRLF list works too.
+///
+/// # Errors
+///
+/// Returns an error when the list cannot be read or is not valid UTF-8.
+pub fn read_file_list(source: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut text = String::new();
+    let read = if source == Path::new("-") {
+        std::io::stdin().read_to_string(&mut text).map(drop)
+    } else {
+        std::fs::File::open(source)
+            .and_then(|mut file| file.read_to_string(&mut text).map(drop))
+    };
+    read.map_err(|err| format!("error: --files-from {}: {err}", source.display()))?;
+    Ok(text
+        .lines()
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(PathBuf::from)
+        .collect())
+}
+
 #[must_use]
 pub fn gather_yaml_from_dir(dir: &Path) -> Vec<PathBuf> {
     let mut files = Vec::new();
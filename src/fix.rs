@@ -136,7 +136,7 @@ pub struct FixOutcome {
 /// and is not atomic with the write, so a symlinked parent or a TOCTOU swap is not covered. A
 /// complete defense needs `openat`/`O_NOFOLLOW`, which is not portable here.
 fn refuse_symlink(path: &Path, flag: &str) -> bool {
-    if std::fs::symlink_metadata(path).is_ok_and(|meta| meta.file_type().is_symlink()) {
+    if is_symlink(path) {
         eprintln!(
             "skipping {}: refusing to follow a symlink for {flag}",
             crate::cli_support::sanitize_control(&path.display().to_string())
@@ -146,6 +146,31 @@ fn refuse_symlink(path: &Path, flag: &str) -> bool {
     false
 }
 
+fn is_symlink(path: &Path) -> bool {
+    std::fs::symlink_metadata(path).is_ok_and(|meta| meta.file_type().is_symlink())
+}
+
+/// The contents `--fix` would write for `content` read from `path`, or `content` itself
+/// where `--fix` leaves the file alone (a symlink, a parse error, `# yamllint
+/// disable-file`). `--fixable` re-lints this to count the problems the fixes resolve.
+#[must_use]
+pub fn fixed_contents(
+    content: &str,
+    cfg: &YamlLintConfig,
+    path: &Path,
+    base_dir: &Path,
+    kind: SourceKind,
+) -> String {
+    if is_symlink(path) {
+        return content.to_string();
+    }
+    match kind {
+        SourceKind::Yaml => apply_safe_fixes(content, cfg, path, base_dir),
+        SourceKind::Markdown => fix_markdown_str(content, path, cfg, base_dir)
+            .unwrap_or_else(|| content.to_string()),
+    }
+}
+
 /// Apply every safe fix to `path` in place.
 ///
 /// # Errors
@@ -6,6 +6,7 @@
     clippy::cognitive_complexity
 )]
 
+pub mod cache;
 pub mod cli_support;
 pub mod conf;
 pub mod config;
@@ -19,13 +20,21 @@ pub mod lint;
 pub mod lsp;
 pub mod markdown_embed;
 pub mod migrate;
+pub mod pattern_rules;
 pub mod report;
 pub mod rules;
 pub mod yaml_dom;
 
 pub use discover::{gather_yaml_from_dir, is_yaml_path};
-pub use lint::{LintProblem, Severity, lint_file, lint_markdown_file, lint_str};
+pub use lint::{
+    LintProblem, RuleTiming, Severity, lint_file, lint_markdown_file, lint_source,
+    lint_str, lint_str_timed,
+};
 pub use markdown_embed::{
     EmbeddedRegion, MarkdownSources, RegionKind, extract_regions, lint_markdown_str,
+    lint_markdown_str_timed,
+};
+pub use report::{
+    ReportEntry, render_codeclimate, render_gitlab, render_json, render_junit,
+    render_review, render_sarif,
 };
-pub use report::{ReportEntry, render_gitlab, render_junit};
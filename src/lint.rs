@@ -1,7 +1,10 @@
 use std::path::Path;
+use std::time::{Duration, Instant};
 
 use crate::config::{RuleLevel, YamlLintConfig};
-use crate::decoder;
+use crate::decoder::{self, InvalidUtf8};
+use crate::pattern_rules::PatternRule;
+use crate::rules::support::events::ParsedEvents;
 use crate::rules::support::yaml_version;
 use crate::rules::{
     anchors, block_scalar_chomping, braces, brackets, colons, commas, comments,
@@ -50,7 +53,8 @@ impl<'i> granit_parser::EventReceiver<'i> for NullSink {
     fn on_event(&mut self, _ev: granit_parser::Event<'i>) {}
 }
 
-/// Lint a single YAML file and return diagnostics in yamllint format order.
+/// Lint a single YAML file and return diagnostics in yamllint format order. A file that is
+/// not valid UTF-8 yields only [`invalid_utf8_problem`].
 ///
 /// # Errors
 ///
@@ -60,8 +64,10 @@ pub fn lint_file(
     cfg: &YamlLintConfig,
     base_dir: &Path,
 ) -> Result<Vec<LintProblem>, String> {
-    let content = decoder::read_file(path)?;
-    Ok(lint_str(&content, path, cfg, base_dir))
+    Ok(match decoder::read_file_text(path)? {
+        Ok(content) => lint_str(&content, path, cfg, base_dir),
+        Err(invalid) => vec![invalid_utf8_problem(invalid)],
+    })
 }
 
 /// Lint the YAML embedded in a markdown file and return diagnostics whose
@@ -75,25 +81,123 @@ pub fn lint_markdown_file(
     cfg: &YamlLintConfig,
     base_dir: &Path,
 ) -> Result<Vec<LintProblem>, String> {
-    let content = decoder::read_file(path)?;
-    Ok(crate::markdown_embed::lint_markdown_str(
-        &content, path, cfg, base_dir,
-    ))
+    Ok(match decoder::read_file_text(path)? {
+        Ok(content) => {
+            crate::markdown_embed::lint_markdown_str(&content, path, cfg, base_dir)
+        }
+        Err(invalid) => vec![invalid_utf8_problem(invalid)],
+    })
+}
+
+/// The lone diagnostic for a file read as UTF-8 that is not, at its first invalid byte.
+#[must_use]
+pub fn invalid_utf8_problem(invalid: InvalidUtf8) -> LintProblem {
+    LintProblem {
+        line: invalid.line,
+        column: invalid.column,
+        level: Severity::Error,
+        message: "file is not valid UTF-8".to_string(),
+        rule: None,
+    }
+}
+
+/// How long one rule's `check` took on one linted source.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleTiming {
+    pub rule: &'static str,
+    pub duration: Duration,
+}
+
+/// The diagnostics [`lint_rule!`] gathers, plus each rule's duration when profiling.
+struct Collector<'t> {
+    problems: Vec<LintProblem>,
+    timings: Option<&'t mut Vec<RuleTiming>>,
+}
+
+impl Collector<'_> {
+    fn time<T>(&mut self, rule: &'static str, check: impl FnOnce() -> T) -> T {
+        let Some(timings) = self.timings.as_deref_mut() else {
+            return check();
+        };
+        let started = Instant::now();
+        let result = check();
+        timings.push(RuleTiming {
+            rule,
+            duration: started.elapsed(),
+        });
+        result
+    }
 }
 
 /// Run one rule under the standard gate (skip a disabled rule or a per-rule-ignored file)
 /// and append a [`LintProblem`] per violation in the rule's own report order. The arms
 /// cover the shapes rules have: a resolved `&Config` or none, a `Vec` or `Option` of
 /// violations, and a per-violation message or a fixed module `MESSAGE`. `$m` is the rule
-/// module; its `ID` / `check` / `Config` / `MESSAGE` are reached through it.
+/// module; its `ID` / `check` / `Config` / `MESSAGE` are reached through it. The `events`
+/// arms call `$m::check_events` with the given leading arguments instead, so an
+/// event-driven rule replays the buffer's shared [`ParsedEvents`] rather than re-parsing.
 macro_rules! lint_rule {
     // config, `Vec<Violation>`, per-violation message (the common rule shape)
     ($d:ident, $cfg:expr, $content:expr, $path:expr, $base:expr, $m:ident) => {
         if let Some(level) = $cfg.rule_level($m::ID)
             && !$cfg.is_rule_ignored($m::ID, $path, $base)
         {
-            for hit in $m::check($content, &$m::Config::resolve($cfg)) {
-                $d.push(LintProblem {
+            let config = $m::Config::resolve($cfg);
+            let hits = $d.time($m::ID, || $m::check($content, &config));
+            for hit in hits {
+                $d.problems.push(LintProblem {
+                    line: hit.line,
+                    column: hit.column,
+                    level: level.into(),
+                    message: hit.message,
+                    rule: Some($m::ID),
+                });
+            }
+        }
+    };
+    // config, `Vec<Violation>`, per-violation message, over the shared parse
+    ($d:ident, $cfg:expr, $path:expr, $base:expr, $m:ident, events($($arg:expr),+)) => {
+        if let Some(level) = $cfg.rule_level($m::ID)
+            && !$cfg.is_rule_ignored($m::ID, $path, $base)
+        {
+            let config = $m::Config::resolve($cfg);
+            let hits = $d.time($m::ID, || $m::check_events($($arg,)+ &config));
+            for hit in hits {
+                $d.problems.push(LintProblem {
+                    line: hit.line,
+                    column: hit.column,
+                    level: level.into(),
+                    message: hit.message,
+                    rule: Some($m::ID),
+                });
+            }
+        }
+    };
+    // `Copy` config by value, `Vec<Violation>`, per-violation message, over the shared parse
+    ($d:ident, $cfg:expr, $path:expr, $base:expr, $m:ident, copy_config, events($($arg:expr),+)) => {
+        if let Some(level) = $cfg.rule_level($m::ID)
+            && !$cfg.is_rule_ignored($m::ID, $path, $base)
+        {
+            let config = $m::Config::resolve($cfg);
+            let hits = $d.time($m::ID, || $m::check_events($($arg,)+ config));
+            for hit in hits {
+                $d.problems.push(LintProblem {
+                    line: hit.line,
+                    column: hit.column,
+                    level: level.into(),
+                    message: hit.message,
+                    rule: Some($m::ID),
+                });
+            }
+        }
+    };
+    // no config, `Vec<Violation>`, per-violation message, over the shared parse
+    ($d:ident, $cfg:expr, $path:expr, $base:expr, $m:ident, no_config, events($($arg:expr),+)) => {
+        if let Some(level) = $cfg.rule_level($m::ID)
+            && !$cfg.is_rule_ignored($m::ID, $path, $base)
+        {
+            for hit in $d.time($m::ID, || $m::check_events($($arg),+)) {
+                $d.problems.push(LintProblem {
                     line: hit.line,
                     column: hit.column,
                     level: level.into(),
@@ -108,8 +212,10 @@ macro_rules! lint_rule {
         if let Some(level) = $cfg.rule_level($m::ID)
             && !$cfg.is_rule_ignored($m::ID, $path, $base)
         {
-            for hit in $m::check($content, &$m::Config::resolve($cfg)) {
-                $d.push(LintProblem {
+            let config = $m::Config::resolve($cfg);
+            let hits = $d.time($m::ID, || $m::check($content, &config));
+            for hit in hits {
+                $d.problems.push(LintProblem {
                     line: hit.line,
                     column: hit.column,
                     level: level.into(),
@@ -124,8 +230,8 @@ macro_rules! lint_rule {
         if let Some(level) = $cfg.rule_level($m::ID)
             && !$cfg.is_rule_ignored($m::ID, $path, $base)
         {
-            for hit in $m::check($content) {
-                $d.push(LintProblem {
+            for hit in $d.time($m::ID, || $m::check($content)) {
+                $d.problems.push(LintProblem {
                     line: hit.line,
                     column: hit.column,
                     level: level.into(),
@@ -140,8 +246,8 @@ macro_rules! lint_rule {
         if let Some(level) = $cfg.rule_level($m::ID)
             && !$cfg.is_rule_ignored($m::ID, $path, $base)
         {
-            for hit in $m::check($content) {
-                $d.push(LintProblem {
+            for hit in $d.time($m::ID, || $m::check($content)) {
+                $d.problems.push(LintProblem {
                     line: hit.line,
                     column: hit.column,
                     level: level.into(),
@@ -155,9 +261,9 @@ macro_rules! lint_rule {
     ($d:ident, $cfg:expr, $content:expr, $path:expr, $base:expr, $m:ident, option, message) => {
         if let Some(level) = $cfg.rule_level($m::ID)
             && !$cfg.is_rule_ignored($m::ID, $path, $base)
-            && let Some(hit) = $m::check($content)
+            && let Some(hit) = $d.time($m::ID, || $m::check($content))
         {
-            $d.push(LintProblem {
+            $d.problems.push(LintProblem {
                 line: hit.line,
                 column: hit.column,
                 level: level.into(),
@@ -171,10 +277,11 @@ macro_rules! lint_rule {
     ($d:ident, $cfg:expr, $content:expr, $path:expr, $base:expr, $m:ident, platform) => {
         if let Some(level) = $cfg.rule_level($m::ID)
             && !$cfg.is_rule_ignored($m::ID, $path, $base)
-            && let Some(hit) =
+            && let Some(hit) = $d.time($m::ID, || {
                 $m::check($content, $m::Config::resolve($cfg), $m::platform_newline())
+            })
         {
-            $d.push(LintProblem {
+            $d.problems.push(LintProblem {
                 line: hit.line,
                 column: hit.column,
                 level: level.into(),
@@ -192,15 +299,32 @@ macro_rules! lint_rule {
 // suite guards it.
 
 /// Document-shape and layout / punctuation rules (first dispatch batch).
-fn collect_layout_diagnostics(
-    diagnostics: &mut Vec<LintProblem>,
-    content: &str,
+fn collect_layout_diagnostics<'input>(
+    diagnostics: &mut Collector,
+    content: &'input str,
+    events: &ParsedEvents<'input>,
     cfg: &YamlLintConfig,
     path: &Path,
     base_dir: &Path,
 ) {
-    lint_rule!(diagnostics, cfg, content, path, base_dir, document_start);
-    lint_rule!(diagnostics, cfg, content, path, base_dir, document_end);
+    lint_rule!(
+        diagnostics,
+        cfg,
+        path,
+        base_dir,
+        document_start,
+        copy_config,
+        events(events)
+    );
+    lint_rule!(
+        diagnostics,
+        cfg,
+        path,
+        base_dir,
+        document_end,
+        copy_config,
+        events(content, events)
+    );
     lint_rule!(
         diagnostics,
         cfg,
@@ -221,40 +345,121 @@ fn collect_layout_diagnostics(
         platform
     );
     lint_rule!(diagnostics, cfg, content, path, base_dir, empty_lines);
-    lint_rule!(diagnostics, cfg, content, path, base_dir, commas);
-    lint_rule!(diagnostics, cfg, content, path, base_dir, colons);
-    lint_rule!(diagnostics, cfg, content, path, base_dir, braces);
-    lint_rule!(diagnostics, cfg, content, path, base_dir, brackets);
+    lint_rule!(
+        diagnostics,
+        cfg,
+        path,
+        base_dir,
+        commas,
+        events(content, events)
+    );
+    lint_rule!(
+        diagnostics,
+        cfg,
+        path,
+        base_dir,
+        colons,
+        events(content, events)
+    );
+    lint_rule!(
+        diagnostics,
+        cfg,
+        path,
+        base_dir,
+        braces,
+        events(content, events)
+    );
+    lint_rule!(
+        diagnostics,
+        cfg,
+        path,
+        base_dir,
+        brackets,
+        events(content, events)
+    );
 }
 
 /// Comment, node-property, and scalar-value rules (second dispatch batch).
-fn collect_value_diagnostics(
-    diagnostics: &mut Vec<LintProblem>,
-    content: &str,
+fn collect_value_diagnostics<'input>(
+    diagnostics: &mut Collector,
+    content: &'input str,
+    events: &ParsedEvents<'input>,
     cfg: &YamlLintConfig,
     path: &Path,
     base_dir: &Path,
 ) {
     lint_rule!(diagnostics, cfg, content, path, base_dir, comments);
     lint_rule!(diagnostics, cfg, content, path, base_dir, anchors);
-    lint_rule!(diagnostics, cfg, content, path, base_dir, tags);
-    lint_rule!(diagnostics, cfg, content, path, base_dir, octal_values);
-    lint_rule!(diagnostics, cfg, content, path, base_dir, float_values);
-    lint_rule!(diagnostics, cfg, content, path, base_dir, empty_values);
-    lint_rule!(diagnostics, cfg, content, path, base_dir, quoted_strings);
-    lint_rule!(diagnostics, cfg, content, path, base_dir, truthy);
+    lint_rule!(diagnostics, cfg, path, base_dir, tags, events(events));
+    lint_rule!(
+        diagnostics,
+        cfg,
+        path,
+        base_dir,
+        octal_values,
+        events(events)
+    );
+    lint_rule!(
+        diagnostics,
+        cfg,
+        path,
+        base_dir,
+        float_values,
+        events(content, events)
+    );
+    lint_rule!(
+        diagnostics,
+        cfg,
+        path,
+        base_dir,
+        empty_values,
+        copy_config,
+        events(content, events)
+    );
+    lint_rule!(
+        diagnostics,
+        cfg,
+        path,
+        base_dir,
+        quoted_strings,
+        events(content, events)
+    );
+    lint_rule!(
+        diagnostics,
+        cfg,
+        path,
+        base_dir,
+        truthy,
+        events(content, events)
+    );
 }
 
 /// Key, indentation, and line / whitespace rules (third dispatch batch).
-fn collect_block_diagnostics(
-    diagnostics: &mut Vec<LintProblem>,
-    content: &str,
+fn collect_block_diagnostics<'input>(
+    diagnostics: &mut Collector,
+    content: &'input str,
+    events: &ParsedEvents<'input>,
     cfg: &YamlLintConfig,
     path: &Path,
     base_dir: &Path,
 ) {
-    lint_rule!(diagnostics, cfg, content, path, base_dir, key_duplicates);
-    lint_rule!(diagnostics, cfg, content, path, base_dir, key_ordering);
+    lint_rule!(
+        diagnostics,
+        cfg,
+        path,
+        base_dir,
+        key_duplicates,
+        copy_config,
+        events(events)
+    );
+    lint_rule!(
+        diagnostics,
+        cfg,
+        path,
+        base_dir,
+        key_ordering,
+        events(events)
+    );
     lint_rule!(diagnostics, cfg, content, path, base_dir, hyphens);
     lint_rule!(
         diagnostics,
@@ -289,11 +494,11 @@ fn collect_block_diagnostics(
     lint_rule!(
         diagnostics,
         cfg,
-        content,
         path,
         base_dir,
         merge_keys,
-        no_config
+        no_config,
+        events(events)
     );
     lint_rule!(
         diagnostics,
@@ -319,18 +524,79 @@ pub fn lint_str(
     cfg: &YamlLintConfig,
     base_dir: &Path,
 ) -> Vec<LintProblem> {
+    lint_str_with(content, path, cfg, base_dir, None)
+}
+
+/// Lint an in-memory YAML buffer that has no file behind it, such as an unsaved
+/// editor buffer.
+///
+/// Path-scoped settings (`ignore` patterns, per-file rule ignores) see the buffer
+/// as `-` relative to the current directory; use [`lint_str`] to lint it under a
+/// real name.
+///
+/// ```
+/// use ryl::config::YamlLintConfig;
+/// use ryl::lint_source;
+///
+/// let cfg = YamlLintConfig::from_yaml_str("rules: {trailing-spaces: enable}").unwrap();
+/// let problems = lint_source("key: value \n", &cfg);
+/// assert_eq!((problems[0].line, problems[0].column), (1, 11));
+/// assert_eq!(problems[0].rule, Some("trailing-spaces"));
+/// ```
+#[must_use]
+pub fn lint_source(content: &str, cfg: &YamlLintConfig) -> Vec<LintProblem> {
+    lint_str(content, Path::new("-"), cfg, Path::new("."))
+}
+
+/// [`lint_str`], also appending to `timings` how long each rule that ran took.
+#[must_use]
+pub fn lint_str_timed(
+    content: &str,
+    path: &Path,
+    cfg: &YamlLintConfig,
+    base_dir: &Path,
+    timings: &mut Vec<RuleTiming>,
+) -> Vec<LintProblem> {
+    lint_str_with(content, path, cfg, base_dir, Some(timings))
+}
+
+pub(crate) fn lint_str_with(
+    content: &str,
+    path: &Path,
+    cfg: &YamlLintConfig,
+    base_dir: &Path,
+    timings: Option<&mut Vec<RuleTiming>>,
+) -> Vec<LintProblem> {
+    // `read_file` already drops a UTF-8 BOM; one left in caller-decoded text would shift
+    // every line-1 column and hide a first-line `disable-file` directive.
+    let content = content.strip_prefix('\u{feff}').unwrap_or(content);
     if crate::directives::disables_file(content) {
         return Vec::new();
     }
 
-    let mut diagnostics: Vec<LintProblem> = Vec::new();
-    collect_layout_diagnostics(&mut diagnostics, content, cfg, path, base_dir);
-    collect_value_diagnostics(&mut diagnostics, content, cfg, path, base_dir);
-    collect_block_diagnostics(&mut diagnostics, content, cfg, path, base_dir);
+    // One parse feeds every event-driven rule and the syntax check below.
+    let events = ParsedEvents::parse(content);
+    let mut collector = Collector {
+        problems: Vec::new(),
+        timings,
+    };
+    collect_layout_diagnostics(&mut collector, content, &events, cfg, path, base_dir);
+    collect_value_diagnostics(&mut collector, content, &events, cfg, path, base_dir);
+    collect_block_diagnostics(&mut collector, content, &events, cfg, path, base_dir);
+    for rule in cfg.pattern_rules() {
+        let hits = collector.time(rule.id(), || rule.check(content, &events));
+        collector.problems.extend(hits);
+    }
+    let mut diagnostics = collector.problems;
 
     let per_line = cfg.per_line_applies(path, base_dir);
-    let directives =
-        crate::directives::Directives::parse_with_per_line(content, &per_line);
+    let pattern_ids: Vec<&'static str> =
+        cfg.pattern_rules().iter().map(PatternRule::id).collect();
+    let directives = crate::directives::Directives::parse_with_pattern_rules(
+        content,
+        &per_line,
+        &pattern_ids,
+    );
     diagnostics.retain(|problem| {
         !problem
             .rule
@@ -341,7 +607,7 @@ pub fn lint_str(
         diagnostics.push(warning);
     }
 
-    if let Some(syntax) = syntax_diagnostic(content) {
+    if let Some(syntax) = syntax_diagnostic(content, &events) {
         diagnostics.clear();
         diagnostics.push(syntax);
     }
@@ -407,11 +673,11 @@ fn higher_minor_version_warning(content: &str) -> Option<LintProblem> {
 /// The syntax error ryl reports for `content` during linting, or `None` if it lints
 /// cleanly. Suppresses granit's undefined-alias error (ryl reports that via the `anchors`
 /// rule, matching yamllint).
-fn syntax_diagnostic(content: &str) -> Option<LintProblem> {
+fn syntax_diagnostic(content: &str, events: &ParsedEvents<'_>) -> Option<LintProblem> {
     if let Some(problem) = unsupported_version_error(content) {
         return Some(problem);
     }
-    match scan(content) {
+    match events.outcome() {
         Ok(()) => None,
         Err(err) if err.info() == "while parsing node, found unknown anchor" => {
             // The parser halts at the tolerated undefined alias, masking any later lexical
@@ -420,7 +686,7 @@ fn syntax_diagnostic(content: &str) -> Option<LintProblem> {
             // alias is the only problem (reported via the `anchors` rule).
             scanner_error(content).map(|err| syntax_problem(&err))
         }
-        Err(err) => Some(syntax_problem(&err)),
+        Err(err) => Some(syntax_problem(err)),
     }
 }
 
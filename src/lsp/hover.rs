@@ -7,8 +7,7 @@ use lsp_types::{
 };
 
 use crate::lsp::encoding::range_contains;
-
-const RULES_URL: &str = "https://ryl-docs.pages.dev/rules/";
+use crate::rules::RULES_DOCS_URL;
 
 /// Build a hover for `position` from the document's `diagnostics`, or `None` when no
 /// diagnostic covers it. The hover range is the first match so the editor highlights
@@ -25,7 +24,7 @@ pub fn hover(diagnostics: &[Diagnostic], position: Position) -> Option<Hover> {
         body.push_str(&section(diagnostic));
     }
     body.push_str("\n\n[Rule reference](");
-    body.push_str(RULES_URL);
+    body.push_str(RULES_DOCS_URL);
     body.push(')');
     Some(Hover {
         contents: HoverContents::Markup(MarkupContent {
@@ -6,18 +6,24 @@
     clippy::cognitive_complexity
 )]
 
+use std::borrow::Cow;
+use std::collections::BTreeMap;
 use std::collections::HashMap;
 use std::collections::HashSet;
 use std::fs::File;
 use std::io::{BufWriter, IsTerminal, Read, Write};
 use std::path::{Path, PathBuf};
 use std::process::ExitCode;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 use clap::{ArgMatches, CommandFactory, FromArgMatches, Parser, ValueEnum};
 use ignore::WalkBuilder;
 use rayon::prelude::*;
+use ryl::cache::ResultCache;
 use ryl::cli_support::{
-    github_escape, lexical_abspath, report_display_path, resolve_ctx, sanitize_control,
+    FormatTemplate, github_escape, lexical_abspath, report_display_path, resolve_ctx,
+    sanitize_control,
 };
 use ryl::config::{
     ConfigContext, Overrides, SourceKind, SystemEnv, YamlLintConfig, discover_config,
@@ -27,16 +33,24 @@ use ryl::config_schema::{
     OutputDestination, OutputTable, schema_string_pretty, yaml_schema_string_pretty,
 };
 use ryl::decoder;
+use ryl::discover::{git_changed_files, read_file_list};
 use ryl::fix::{
     DiffStats, apply_safe_fixes_to_files, diff_outcome, diff_safe_fixes_for_files,
 };
+use ryl::lint::invalid_utf8_problem;
 use ryl::migrate::{
     MigrateOptions, OutputMode as MigrateOutputMode, SourceCleanup,
     UserConfigMigration, WriteMode, migrate_configs,
 };
-use ryl::report::{ReportEntry, render_gitlab, render_junit};
+use ryl::pattern_rules::{PatternRule, load_rules_dir};
+use ryl::report::{
+    ReportEntry, render_codeclimate, render_gitlab, render_json, render_junit,
+    render_review, render_sarif,
+};
+use ryl::rules::{ALL_RULE_IDS, rule_docs_url};
 use ryl::{
-    LintProblem, Severity, lint_file, lint_markdown_file, lint_markdown_str, lint_str,
+    LintProblem, RuleTiming, Severity, lint_file, lint_markdown_file,
+    lint_markdown_str, lint_markdown_str_timed, lint_str, lint_str_timed,
 };
 use same_file::Handle;
 
@@ -100,6 +114,38 @@ fn cli_overrides(args: &LintArgs) -> Overrides {
     }
 }
 
+/// `discover_config` for the run's `-c`/`-d` overrides, with any `--config-overlay` data
+/// layered on the result.
+///
+/// # Errors
+///
+/// Returns an error if discovery fails or the overlay is invalid.
+fn discover_overlaid_config(
+    inputs: &[PathBuf],
+    args: &LintArgs,
+) -> Result<ConfigContext, String> {
+    let mut ctx = discover_config(inputs, &cli_overrides(args))?;
+    if let Some(data) = &args.config_overlay {
+        ctx.config.apply_overlay(data, &ctx.base_dir, &SystemEnv)?;
+    }
+    Ok(ctx)
+}
+
+/// The `--rulesdir` pattern rules, loaded once and shared by every file's config (empty
+/// without the flag).
+///
+/// # Errors
+///
+/// Returns an error if the directory or one of its rule files cannot be loaded.
+fn load_pattern_rules(args: &LintArgs) -> Result<Arc<[PatternRule]>, String> {
+    match &args.rulesdir {
+        Some(dir) => load_rules_dir(dir)
+            .map(Arc::from)
+            .map_err(|err| format!("error: {}", sanitize_control(&err))),
+        None => Ok(Arc::from([])),
+    }
+}
+
 fn build_global_cfg(
     inputs: &[PathBuf],
     args: &LintArgs,
@@ -108,7 +154,7 @@ fn build_global_cfg(
         || args.config_file.is_some()
         || std::env::var("YAMLLINT_CONFIG_FILE").is_ok()
     {
-        discover_config(inputs, &cli_overrides(args)).map(Some)
+        discover_overlaid_config(inputs, args).map(Some)
     } else {
         Ok(None)
     }
@@ -131,7 +177,7 @@ fn run_output_config(
     if let Some(ctx) = global_cfg {
         return Ok(ctx.config.output().cloned());
     }
-    Ok(discover_config(inputs, &cli_overrides(args))?
+    Ok(discover_overlaid_config(inputs, args)?
         .config
         .output()
         .cloned())
@@ -225,8 +271,14 @@ enum CliFormat {
     Colored,
     Github,
     Parsable,
+    Concise,
     Junit,
     Gitlab,
+    #[value(name = "codeclimate")]
+    CodeClimate,
+    Sarif,
+    Review,
+    Json,
 }
 
 #[derive(Parser, Debug)]
@@ -296,7 +348,7 @@ struct Cli {
 #[derive(clap::Subcommand, Debug)]
 enum Commands {
     /// Lint YAML inputs (the explicit form of bare `ryl <paths>`)
-    Check(LintArgs),
+    Check(Box<LintArgs>),
     /// Run the language server (LSP) over stdio for editor integration
     #[cfg(feature = "lsp")]
     Server,
@@ -305,6 +357,8 @@ enum Commands {
 /// The lint pass arguments, flattened both at the top level (bare `ryl <paths>`) and under
 /// `ryl check`, so the two forms are byte-for-byte equivalent.
 #[derive(clap::Args, Debug, Default)]
+// These are independent toggles, not state better modeled as an enum.
+#[allow(clippy::struct_excessive_bools)]
 struct LintArgs {
     /// One or more paths: files and/or directories, or `-` to read from stdin
     #[arg(value_name = "PATH_OR_FILE")]
@@ -314,6 +368,20 @@ struct LintArgs {
     #[arg(long = "stdin-filename", value_name = "FILE")]
     stdin_filename: Option<PathBuf>,
 
+    /// Lint only the files changed between REF and HEAD (`git diff REF...HEAD`) under the
+    /// current directory, instead of `PATH_OR_FILE` inputs
+    #[arg(
+        long = "since",
+        value_name = "REF",
+        conflicts_with_all = ["inputs", "stdin_filename"]
+    )]
+    since: Option<String>,
+
+    /// Also lint the paths listed one per line in FILE (`-` for stdin), skipping blank and
+    /// `#` lines; like walked files, they are filtered by `yaml-files` and `ignore`
+    #[arg(long = "files-from", value_name = "FILE", conflicts_with = "since")]
+    files_from: Option<PathBuf>,
+
     /// Path to configuration file (YAML or TOML)
     #[arg(short = 'c', long = "config-file", value_name = "FILE")]
     config_file: Option<PathBuf>,
@@ -322,15 +390,36 @@ struct LintArgs {
     #[arg(short = 'd', long = "config-data", value_name = "YAML")]
     config_data: Option<String>,
 
-    /// Output format (auto, standard, colored, github, parsable, junit, gitlab). Repeatable:
-    /// each `--format` may be followed by an `--output-file` to send that format to a file,
-    /// so console and report artifacts can be produced together.
+    /// Inline configuration data (yaml) layered over the discovered config instead of
+    /// replacing it: its rules merge into the project's, like a config that `extends` it
+    #[arg(long = "config-overlay", value_name = "YAML")]
+    config_overlay: Option<String>,
+
+    /// Directory of YAML pattern-rule files (each a `rule` name, a `pattern` regex, and a
+    /// `scope` of keys, values, or lines) run after the built-in rules
+    #[arg(long = "rulesdir", value_name = "DIR")]
+    rulesdir: Option<PathBuf>,
+
+    /// Output format (auto, standard, colored, github, parsable, concise, junit, gitlab,
+    /// codeclimate, sarif, review, json).
+    /// Repeatable: each `--format` may be followed by an `--output-file` to send that format
+    /// to a file, so console and report artifacts can be produced together.
     #[arg(short = 'f', long = "format", value_enum)]
     format: Vec<CliFormat>,
 
+    /// Render each diagnostic with TEMPLATE, a custom console format such as
+    /// `'{path}:{line}:{col}: {level} {message} ({rule})'` (`{{`/`}}` are literal braces).
+    /// Takes an `--output-file` like a `--format`
+    #[arg(
+        long = "format-template",
+        value_name = "TEMPLATE",
+        value_parser = FormatTemplate::parse
+    )]
+    format_template: Option<FormatTemplate>,
+
     /// Destination for the preceding `--format` (a path, or `-` for stdout). Repeatable;
     /// each binds to the most recent `--format`. Default stream otherwise: stderr for the
-    /// console formats, stdout for junit/gitlab.
+    /// console formats, stdout for junit/gitlab/codeclimate/sarif/review/json.
     #[arg(
         short = 'o',
         long = "output-file",
@@ -339,10 +428,125 @@ struct LintArgs {
     )]
     output_file: Vec<PathBuf>,
 
+    /// Also write each linted file's diagnostics, alone, to DIR in every output format, as
+    /// `DIR/<input path>.<format>` with the path percent-encoded into one file name, so the
+    /// reports can be uploaded in parallel
+    #[arg(
+        long = "split-output",
+        value_name = "DIR",
+        conflicts_with_all = ["count", "fixable", "diff"]
+    )]
+    split_output: Option<PathBuf>,
+
+    #[command(flatten)]
+    render: RenderFlags,
+
+    /// Print only an `errors=E warnings=W files=F` totals line to stdout instead of the
+    /// diagnostics; the exit code is unchanged
+    #[arg(
+        long = "count",
+        default_value_t = false,
+        conflicts_with_all = ["format", "format_template", "output_file", "diff"]
+    )]
+    count: bool,
+
+    /// Print, per rule, how many problems `--fix` would fix and how many need a manual
+    /// edit to stdout instead of the diagnostics; no file is changed and the exit code is
+    /// unchanged
+    #[arg(
+        long = "fixable",
+        default_value_t = false,
+        conflicts_with_all = ["count", "format", "format_template", "output_file", "fix", "diff"]
+    )]
+    fixable: bool,
+
+    /// After the diagnostics, print an `N problems (E errors, W warnings)` footer to
+    /// stderr when a `standard` or `colored` report goes there and anything was reported
+    #[arg(
+        long = "summary",
+        default_value_t = false,
+        conflicts_with_all = ["count", "fixable", "diff"]
+    )]
+    summary: bool,
+
+    /// Also write a final `status errors=E warnings=W` line to file descriptor N (Unix),
+    /// apart from the diagnostics, for wrappers that want the totals without parsing them
+    #[arg(long = "status-fd", value_name = "N", conflicts_with = "diff")]
+    status_fd: Option<u32>,
+
+    /// Print details of how the run was set up to stderr, such as which format
+    /// `--format auto` resolved to and why
+    #[arg(long = "verbose", default_value_t = false)]
+    verbose: bool,
+
+    /// Write each file's lint duration, with its per-rule breakdown, as JSON to FILE
+    #[arg(long = "profile-json", value_name = "FILE")]
+    profile_json: Option<PathBuf>,
+
+    /// Cache each file's diagnostics in DIR and reuse them while the file (by modification
+    /// time and size), its effective config, and the ryl version are unchanged
+    #[arg(
+        long = "cache-dir",
+        value_name = "DIR",
+        conflicts_with_all = ["fix", "diff", "profile_json"]
+    )]
+    cache_dir: Option<PathBuf>,
+
     #[command(flatten)]
     lint: LintFlags,
 }
 
+/// Per-format rendering switches; folded into [`RenderOptions`] for each target.
+#[derive(clap::Args, Debug, Default)]
+// Independent CLI toggles; `RenderOptions` folds the exclusive path pair into an enum.
+#[allow(clippy::struct_excessive_bools)]
+struct RenderFlags {
+    /// Append each diagnostic's rule documentation URL to `parsable` output lines
+    #[arg(long = "show-rule-url", default_value_t = false)]
+    show_rule_url: bool,
+
+    /// Print file paths in the console formats relative to the project root
+    /// (`CI_PROJECT_DIR`, else the working directory), as the report formats do
+    #[arg(
+        long = "relative-paths",
+        visible_alias = "relative",
+        default_value_t = false
+    )]
+    relative_paths: bool,
+
+    /// Print file paths in every format as absolute paths, resolving symlinks where the
+    /// file exists
+    #[arg(
+        long = "absolute-paths",
+        default_value_t = false,
+        conflicts_with = "relative_paths"
+    )]
+    absolute_paths: bool,
+
+    /// Append the config file that applied to each file to its `standard` and `colored`
+    /// header, e.g. `docs/a.yaml  [config: docs/.yamllint]`
+    #[arg(long = "annotate-config", default_value_t = false)]
+    annotate_config: bool,
+
+    /// Cap the `github` format at N annotations, ending with a `::notice::` that counts the
+    /// omitted ones
+    #[arg(long = "max-annotations", value_name = "N")]
+    max_annotations: Option<usize>,
+
+    /// When to colour output: `auto` follows `NO_COLOR`, `FORCE_COLOR` and the terminal;
+    /// `always` also colours `github` annotation messages; `never` prints `colored` plain
+    #[arg(long = "color", value_name = "WHEN", value_enum, default_value_t)]
+    color: ColorWhen,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+enum ColorWhen {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
 #[derive(clap::Args, Debug, Default)]
 struct LintFlags {
     #[command(flatten)]
@@ -355,6 +559,11 @@ struct LintFlags {
     /// blocks) using default globs, without configuring `[files].markdown`
     #[arg(long = "markdown", default_value_t = false)]
     markdown: bool,
+
+    /// Decode every input (and stdin) with this encoding label, e.g. `latin1` or
+    /// `windows-1252`, instead of detecting it; config files are still detected
+    #[arg(long = "input-encoding", value_name = "LABEL")]
+    input_encoding: Option<String>,
 }
 
 #[derive(clap::Args, Debug, Default)]
@@ -435,16 +644,47 @@ enum OutputFormat {
     Colored,
     Github,
     Parsable,
+    Concise,
     Junit,
     Gitlab,
+    CodeClimate,
+    Sarif,
+    Review,
+    Json,
+    /// `--format-template`, rendered with [`RenderOptions::template`].
+    Template,
 }
 
 impl OutputFormat {
-    /// Streaming formats emit per diagnostic; junit/gitlab buffer all and serialize once.
+    /// The `--format` value, or `template` for `--format-template`.
+    const fn name(self) -> &'static str {
+        match self {
+            Self::Standard => "standard",
+            Self::Colored => "colored",
+            Self::Github => "github",
+            Self::Parsable => "parsable",
+            Self::Concise => "concise",
+            Self::Junit => "junit",
+            Self::Gitlab => "gitlab",
+            Self::CodeClimate => "codeclimate",
+            Self::Sarif => "sarif",
+            Self::Review => "review",
+            Self::Json => "json",
+            Self::Template => "template",
+        }
+    }
+
+    /// Streaming formats emit per diagnostic; the report formats buffer all and serialize
+    /// once.
     const fn is_streaming(self) -> bool {
         matches!(
             self,
-            Self::Standard | Self::Colored | Self::Github | Self::Parsable
+            Self::Standard
+                | Self::Colored
+                | Self::Github
+                | Self::Parsable
+                | Self::Concise
+                | Self::Template
         )
     }
 }
@@ -485,13 +725,19 @@ fn resolve_cli_targets(
         Format(OutputFormat),
         Output(PathBuf),
     }
+    let color = args.render.color;
     let mut occurrences: Vec<(usize, Occurrence)> = Vec::new();
     if let Some(indices) = matches.indices_of("format") {
         for (index, format) in indices.zip(&args.format) {
-            occurrences
-                .push((index, Occurrence::Format(detect_output_format(*format))));
+            occurrences.push((
+                index,
+                Occurrence::Format(detect_output_format(*format, color)),
+            ));
         }
     }
+    if let Some(index) = matches.index_of("format_template") {
+        occurrences.push((index, Occurrence::Format(OutputFormat::Template)));
+    }
     if let Some(indices) = matches.indices_of("output_file") {
         for (index, path) in indices.zip(&args.output_file) {
             occurrences.push((index, Occurrence::Output(path.clone())));
@@ -532,7 +778,9 @@ fn resolve_cli_targets(
 }
 
 /// The run's output targets, precedence CLI > config > default: the CLI `--format` pairs,
-/// else the config `[output]` table, else the default auto-console target.
+/// else the config `[output]` table, else the default auto-console target. `--count` has no
+/// targets, since its totals line replaces every diagnostic output. With `--verbose`, a
+/// target that resolved `auto` also prints the rationale line from [`auto_output_format`].
 ///
 /// # Errors
 ///
@@ -542,16 +790,35 @@ fn resolve_targets(
     args: &LintArgs,
     config_output: Option<&OutputTable>,
 ) -> Result<Vec<OutputTarget>, String> {
+    if args.count || args.fixable {
+        return Ok(Vec::new());
+    }
+    let color = args.render.color;
     let cli_targets = resolve_cli_targets(matches, args)?;
     if !cli_targets.is_empty() {
+        if args.verbose && args.format.contains(&CliFormat::Auto) {
+            print_auto_rationale(color);
+        }
         return Ok(cli_targets);
     }
-    if let Some(config_targets) = config_output.map(config_targets_from_table)
-        && !config_targets.is_empty()
-    {
-        return Ok(config_targets);
+    if let Some(table) = config_output {
+        let config_targets = config_targets_from_table(table, color);
+        if !config_targets.is_empty() {
+            if args.verbose
+                && table
+                    .entries()
+                    .iter()
+                    .any(|(name, destination)| *name == "auto" && destination.is_some())
+            {
+                print_auto_rationale(color);
+            }
+            return Ok(config_targets);
+        }
     }
-    let format = detect_output_format(CliFormat::Auto);
+    if args.verbose {
+        print_auto_rationale(color);
+    }
+    let format = detect_output_format(CliFormat::Auto, color);
     Ok(vec![OutputTarget {
         destination: default_destination(format),
         format,
@@ -561,7 +828,10 @@ fn resolve_targets(
 /// One target per declared format, in `OutputTable::entries` order (deterministic). Table
 /// field names are exactly the `--format` value names (so the `from_str` cannot fail), and
 /// `auto` is env-resolved like `--format auto`.
-fn config_targets_from_table(table: &OutputTable) -> Vec<OutputTarget> {
+fn config_targets_from_table(
+    table: &OutputTable,
+    color: ColorWhen,
+) -> Vec<OutputTarget> {
     table
         .entries()
         .into_iter()
@@ -569,13 +839,17 @@ fn config_targets_from_table(table: &OutputTable) -> Vec<OutputTarget> {
             let destination = destination?;
             let choice = CliFormat::from_str(name, false)
                 .expect("OutputTable field names match CliFormat value names");
-            Some(config_target(choice, destination))
+            Some(config_target(choice, destination, color))
         })
         .collect()
 }
 
-fn config_target(choice: CliFormat, destination: &OutputDestination) -> OutputTarget {
-    let format = detect_output_format(choice);
+fn config_target(
+    choice: CliFormat,
+    destination: &OutputDestination,
+    color: ColorWhen,
+) -> OutputTarget {
+    let format = detect_output_format(choice, color);
     let destination = match destination.path.as_deref() {
         None => default_destination(format),
         Some("-") => Destination::Stdout,
@@ -587,26 +861,50 @@ fn config_target(choice: CliFormat, destination: &OutputDestination) -> OutputTa
     }
 }
 
-fn detect_output_format(choice: CliFormat) -> OutputFormat {
+/// The format `choice` resolves to. `--color never` makes `colored` print as `standard`.
+fn detect_output_format(choice: CliFormat, color: ColorWhen) -> OutputFormat {
     match choice {
         CliFormat::Standard => OutputFormat::Standard,
+        CliFormat::Colored if color == ColorWhen::Never => OutputFormat::Standard,
         CliFormat::Colored => OutputFormat::Colored,
         CliFormat::Github => OutputFormat::Github,
         CliFormat::Parsable => OutputFormat::Parsable,
+        CliFormat::Concise => OutputFormat::Concise,
         CliFormat::Junit => OutputFormat::Junit,
         CliFormat::Gitlab => OutputFormat::Gitlab,
-        CliFormat::Auto => {
-            if github_env_active() {
-                OutputFormat::Github
-            } else if supports_color() {
-                OutputFormat::Colored
-            } else {
-                OutputFormat::Standard
-            }
-        }
+        CliFormat::CodeClimate => OutputFormat::CodeClimate,
+        CliFormat::Sarif => OutputFormat::Sarif,
+        CliFormat::Review => OutputFormat::Review,
+        CliFormat::Json => OutputFormat::Json,
+        CliFormat::Auto => auto_output_format(color).0,
+    }
+}
+
+/// Resolve `auto` from the environment, with the reason `--verbose` reports: GitHub Actions
+/// wins, then an explicit `--color`, then `NO_COLOR`, then `FORCE_COLOR`, then whether
+/// stderr is a terminal.
+fn auto_output_format(color: ColorWhen) -> (OutputFormat, &'static str) {
+    if github_env_active() {
+        (OutputFormat::Github, "GitHub Actions detected -> github")
+    } else if color == ColorWhen::Never {
+        (OutputFormat::Standard, "--color never -> standard")
+    } else if color == ColorWhen::Always {
+        (OutputFormat::Colored, "--color always -> colored")
+    } else if std::env::var_os("NO_COLOR").is_some() {
+        (OutputFormat::Standard, "NO_COLOR set -> standard")
+    } else if std::env::var_os("FORCE_COLOR").is_some() {
+        (OutputFormat::Colored, "FORCE_COLOR set -> colored")
+    } else if std::io::stderr().is_terminal() {
+        (OutputFormat::Colored, "TTY + color -> colored")
+    } else {
+        (OutputFormat::Standard, "stderr is not a TTY -> standard")
     }
 }
 
+fn print_auto_rationale(color: ColorWhen) {
+    eprintln!("auto: {}", auto_output_format(color).1);
+}
+
 /// An opened output destination. A file is opened create+write but **not** truncate, so its
 /// contents survive until [`OutputSink::commit`] truncates and rewrites it: a later target
 /// failing to open then cannot destroy an existing artifact (a freshly created one may be
@@ -685,6 +983,47 @@ fn open_targets(targets: &[OutputTarget]) -> Result<Vec<OutputSink>, String> {
     Ok(sinks)
 }
 
+/// Per-format rendering switches from the CLI, shared by every target.
+#[derive(Clone, Copy)]
+struct RenderOptions<'a> {
+    show_rule_url: bool,
+    max_annotations: Option<usize>,
+    path_style: PathStyle,
+    annotate_config: bool,
+    github_color: bool,
+    template: Option<&'a FormatTemplate>,
+    split_output: Option<&'a Path>,
+}
+
+/// Which form the file paths take, from the mutually exclusive `--relative-paths` and
+/// `--absolute-paths`.
+#[derive(Clone, Copy)]
+enum PathStyle {
+    AsGiven,
+    Relative,
+    Absolute,
+}
+
+impl<'a> RenderOptions<'a> {
+    fn from_args(args: &'a LintArgs) -> Self {
+        Self {
+            show_rule_url: args.render.show_rule_url,
+            max_annotations: args.render.max_annotations,
+            path_style: if args.render.absolute_paths {
+                PathStyle::Absolute
+            } else if args.render.relative_paths {
+                PathStyle::Relative
+            } else {
+                PathStyle::AsGiven
+            },
+            annotate_config: args.render.annotate_config,
+            github_color: matches!(args.render.color, ColorWhen::Always),
+            template: args.format_template.as_ref(),
+            split_output: args.split_output.as_deref(),
+        }
+    }
+}
+
 /// Render `records` for each target and write to its sink. Report entries are built once and
 /// shared across any report targets.
 ///
@@ -695,20 +1034,86 @@ fn write_targets(
     targets: &[OutputTarget],
     sinks: &mut [OutputSink],
     records: &[FileRecord],
+    options: RenderOptions<'_>,
 ) -> Result<(), String> {
     let project_root = report_project_root();
     let entries = targets
         .iter()
         .any(|target| !target.format.is_streaming())
-        .then(|| build_entries(records, &project_root));
+        .then(|| build_entries(records, &project_root, options.path_style));
     for (target, sink) in targets.iter().zip(sinks.iter_mut()) {
-        let bytes = render_target(target.format, records, entries.as_deref());
+        let bytes = render_target(
+            target.format,
+            records,
+            entries.as_deref(),
+            &project_root,
+            options,
+        );
         sink.commit(&bytes)
             .map_err(|err| write_output_error(&err))?;
     }
+    if let Some(dir) = options.split_output {
+        write_split_output(dir, targets, records, &project_root, options)?;
+    }
+    Ok(())
+}
+
+/// `--split-output`: render each record on its own in every target's format, to
+/// `dir/<encoded path>.<format>`. A clean file still gets its (empty) report.
+///
+/// # Errors
+///
+/// Propagates a failure to create `dir` or write a report into it.
+fn write_split_output(
+    dir: &Path,
+    targets: &[OutputTarget],
+    records: &[FileRecord],
+    project_root: &Path,
+    options: RenderOptions<'_>,
+) -> Result<(), String> {
+    std::fs::create_dir_all(dir).map_err(|err| {
+        format!(
+            "error: failed to create split output directory {}: {err}",
+            dir.display()
+        )
+    })?;
+    for record in records {
+        let single = std::slice::from_ref(record);
+        let entries = targets
+            .iter()
+            .any(|target| !target.format.is_streaming())
+            .then(|| build_entries(single, project_root, options.path_style));
+        let stem = split_file_stem(record.path);
+        for target in targets {
+            let bytes = render_target(
+                target.format,
+                single,
+                entries.as_deref(),
+                project_root,
+                options,
+            );
+            std::fs::write(dir.join(format!("{stem}.{}", target.format.name())), bytes)
+                .map_err(|err| write_output_error(&err))?;
+        }
+    }
     Ok(())
 }
 
+/// `path` as one portable file name: every byte outside `[A-Za-z0-9._-]` (separators
+/// included) becomes `%XX`, so distinct inputs never share a report.
+fn split_file_stem(path: &Path) -> String {
+    path.to_string_lossy()
+        .bytes()
+        .map(|byte| {
+            if byte.is_ascii_alphanumeric() || matches!(byte, b'.' | b'_' | b'-') {
+                char::from(byte).to_string()
+            } else {
+                format!("%{byte:02X}")
+            }
+        })
+        .collect()
+}
+
 /// Open then render and write `records` to each target. For paths with no `--fix` ordering
 /// constraint (empty-input and stdin); the `--fix` path opens early via [`open_targets`].
 ///
@@ -718,57 +1123,154 @@ fn write_targets(
 fn emit_targets(
     targets: &[OutputTarget],
     records: &[FileRecord],
+    options: RenderOptions<'_>,
 ) -> Result<(), String> {
     let mut sinks = open_targets(targets)?;
-    write_targets(targets, &mut sinks, records)
+    write_targets(targets, &mut sinks, records, options)
 }
 
 /// Render `records` to bytes in `format`. The report arms serialize the pre-built `entries`,
-/// always `Some` when a report target is present (see [`write_targets`]).
+/// always `Some` when a report target is present (see [`write_targets`]). With
+/// `--relative-paths`, the console arms show paths relative to `project_root`, and with
+/// `--absolute-paths` as absolute paths; with `--annotate-config`, the standard and
+/// colored headers name each file's config source.
 fn render_target(
     format: OutputFormat,
     records: &[FileRecord],
     entries: Option<&[ReportEntry]>,
+    project_root: &Path,
+    options: RenderOptions<'_>,
 ) -> Vec<u8> {
+    let paths = match options.path_style {
+        PathStyle::AsGiven => ConsolePaths::AsGiven,
+        PathStyle::Relative => ConsolePaths::Relative(project_root),
+        PathStyle::Absolute => ConsolePaths::Absolute,
+    };
+    let annotate = options.annotate_config;
     match format {
-        OutputFormat::Standard => render_streaming(records, append_standard),
-        OutputFormat::Colored => render_streaming(records, append_colored),
-        OutputFormat::Github => render_streaming(records, append_github),
-        OutputFormat::Parsable => render_streaming(records, append_parsable),
+        OutputFormat::Standard => {
+            render_streaming(records, paths, annotate, append_standard)
+        }
+        OutputFormat::Colored => {
+            render_streaming(records, paths, annotate, append_colored)
+        }
+        OutputFormat::Github => render_github(
+            records,
+            paths,
+            options.max_annotations,
+            options.github_color,
+        ),
+        OutputFormat::Parsable => {
+            render_streaming(records, paths, false, |out, path, _, problems| {
+                append_parsable(out, path, problems, options.show_rule_url);
+            })
+        }
+        OutputFormat::Concise => {
+            render_streaming(records, paths, false, |out, path, _, problems| {
+                append_concise(out, path, problems);
+            })
+        }
         OutputFormat::Junit => render_junit(entries.expect(REPORT_ENTRIES_BUILT)),
         OutputFormat::Gitlab => render_gitlab(entries.expect(REPORT_ENTRIES_BUILT)),
+        OutputFormat::CodeClimate => {
+            render_codeclimate(entries.expect(REPORT_ENTRIES_BUILT))
+        }
+        OutputFormat::Sarif => render_sarif(entries.expect(REPORT_ENTRIES_BUILT)),
+        OutputFormat::Review => render_review(entries.expect(REPORT_ENTRIES_BUILT)),
+        OutputFormat::Json => render_json(entries.expect(REPORT_ENTRIES_BUILT)),
+        OutputFormat::Template => {
+            let template = options.template.expect(TEMPLATE_PARSED);
+            render_streaming(records, paths, false, |out, path, _, problems| {
+                append_template(out, path, problems, template);
+            })
+        }
     }
 }
 
-// A report target means entries are built, so the junit/gitlab arms only see `Some`; the
+// A report target means entries are built, so the report arms only see `Some`; the
 // `expect` pins that invariant rather than leaving an uncovered `None` arm.
 const REPORT_ENTRIES_BUILT: &str =
     "report entries are built when a report target is present";
 
+// Likewise, a template target only comes from `--format-template`, whose value it renders.
+const TEMPLATE_PARSED: &str = "a template target carries its --format-template";
+
 /// Append each record's per-file block via `append`, skipping clean files; a processing-error
-/// record contributes its (already-sanitized) message line.
+/// record contributes its (already-sanitized) message line. With `annotate_config`, `append`
+/// also receives the record's config source (shown in the same path form as the file).
 fn render_streaming(
     records: &[FileRecord],
-    append: fn(&mut Vec<u8>, &Path, &[LintProblem]),
+    paths: ConsolePaths<'_>,
+    annotate_config: bool,
+    append: impl Fn(&mut Vec<u8>, &Path, Option<&Path>, &[LintProblem]),
 ) -> Vec<u8> {
     let mut out: Vec<u8> = Vec::new();
     for record in records {
         if let Some(message) = &record.error {
             writeln!(out, "{message}").expect(OUTPUT_INFALLIBLE);
         } else if !record.kept.is_empty() {
-            append(&mut out, record.path, &record.kept);
+            let config = record
+                .config_source
+                .filter(|_| annotate_config)
+                .map(|source| console_path(source, paths));
+            append(
+                &mut out,
+                &console_path(record.path, paths),
+                config.as_deref(),
+                &record.kept,
+            );
         }
     }
     out
 }
 
+/// How the console formats show each file path: a [`PathStyle`] with the project root
+/// that `Relative` resolves against.
+#[derive(Clone, Copy)]
+enum ConsolePaths<'a> {
+    AsGiven,
+    Relative(&'a Path),
+    Absolute,
+}
+
+/// The path a console format shows for `path`: as given, relative to the
+/// `--relative-paths` project root in the report formats' form, or absolute.
+fn console_path<'a>(path: &'a Path, paths: ConsolePaths<'_>) -> Cow<'a, Path> {
+    match paths {
+        ConsolePaths::AsGiven => Cow::Borrowed(path),
+        ConsolePaths::Relative(root) => {
+            Cow::Owned(PathBuf::from(report_display_path(path, root)))
+        }
+        ConsolePaths::Absolute => Cow::Owned(absolute_path(path)),
+    }
+}
+
+/// `path` made absolute for `--absolute-paths`: canonicalized when it exists, else joined
+/// onto the working directory. The stdin label names no file and is kept as is.
+fn absolute_path(path: &Path) -> PathBuf {
+    if path == Path::new(STDIN_LABEL) {
+        return path.to_path_buf();
+    }
+    std::fs::canonicalize(path).unwrap_or_else(|_| lexical_abspath(path))
+}
+
 /// Convert every record (clean files included) into a [`ReportEntry`] with a project-root
-/// relative display path; the report emitters decide how to render a clean file.
-fn build_entries(records: &[FileRecord], project_root: &Path) -> Vec<ReportEntry> {
+/// relative display path (absolute under [`PathStyle::Absolute`]); the report emitters
+/// decide how to render a clean file.
+fn build_entries(
+    records: &[FileRecord],
+    project_root: &Path,
+    path_style: PathStyle,
+) -> Vec<ReportEntry> {
     records
         .iter()
         .map(|record| ReportEntry {
-            path: report_display_path(record.path, project_root),
+            path: if matches!(path_style, PathStyle::Absolute) {
+                sanitize_control(&absolute_path(record.path).display().to_string())
+                    .into_owned()
+            } else {
+                report_display_path(record.path, project_root)
+            },
             problems: record.kept.clone(),
             error: record.error.clone(),
         })
@@ -805,7 +1307,8 @@ fn validate_targets(targets: &[OutputTarget], diff: bool) -> Result<(), String>
 fn reject_diff_report_conflict(targets: &[OutputTarget]) -> Result<(), String> {
     if targets.iter().any(|target| !target.format.is_streaming()) {
         return Err(
-            "error: `--diff` cannot be combined with `--format junit` or `--format gitlab`"
+            "error: `--diff` cannot be combined with `--format junit`, `--format gitlab`, \
+             `--format codeclimate`, `--format sarif`, `--format review`, or `--format json`"
                 .to_string(),
         );
     }
@@ -939,16 +1442,6 @@ fn github_env_active() -> bool {
         && std::env::var_os("GITHUB_WORKFLOW").is_some()
 }
 
-fn supports_color() -> bool {
-    if std::env::var_os("NO_COLOR").is_some() {
-        return false;
-    }
-    if std::env::var_os("FORCE_COLOR").is_some() {
-        return true;
-    }
-    std::io::stderr().is_terminal()
-}
-
 fn main() -> ExitCode {
     // Keep the `ArgMatches` so `resolve_cli_targets` can recover the CLI order of the
     // repeatable `--format`/`--output-file` pairs via `indices_of`; `from_arg_matches` then
@@ -1013,20 +1506,47 @@ fn run_cli(cli: &Cli, matches: &ArgMatches) -> Result<ExitCode, String> {
     run_lint(&cli.lint_args, matches)
 }
 
+/// Reject flags and inputs that cannot apply when the single input is `-` (stdin).
+fn reject_stdin_conflicts(args: &LintArgs) -> Result<(), String> {
+    if args.inputs.len() > 1 || args.files_from.is_some() {
+        return Err(
+            "error: `-` (stdin) cannot be combined with other inputs".to_string()
+        );
+    }
+    if args.lint.fix.fix {
+        return Err(
+            "error: `--fix` is not supported when reading from stdin".to_string()
+        );
+    }
+    if args.profile_json.is_some() {
+        return Err(
+            "error: `--profile-json` is not supported when reading from stdin"
+                .to_string(),
+        );
+    }
+    if args.cache_dir.is_some() {
+        return Err(
+            "error: `--cache-dir` is not supported when reading from stdin".to_string(),
+        );
+    }
+    if args.split_output.is_some() {
+        return Err(
+            "error: `--split-output` is not supported when reading from stdin"
+                .to_string(),
+        );
+    }
+    Ok(())
+}
+
 fn run_lint(args: &LintArgs, matches: &ArgMatches) -> Result<ExitCode, String> {
+    if let Some(label) = &args.lint.input_encoding {
+        decoder::set_input_encoding(label)
+            .map_err(|err| format!("error: --input-encoding: {err}"))?;
+    }
+
     let stdin_input = Path::new("-");
-    let has_stdin = args.inputs.iter().any(|p| p.as_path() == stdin_input);
-    if has_stdin {
-        if args.inputs.len() > 1 {
-            return Err(
-                "error: `-` (stdin) cannot be combined with other inputs".to_string()
-            );
-        }
-        if args.lint.fix.fix {
-            return Err(
-                "error: `--fix` is not supported when reading from stdin".to_string()
-            );
-        }
+    if args.inputs.iter().any(|p| p.as_path() == stdin_input) {
+        reject_stdin_conflicts(args)?;
         return run_stdin_lint(args, matches);
     }
 
@@ -1037,14 +1557,23 @@ fn run_lint(args: &LintArgs, matches: &ArgMatches) -> Result<ExitCode, String> {
         );
     }
 
-    if args.inputs.is_empty() {
+    if args.inputs.is_empty() && args.since.is_none() && args.files_from.is_none() {
         return Err(
             "error: expected one or more paths (files and/or directories), or `-` for stdin"
                 .to_string(),
         );
     }
+    // `--since` lints changed files under the working directory, so config is anchored there;
+    // so does a `--files-from` list given without positional inputs.
+    let since_anchor = [PathBuf::from(".")];
+    let inputs: &[PathBuf] = if args.since.is_some() || args.inputs.is_empty() {
+        &since_anchor
+    } else {
+        &args.inputs
+    };
 
-    let mut global_cfg = build_global_cfg(&args.inputs, args)?;
+    let pattern_rules = load_pattern_rules(args)?;
+    let mut global_cfg = build_global_cfg(inputs, args)?;
     if args.lint.markdown
         && let Some(ctx) = global_cfg.as_mut()
     {
@@ -1056,9 +1585,16 @@ fn run_lint(args: &LintArgs, matches: &ArgMatches) -> Result<ExitCode, String> {
             eprintln!("{}", sanitize_control(notice));
         }
     }
-    let inputs = &args.inputs;
-
-    let (candidates, explicit_files) = gather_inputs(inputs);
+    // Changed files are walk candidates, so a non-YAML change is skipped rather than an error.
+    let (mut candidates, explicit_files) = match &args.since {
+        Some(rev) => (git_changed_files(rev)?, Vec::new()),
+        None => gather_inputs(&args.inputs),
+    };
+    // Listed paths join the walk candidates, so a non-YAML entry is skipped like a changed
+    // file under `--since` rather than rejected like an explicit argument.
+    if let Some(list) = &args.files_from {
+        candidates.extend(read_file_list(list)?);
+    }
 
     let mut cache: HashMap<PathBuf, (PathBuf, YamlLintConfig, bool)> = HashMap::new();
     let mut emitted_notices: HashSet<String> = HashSet::new();
@@ -1068,13 +1604,19 @@ fn run_lint(args: &LintArgs, matches: &ArgMatches) -> Result<ExitCode, String> {
         &explicit_files,
         global_cfg.as_ref(),
         args.lint.markdown,
+        args.config_overlay.as_deref(),
+        &pattern_rules,
         &mut cache,
         &mut emitted_notices,
         &mut files,
     )?;
 
     if args.lint.compatibility.list_files {
-        for (path, ..) in &files {
+        // Sorted so the listing is stable across filesystems, whatever order the walk took.
+        let mut listed: Vec<&Path> =
+            files.iter().map(|(path, ..)| path.as_path()).collect();
+        listed.sort_unstable();
+        for path in listed {
             println!("{}", sanitize_control(&path.display().to_string()));
         }
         return Ok(ExitCode::SUCCESS);
@@ -1086,7 +1628,7 @@ fn run_lint(args: &LintArgs, matches: &ArgMatches) -> Result<ExitCode, String> {
     let output_config = if args.lint.fix.diff {
         None
     } else {
-        run_output_config(global_cfg.as_ref(), &args.inputs, args)?
+        run_output_config(global_cfg.as_ref(), inputs, args)?
     };
     let targets = resolve_targets(matches, args, output_config.as_ref())?;
     validate_targets(&targets, args.lint.fix.diff)?;
@@ -1097,8 +1639,8 @@ fn run_lint(args: &LintArgs, matches: &ArgMatches) -> Result<ExitCode, String> {
     if files.is_empty() {
         // Still emit a valid empty report per target, so CI artifact ingestion sees
         // `[]` / `<testsuites .../>` rather than a missing file.
-        emit_targets(targets, &[])?;
-        return Ok(ExitCode::SUCCESS);
+        emit_targets(targets, &[], RenderOptions::from_args(args))?;
+        return finish_run(&LintSummary::default(), args);
     }
 
     if let Some(config_found) = ruleless_config_found {
@@ -1131,22 +1673,55 @@ fn lint_and_exit(
         0
     };
 
-    let results = lint_files(files);
-    let (summary, records) =
+    let results = if let Some(dest) = &args.profile_json {
+        let (results, profiles) = lint_files_profiled(files);
+        write_profile(dest, files, &profiles)?;
+        results
+    } else if let Some(dir) = &args.cache_dir {
+        let cache = ResultCache::open(dir)?;
+        let results = lint_files(files, Some(&cache));
+        if args.verbose {
+            eprintln!("cache: hits={} misses={}", cache.hits(), cache.misses());
+        }
+        results
+    } else {
+        lint_files(files, None)
+    };
+    let (mut summary, records) =
         collect_records(files, results, args.lint.compatibility.no_warnings);
-    write_targets(targets, &mut sinks, &records)?;
+    if args.fixable {
+        let sources: Vec<Option<String>> = files
+            .par_iter()
+            .map(|(path, ..)| decoder::read_file_text(path).ok().and_then(Result::ok))
+            .collect();
+        summary.fixability = tally_fixability(
+            files,
+            &records,
+            &sources,
+            args.lint.compatibility.no_warnings,
+        );
+    }
+    write_targets(
+        targets,
+        &mut sinks,
+        &records,
+        RenderOptions::from_args(args),
+    )?;
+    if args.summary {
+        print_summary_footer(&summary, targets);
+    }
 
     if args.lint.fix.fix && initial_problem_count > 0 {
         eprintln!(
             "Found {} {} ({} fixed, {} remaining).",
             initial_problem_count,
             pluralize("problem", initial_problem_count),
-            initial_problem_count.saturating_sub(summary.problem_count),
-            summary.problem_count
+            initial_problem_count.saturating_sub(summary.problem_count()),
+            summary.problem_count()
         );
     }
 
-    Ok(summary_to_exit(&summary, args.lint.compatibility.strict))
+    finish_run(&summary, args)
 }
 
 /// Apply safe fixes in place and report any files skipped (they do not parse), returning the
@@ -1160,7 +1735,7 @@ fn apply_fixes_reporting_skips(
     no_warnings: bool,
 ) -> Result<usize, String> {
     let initial_problem_count =
-        count_reported_problems(&lint_files(files), no_warnings);
+        count_reported_problems(&lint_files(files, None), no_warnings);
     let fix_stats = apply_safe_fixes_to_files(files)?;
     for (path, problem) in &fix_stats.skipped {
         eprint_skip_notice(path, problem, "--fix");
@@ -1168,10 +1743,90 @@ fn apply_fixes_reporting_skips(
     Ok(initial_problem_count)
 }
 
+/// Print the `--count` totals line and write the `--status-fd` line when requested, then
+/// map the tally to an exit code.
+///
+/// # Errors
+///
+/// Returns an error if the `--status-fd` descriptor cannot be opened or written.
+fn finish_run(summary: &LintSummary, args: &LintArgs) -> Result<ExitCode, String> {
+    if args.count {
+        println!(
+            "errors={} warnings={} files={}",
+            summary.errors, summary.warnings, summary.files
+        );
+    }
+    if args.fixable {
+        print_fixable_report(summary);
+    }
+    if let Some(fd) = args.status_fd {
+        write_status_fd(fd, summary)?;
+    }
+    Ok(summary_to_exit(summary, args.lint.compatibility.strict))
+}
+
+/// Write `status errors=E warnings=W` to the inherited descriptor `fd`. It is reopened
+/// through `/dev/fd` (the crate forbids the `unsafe` of `from_raw_fd`) in append mode, so
+/// a descriptor redirected to a regular file is added to rather than overwritten.
+#[cfg(unix)]
+fn write_status_fd(fd: u32, summary: &LintSummary) -> Result<(), String> {
+    let fail =
+        |err: std::io::Error| format!("error: failed to write --status-fd {fd}: {err}");
+    let mut out = std::fs::OpenOptions::new()
+        .append(true)
+        .open(format!("/dev/fd/{fd}"))
+        .map_err(fail)?;
+    writeln!(
+        out,
+        "status errors={} warnings={}",
+        summary.errors, summary.warnings
+    )
+    .map_err(fail)
+}
+
+/// Print the `--summary` footer. Only the human console formats get it: a machine format
+/// on stderr would be corrupted by a trailing prose line.
+fn print_summary_footer(summary: &LintSummary, targets: &[OutputTarget]) {
+    let console = targets.iter().any(|target| {
+        matches!(
+            target.format,
+            OutputFormat::Standard | OutputFormat::Colored
+        ) && matches!(target.destination, Destination::Stderr)
+    });
+    let total = summary.problem_count();
+    if console && total > 0 {
+        eprintln!(
+            "{total} {} ({} {}, {} {})",
+            pluralize("problem", total),
+            summary.errors,
+            pluralize("error", summary.errors),
+            summary.warnings,
+            pluralize("warning", summary.warnings),
+        );
+    }
+}
+
+/// Print the `--fixable` report: one `<rule> fixable=F manual=M` line per rule with a
+/// reported problem, by rule name, then the `fixable=F manual=M` totals.
+fn print_fixable_report(summary: &LintSummary) {
+    let (mut fixable, mut manual) = (0, 0);
+    for (rule, tally) in &summary.fixability {
+        println!("{rule} fixable={} manual={}", tally.fixable, tally.manual);
+        fixable += tally.fixable;
+        manual += tally.manual;
+    }
+    println!("fixable={fixable} manual={manual}");
+}
+
+#[cfg(not(unix))]
+fn write_status_fd(_fd: u32, _summary: &LintSummary) -> Result<(), String> {
+    Err("error: --status-fd is only supported on Unix".to_string())
+}
+
 fn summary_to_exit(summary: &LintSummary, strict: bool) -> ExitCode {
-    if summary.has_error {
+    if summary.errors > 0 {
         ExitCode::from(1)
-    } else if summary.has_warning && strict {
+    } else if summary.warnings + summary.suppressed_warnings > 0 && strict {
         ExitCode::from(2)
     } else {
         ExitCode::SUCCESS
@@ -1211,8 +1866,8 @@ fn run_stdin_lint(args: &LintArgs, matches: &ArgMatches) -> Result<ExitCode, Str
     else {
         // An ignored stdin filename is an empty input set: still emit a valid empty
         // report per target so CI artifact ingestion does not see a missing file.
-        emit_targets(targets, &[])?;
-        return Ok(ExitCode::SUCCESS);
+        emit_targets(targets, &[], RenderOptions::from_args(args))?;
+        return finish_run(&LintSummary::default(), args);
     };
 
     if args.lint.compatibility.list_files {
@@ -1228,16 +1883,35 @@ fn run_stdin_lint(args: &LintArgs, matches: &ArgMatches) -> Result<ExitCode, Str
         return run_stdin_diff(&path, &base_dir, &cfg, kind);
     }
 
-    let outcome = read_and_lint_stdin(&path, &base_dir, &cfg, kind);
+    let (outcome, source) = match read_and_lint_stdin(&path, &base_dir, &cfg, kind) {
+        Ok((problems, source)) => (Ok(problems), source),
+        Err(err) => (Err(err), None),
+    };
 
     let files = vec![(path, base_dir, cfg, kind)];
     let results = vec![(0usize, outcome)];
 
     let mut sinks = open_targets(targets)?;
-    let (summary, records) =
+    let (mut summary, records) =
         collect_records(&files, results, args.lint.compatibility.no_warnings);
-    write_targets(targets, &mut sinks, &records)?;
-    Ok(summary_to_exit(&summary, args.lint.compatibility.strict))
+    if args.fixable {
+        summary.fixability = tally_fixability(
+            &files,
+            &records,
+            &[source],
+            args.lint.compatibility.no_warnings,
+        );
+    }
+    write_targets(
+        targets,
+        &mut sinks,
+        &records,
+        RenderOptions::from_args(args),
+    )?;
+    if args.summary {
+        print_summary_footer(&summary, targets);
+    }
+    finish_run(&summary, args)
 }
 
 /// Resolve the source kind for stdin, or `None` to skip an ignored `--stdin-filename`.
@@ -1273,27 +1947,40 @@ fn resolve_stdin_kind(
 /// Read and decode stdin. The bool is whether the bytes were plain UTF-8 (no BOM, no
 /// transcode), i.e. whether a textual `--diff` would apply back to the original bytes.
 fn read_stdin_decoded(path: &Path) -> Result<(String, bool), String> {
+    let buf = read_stdin_bytes(path)?;
+    let content = decoder::decode_input_bytes(&buf)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+    let plain_utf8 = content.as_bytes() == buf.as_slice();
+    Ok((content, plain_utf8))
+}
+
+fn read_stdin_bytes(path: &Path) -> Result<Vec<u8>, String> {
     let mut buf = Vec::new();
     std::io::stdin()
         .read_to_end(&mut buf)
         .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
-    let content = decoder::decode_bytes(&buf)
-        .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
-    let plain_utf8 = content.as_bytes() == buf.as_slice();
-    Ok((content, plain_utf8))
+    Ok(buf)
 }
 
+/// Read and lint stdin, also returning the decoded text (`None` when it is not valid
+/// UTF-8) for `--fixable` to fix and re-lint.
 fn read_and_lint_stdin(
     path: &Path,
     base_dir: &Path,
     cfg: &YamlLintConfig,
     kind: SourceKind,
-) -> Result<Vec<LintProblem>, String> {
-    let (content, _) = read_stdin_decoded(path)?;
-    Ok(match kind {
+) -> Result<(Vec<LintProblem>, Option<String>), String> {
+    let content = match decoder::decode_input_text(read_stdin_bytes(path)?)
+        .map_err(|err| format!("failed to read {}: {err}", path.display()))?
+    {
+        Ok(content) => content,
+        Err(invalid) => return Ok((vec![invalid_utf8_problem(invalid)], None)),
+    };
+    let problems = match kind {
         SourceKind::Markdown => lint_markdown_str(&content, path, cfg, base_dir),
         SourceKind::Yaml => lint_str(&content, path, cfg, base_dir),
-    })
+    };
+    Ok((problems, Some(content)))
 }
 
 fn run_stdin_diff(
@@ -1348,7 +2035,7 @@ fn resolve_stdin_ctx(
     } else {
         PathBuf::from(".")
     };
-    let ctx = discover_config(std::slice::from_ref(&anchor), &cli_overrides(args))?;
+    let ctx = discover_overlaid_config(std::slice::from_ref(&anchor), args)?;
     for notice in &ctx.notices {
         eprintln!("{}", sanitize_control(notice.as_str()));
     }
@@ -1356,6 +2043,7 @@ fn resolve_stdin_ctx(
     if args.lint.markdown {
         cfg.enable_default_markdown(&ctx.base_dir);
     }
+    cfg.set_pattern_rules(load_pattern_rules(args)?);
     if !apply_yaml_files {
         cfg.disable_path_based_rule_ignores();
     }
@@ -1364,15 +2052,20 @@ fn resolve_stdin_ctx(
 
 fn lint_files(
     files: &[(PathBuf, PathBuf, YamlLintConfig, SourceKind)],
+    cache: Option<&ResultCache>,
 ) -> Vec<(usize, Result<Vec<LintProblem>, String>)> {
     let mut results: Vec<(usize, Result<Vec<LintProblem>, String>)> = files
         .par_iter()
         .enumerate()
         .map(|(idx, (path, base_dir, cfg, kind))| {
-            let result = match kind {
+            let lint = || match kind {
                 SourceKind::Markdown => lint_markdown_file(path, cfg, base_dir),
                 SourceKind::Yaml => lint_file(path, cfg, base_dir),
             };
+            let result = match cache {
+                Some(cache) => cache.get_or_lint(path, base_dir, cfg, *kind, lint),
+                None => lint(),
+            };
             (idx, result)
         })
         .collect();
@@ -1380,12 +2073,118 @@ fn lint_files(
     results
 }
 
+/// Each file's input index and lint result, as [`lint_files`] returns them.
+type LintResults = Vec<(usize, Result<Vec<LintProblem>, String>)>;
+
+/// One file's lint duration (including the read) and the rules' durations within it.
+struct FileProfile {
+    duration: Duration,
+    rules: Vec<RuleTiming>,
+}
+
+/// [`lint_files`], also timing each file and each rule run on it for `--profile-json`.
+fn lint_files_profiled(
+    files: &[(PathBuf, PathBuf, YamlLintConfig, SourceKind)],
+) -> (LintResults, Vec<FileProfile>) {
+    files
+        .par_iter()
+        .enumerate()
+        .map(|(idx, (path, base_dir, cfg, kind))| {
+            let mut rules = Vec::new();
+            let started = Instant::now();
+            let result = decoder::read_file_text(path).map(|text| match (text, kind) {
+                (Err(invalid), _) => vec![invalid_utf8_problem(invalid)],
+                (Ok(content), SourceKind::Markdown) => {
+                    lint_markdown_str_timed(&content, path, cfg, base_dir, &mut rules)
+                }
+                (Ok(content), SourceKind::Yaml) => {
+                    lint_str_timed(&content, path, cfg, base_dir, &mut rules)
+                }
+            });
+            let profile = FileProfile {
+                duration: started.elapsed(),
+                rules,
+            };
+            ((idx, result), profile)
+        })
+        .unzip()
+}
+
+/// Write the `--profile-json` report: per file, its duration and per-rule durations (a rule
+/// run once per embedded Markdown region is summed), then per-rule totals across the run.
+///
+/// # Errors
+///
+/// Returns an error if `dest` cannot be written.
+fn write_profile(
+    dest: &Path,
+    files: &[(PathBuf, PathBuf, YamlLintConfig, SourceKind)],
+    profiles: &[FileProfile],
+) -> Result<(), String> {
+    let mut totals = Vec::new();
+    let file_entries: Vec<serde_json::Value> = files
+        .iter()
+        .zip(profiles)
+        .map(|((path, ..), profile)| {
+            let rules = sum_by_rule(profile.rules.iter().map(|t| (t.rule, t.duration)));
+            totals.extend(rules.iter().copied());
+            serde_json::json!({
+                "path": path.display().to_string(),
+                "duration_ns": nanos(profile.duration),
+                "rules": rule_durations_json(&rules),
+            })
+        })
+        .collect();
+    let report = serde_json::json!({
+        "files": file_entries,
+        "rules": rule_durations_json(&sum_by_rule(totals)),
+    });
+    let mut bytes =
+        serde_json::to_vec_pretty(&report).expect("a JSON value always serializes");
+    bytes.push(b'\n');
+    std::fs::write(dest, bytes).map_err(|err| {
+        format!(
+            "error: cannot write --profile-json {}: {err}",
+            sanitize_control(&dest.display().to_string())
+        )
+    })
+}
+
+/// Sum durations per rule, keeping each rule's first-seen position.
+fn sum_by_rule(
+    timings: impl IntoIterator<Item = (&'static str, Duration)>,
+) -> Vec<(&'static str, Duration)> {
+    let mut sums: Vec<(&'static str, Duration)> = Vec::new();
+    for (rule, duration) in timings {
+        match sums.iter_mut().find(|(seen, _)| *seen == rule) {
+            Some((_, sum)) => *sum += duration,
+            None => sums.push((rule, duration)),
+        }
+    }
+    sums
+}
+
+fn rule_durations_json(rules: &[(&str, Duration)]) -> Vec<serde_json::Value> {
+    rules
+        .iter()
+        .map(|(rule, duration)| {
+            serde_json::json!({ "rule": rule, "duration_ns": nanos(*duration) })
+        })
+        .collect()
+}
+
+fn nanos(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
 #[allow(clippy::too_many_arguments)]
 fn gather_lint_files(
     candidates: &[PathBuf],
     explicit_files: &[PathBuf],
     global_cfg: Option<&ConfigContext>,
     markdown: bool,
+    overlay: Option<&str>,
+    pattern_rules: &Arc<[PatternRule]>,
     cache: &mut HashMap<PathBuf, (PathBuf, YamlLintConfig, bool)>,
     emitted_notices: &mut HashSet<String>,
     files: &mut Vec<(PathBuf, PathBuf, YamlLintConfig, SourceKind)>,
@@ -1405,8 +2204,8 @@ fn gather_lint_files(
         .map(|path| (path, false))
         .chain(explicit_files.iter().map(|path| (path, true)));
     for (path, explicit) in tagged {
-        let (base_dir, cfg, notices, found) =
-            resolve_ctx(path, global_cfg, markdown, cache)?;
+        let (base_dir, mut cfg, notices, found) =
+            resolve_ctx(path, global_cfg, markdown, overlay, cache)?;
         for notice in notices {
             if emitted_notices.insert(notice.clone()) {
                 eprintln!("{}", sanitize_control(notice.as_str()));
@@ -1429,6 +2228,7 @@ fn gather_lint_files(
         if !seen.insert(lexical_abspath(path)) {
             continue;
         }
+        cfg.set_pattern_rules(Arc::clone(pattern_rules));
         if !cfg.enables_any_rule() && ruleless_config_found.is_none() {
             ruleless_config_found = Some(found);
         }
@@ -1443,33 +2243,40 @@ fn gather_lint_files(
 /// the filter+tally pass runs once.
 struct FileRecord<'a> {
     path: &'a Path,
+    /// The config file that applied to `path`, for `--annotate-config`.
+    config_source: Option<&'a Path>,
     kept: Vec<LintProblem>,
     error: Option<String>,
 }
 
 /// Filter and tally every lint result into [`FileRecord`]s in file order. The returned
 /// [`LintSummary`] (and exit code) is independent of which formats render the records.
-/// `no_warnings` drops warning-level diagnostics before they are kept or counted.
+/// `no_warnings` drops warning-level diagnostics before they are kept or counted; they
+/// are only tallied as suppressed, for `--strict`.
 fn collect_records<'a>(
     files: &'a [(PathBuf, PathBuf, YamlLintConfig, SourceKind)],
     results: Vec<(usize, Result<Vec<LintProblem>, String>)>,
     no_warnings: bool,
 ) -> (LintSummary, Vec<FileRecord<'a>>) {
-    let mut summary = LintSummary::default();
+    let mut summary = LintSummary {
+        files: results.len(),
+        ..LintSummary::default()
+    };
     let mut records: Vec<FileRecord<'a>> = Vec::with_capacity(results.len());
 
     for (idx, outcome) in results {
-        let (path, ..) = &files[idx];
+        let (path, _, cfg, _) = &files[idx];
+        let config_source = cfg.source();
         match outcome {
             Err(message) => {
                 // Sanitize: a crafted filename could otherwise inject terminal escapes or
                 // (via a newline) a GitHub workflow command. Safe for every format because it
                 // neutralises the newline injection needs.
                 let message = sanitize_control(&message).into_owned();
-                summary.has_error = true;
-                summary.problem_count += 1;
+                summary.errors += 1;
                 records.push(FileRecord {
                     path,
+                    config_source,
                     kept: Vec::new(),
                     error: Some(message),
                 });
@@ -1478,17 +2285,18 @@ fn collect_records<'a>(
                 let mut kept: Vec<LintProblem> = Vec::new();
                 for problem in diagnostics {
                     if no_warnings && problem.level == Severity::Warning {
+                        summary.suppressed_warnings += 1;
                         continue;
                     }
                     match problem.level {
-                        Severity::Error => summary.has_error = true,
-                        Severity::Warning => summary.has_warning = true,
+                        Severity::Error => summary.errors += 1,
+                        Severity::Warning => summary.warnings += 1,
                     }
-                    summary.problem_count += 1;
                     kept.push(problem);
                 }
                 records.push(FileRecord {
                     path,
+                    config_source,
                     kept,
                     error: None,
                 });
@@ -1501,9 +2309,82 @@ fn collect_records<'a>(
 
 #[derive(Default)]
 struct LintSummary {
-    has_error: bool,
-    has_warning: bool,
-    problem_count: usize,
+    /// Error-level diagnostics plus files that could not be processed.
+    errors: usize,
+    warnings: usize,
+    /// Warnings dropped by `--no-warnings`: hidden and uncounted, but still found, so
+    /// `--strict` fails on them as yamllint does.
+    suppressed_warnings: usize,
+    files: usize,
+    /// Reported problems per rule (`syntax` for rule-less ones), split by whether `--fix`
+    /// would fix them. Filled by [`tally_fixability`] only under `--fixable`.
+    fixability: BTreeMap<&'static str, Fixability>,
+}
+
+#[derive(Default)]
+struct Fixability {
+    fixable: usize,
+    manual: usize,
+}
+
+impl LintSummary {
+    const fn problem_count(&self) -> usize {
+        self.errors + self.warnings
+    }
+}
+
+/// Split each file's kept problems per rule into fixable and manual for `--fixable`. The
+/// safe fixes are applied in memory and the result re-linted: per rule, the problems that
+/// go away are fixable, so the totals match what `--fix` would then report. `sources` holds
+/// each file's decoded text, `None` where it could not be read as text (nothing fixable).
+fn tally_fixability(
+    files: &[(PathBuf, PathBuf, YamlLintConfig, SourceKind)],
+    records: &[FileRecord<'_>],
+    sources: &[Option<String>],
+    no_warnings: bool,
+) -> BTreeMap<&'static str, Fixability> {
+    let remaining: Vec<Option<BTreeMap<&'static str, usize>>> = files
+        .par_iter()
+        .zip(sources.par_iter())
+        .map(|((path, base_dir, cfg, kind), source)| {
+            let source = source.as_deref()?;
+            let fixed = ryl::fix::fixed_contents(source, cfg, path, base_dir, *kind);
+            if fixed == source {
+                return None;
+            }
+            let problems = match kind {
+                SourceKind::Markdown => lint_markdown_str(&fixed, path, cfg, base_dir),
+                SourceKind::Yaml => lint_str(&fixed, path, cfg, base_dir),
+            };
+            Some(count_by_rule(problems.iter().filter(|problem| {
+                !(no_warnings && problem.level == Severity::Warning)
+            })))
+        })
+        .collect();
+
+    let mut fixability: BTreeMap<&'static str, Fixability> = BTreeMap::new();
+    for (record, remaining) in records.iter().zip(remaining) {
+        for (rule, count) in count_by_rule(&record.kept) {
+            // `None`: the fixes change nothing in this file, so every problem is manual.
+            let fixed = remaining.as_ref().map_or(0, |after| {
+                count.saturating_sub(after.get(rule).copied().unwrap_or(0))
+            });
+            let tally = fixability.entry(rule).or_default();
+            tally.fixable += fixed;
+            tally.manual += count - fixed;
+        }
+    }
+    fixability
+}
+
+fn count_by_rule<'p>(
+    problems: impl IntoIterator<Item = &'p LintProblem>,
+) -> BTreeMap<&'static str, usize> {
+    let mut counts = BTreeMap::new();
+    for problem in problems {
+        *counts.entry(problem.rule.unwrap_or("syntax")).or_default() += 1;
+    }
+    counts
 }
 
 fn count_reported_problems(
@@ -1522,8 +2403,12 @@ fn count_reported_problems(
         .sum()
 }
 
-fn pluralize(singular: &str, count: usize) -> &str {
-    if count == 1 { singular } else { "problems" }
+fn pluralize(singular: &str, count: usize) -> String {
+    if count == 1 {
+        singular.to_string()
+    } else {
+        format!("{singular}s")
+    }
 }
 
 // The streaming `append_*` fns are only reached with a non-empty `problems` slice (the
@@ -1544,37 +2429,136 @@ fn append_grouped(
     writeln!(out).expect(OUTPUT_INFALLIBLE);
 }
 
-fn append_standard(out: &mut Vec<u8>, path: &Path, problems: &[LintProblem]) {
+fn append_standard(
+    out: &mut Vec<u8>,
+    path: &Path,
+    config: Option<&Path>,
+    problems: &[LintProblem],
+) {
     let display = path.display().to_string();
-    let header = sanitize_control(&display);
+    let header = format!(
+        "{}{}",
+        sanitize_control(&display),
+        config_annotation(config)
+    );
     append_grouped(out, &header, problems, format_standard);
 }
 
-fn append_colored(out: &mut Vec<u8>, path: &Path, problems: &[LintProblem]) {
+fn append_colored(
+    out: &mut Vec<u8>,
+    path: &Path,
+    config: Option<&Path>,
+    problems: &[LintProblem],
+) {
     let header = format!(
-        "\u{001b}[4m{}\u{001b}[0m",
-        sanitize_control(&path.display().to_string())
+        "\u{001b}[4m{}\u{001b}[0m{}",
+        sanitize_control(&path.display().to_string()),
+        config_annotation(config)
     );
     append_grouped(out, &header, problems, format_colored);
 }
 
-fn append_github(out: &mut Vec<u8>, path: &Path, problems: &[LintProblem]) {
-    let path_str = path.display().to_string();
-    writeln!(out, "::group::{}", github_escape(&path_str, false))
+/// The `--annotate-config` header suffix naming the config source, or empty without one.
+fn config_annotation(config: Option<&Path>) -> String {
+    config.map_or_else(String::new, |source| {
+        format!(
+            "  [config: {}]",
+            sanitize_control(&source.display().to_string())
+        )
+    })
+}
+
+/// Like [`render_streaming`], but identical annotations within a file are emitted once and
+/// at most `max_annotations` are emitted across the run, the rest counted in a final
+/// `::notice::` (GitHub caps the annotations it displays per step).
+fn render_github(
+    records: &[FileRecord],
+    paths: ConsolePaths<'_>,
+    max_annotations: Option<usize>,
+    color: bool,
+) -> Vec<u8> {
+    let mut out: Vec<u8> = Vec::new();
+    let mut emitted = 0usize;
+    let mut omitted = 0usize;
+    for record in records {
+        if let Some(message) = &record.error {
+            writeln!(out, "{message}").expect(OUTPUT_INFALLIBLE);
+            continue;
+        }
+        let path_str = console_path(record.path, paths).display().to_string();
+        let escaped_file = github_escape(&path_str, true);
+        let mut seen = HashSet::new();
+        let annotations: Vec<String> = record
+            .kept
+            .iter()
+            .map(|problem| format_github(problem, &escaped_file, color))
+            .filter(|annotation| seen.insert(annotation.clone()))
+            .collect();
+        let room = max_annotations
+            .map_or(annotations.len(), |max| max.saturating_sub(emitted))
+            .min(annotations.len());
+        omitted += annotations.len() - room;
+        if room == 0 {
+            continue;
+        }
+        emitted += room;
+        writeln!(out, "::group::{}", github_escape(&path_str, false))
+            .expect(OUTPUT_INFALLIBLE);
+        for annotation in &annotations[..room] {
+            writeln!(out, "{annotation}").expect(OUTPUT_INFALLIBLE);
+        }
+        writeln!(out, "::endgroup::").expect(OUTPUT_INFALLIBLE);
+        writeln!(out).expect(OUTPUT_INFALLIBLE);
+    }
+    if omitted > 0 {
+        writeln!(
+            out,
+            "::notice::{omitted} more {} omitted by --max-annotations",
+            if omitted == 1 {
+                "annotation"
+            } else {
+                "annotations"
+            }
+        )
         .expect(OUTPUT_INFALLIBLE);
-    let escaped_file = github_escape(&path_str, true);
+    }
+    out
+}
+
+fn append_parsable(
+    out: &mut Vec<u8>,
+    path: &Path,
+    problems: &[LintProblem],
+    show_rule_url: bool,
+) {
+    let sanitized_path = sanitize_control(&path.display().to_string()).into_owned();
     for problem in problems {
-        writeln!(out, "{}", format_github(problem, &escaped_file))
+        writeln!(
+            out,
+            "{}",
+            format_parsable(problem, &sanitized_path, show_rule_url)
+        )
+        .expect(OUTPUT_INFALLIBLE);
+    }
+}
+
+fn append_concise(out: &mut Vec<u8>, path: &Path, problems: &[LintProblem]) {
+    let sanitized_path = sanitize_control(&path.display().to_string()).into_owned();
+    for problem in problems {
+        writeln!(out, "{}", format_concise(problem, &sanitized_path))
             .expect(OUTPUT_INFALLIBLE);
     }
-    writeln!(out, "::endgroup::").expect(OUTPUT_INFALLIBLE);
-    writeln!(out).expect(OUTPUT_INFALLIBLE);
 }
 
-fn append_parsable(out: &mut Vec<u8>, path: &Path, problems: &[LintProblem]) {
+fn append_template(
+    out: &mut Vec<u8>,
+    path: &Path,
+    problems: &[LintProblem],
+    template: &FormatTemplate,
+) {
     let sanitized_path = sanitize_control(&path.display().to_string()).into_owned();
     for problem in problems {
-        writeln!(out, "{}", format_parsable(problem, &sanitized_path))
+        writeln!(out, "{}", template.render(&sanitized_path, problem))
             .expect(OUTPUT_INFALLIBLE);
     }
 }
@@ -1614,10 +2598,20 @@ fn format_colored(problem: &LintProblem) -> String {
     line
 }
 
-/// `escaped_file` is the `file=` property value, escaped once per file by the caller.
-fn format_github(problem: &LintProblem, escaped_file: &str) -> String {
+/// `escaped_file` is the `file=` property value, escaped once per file by the caller. With
+/// `color`, only the human-readable message after the `::` is coloured, as in `colored`.
+fn format_github(problem: &LintProblem, escaped_file: &str, color: bool) -> String {
+    let (dim, level, reset) = if color {
+        let level = match problem.level {
+            Severity::Warning => "\u{001b}[33m",
+            Severity::Error => "\u{001b}[31m",
+        };
+        ("\u{001b}[2m", level, "\u{001b}[0m")
+    } else {
+        ("", "", "")
+    };
     let mut line = format!(
-        "::{} file={escaped_file},line={},col={}::{}:{} ",
+        "::{} file={escaped_file},line={},col={}::{dim}{}:{}{reset} ",
         problem.level.as_str(),
         problem.line,
         problem.column,
@@ -1625,16 +2619,38 @@ fn format_github(problem: &LintProblem, escaped_file: &str) -> String {
         problem.column
     );
     if let Some(rule) = problem.rule {
+        line.push_str(dim);
         line.push('[');
         line.push_str(rule);
-        line.push_str("] ");
+        line.push(']');
+        line.push_str(reset);
+        line.push(' ');
     }
+    line.push_str(level);
     line.push_str(&github_escape(&problem.message, false));
+    line.push_str(reset);
+    line
+}
+
+/// `path:line:col rule message`, for logs that truncate long output (pre-commit); a syntax
+/// error has no rule, so its message follows the position directly.
+fn format_concise(problem: &LintProblem, sanitized_path: &str) -> String {
+    let mut line = format!("{sanitized_path}:{}:{} ", problem.line, problem.column);
+    if let Some(rule) = problem.rule {
+        line.push_str(rule);
+        line.push(' ');
+    }
+    line.push_str(&sanitize_control(&problem.message));
     line
 }
 
-/// `sanitized_path` is sanitized once per file by the caller.
-fn format_parsable(problem: &LintProblem, sanitized_path: &str) -> String {
+/// `sanitized_path` is sanitized once per file by the caller. With `show_rule_url`, the
+/// rule's docs URL follows the rule id; a syntax error has no rule and so no URL.
+fn format_parsable(
+    problem: &LintProblem,
+    sanitized_path: &str,
+    show_rule_url: bool,
+) -> String {
     let mut line = format!(
         "{sanitized_path}:{}:{}: [{}] {}",
         problem.line,
@@ -1646,6 +2662,10 @@ fn format_parsable(problem: &LintProblem, sanitized_path: &str) -> String {
         line.push_str(" (");
         line.push_str(rule);
         line.push(')');
+        if show_rule_url && ALL_RULE_IDS.contains(&rule) {
+            line.push(' ');
+            line.push_str(&rule_docs_url(rule));
+        }
     }
     line
 }
@@ -6,7 +6,7 @@ use std::path::Path;
 use super::{EmbeddedRegion, MarkdownSources, extract_regions};
 use crate::config::YamlLintConfig;
 use crate::fix::suppressed_rules;
-use crate::lint::{LintProblem, lint_str};
+use crate::lint::{LintProblem, RuleTiming, lint_str_with};
 
 /// Lint every embedded YAML region in `markdown` and return diagnostics whose line/column
 /// point into the original markdown document. Each region is linted as an independent
@@ -17,6 +17,28 @@ pub fn lint_markdown_str(
     path: &Path,
     cfg: &YamlLintConfig,
     base_dir: &Path,
+) -> Vec<LintProblem> {
+    lint_markdown_with(markdown, path, cfg, base_dir, None)
+}
+
+/// [`lint_markdown_str`], also appending to `timings` how long each rule took per region.
+#[must_use]
+pub fn lint_markdown_str_timed(
+    markdown: &str,
+    path: &Path,
+    cfg: &YamlLintConfig,
+    base_dir: &Path,
+    timings: &mut Vec<RuleTiming>,
+) -> Vec<LintProblem> {
+    lint_markdown_with(markdown, path, cfg, base_dir, Some(timings))
+}
+
+fn lint_markdown_with(
+    markdown: &str,
+    path: &Path,
+    cfg: &YamlLintConfig,
+    base_dir: &Path,
+    mut timings: Option<&mut Vec<RuleTiming>>,
 ) -> Vec<LintProblem> {
     if super::markdown_has_unsupported_cr(markdown) {
         return vec![super::unsupported_cr_skip()];
@@ -32,7 +54,8 @@ pub fn lint_markdown_str(
         if region.content.trim().is_empty() {
             continue;
         }
-        let mut region_problems = lint_str(&region.content, path, cfg, base_dir);
+        let mut region_problems =
+            lint_str_with(&region.content, path, cfg, base_dir, timings.as_deref_mut());
         region_problems
             .retain(|problem| !problem.rule.is_some_and(|id| suppressed.contains(&id)));
         if region_problems.is_empty() {
@@ -7,7 +7,7 @@
 
 mod lint;
 
-pub use lint::{lint_markdown_str, markdown_parse_skips};
+pub use lint::{lint_markdown_str, lint_markdown_str_timed, markdown_parse_skips};
 
 use std::ops::Range;
 
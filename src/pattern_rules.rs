@@ -0,0 +1,241 @@
+//! User-defined pattern rules loaded from a `--rulesdir` directory.
+//!
+//! Each `*.yaml`/`*.yml` file in the directory declares one rule: a name, a regex, and
+//! whether it matches mapping keys, scalar values, or raw lines. Pattern rules run after
+//! the built-in rules and report through the same [`LintProblem`] pipeline, so inline
+//! directives suppress them and every output format renders them like any other rule.
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use granit_parser::{Event, Span, SpannedEventReceiver};
+use regex::Regex;
+
+use crate::config::RuleLevel;
+use crate::lint::{LintProblem, Severity};
+use crate::rules::ALL_RULE_IDS;
+use crate::rules::support::events::ParsedEvents;
+use crate::rules::support::line_syntax::line_contents;
+use crate::rules::support::mapping_key_walker::Walker;
+use crate::yaml_dom::YamlOwned;
+
+/// Which part of the document a [`PatternRule`] matches against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PatternScope {
+    /// Scalar mapping keys.
+    Keys,
+    /// Scalar values: mapping values and sequence items.
+    Values,
+    /// Each raw source line, comments included.
+    Lines,
+}
+
+impl PatternScope {
+    fn parse(raw: &str) -> Option<Self> {
+        match raw {
+            "keys" => Some(Self::Keys),
+            "values" => Some(Self::Values),
+            "lines" => Some(Self::Lines),
+            _ => None,
+        }
+    }
+}
+
+/// One rule declared by a `--rulesdir` file.
+#[derive(Debug, Clone)]
+pub struct PatternRule {
+    id: &'static str,
+    regex: Regex,
+    scope: PatternScope,
+    level: RuleLevel,
+    message: String,
+}
+
+impl PatternRule {
+    /// The rule name diagnostics are reported under.
+    #[must_use]
+    pub const fn id(&self) -> &'static str {
+        self.id
+    }
+
+    #[must_use]
+    pub const fn scope(&self) -> PatternScope {
+        self.scope
+    }
+
+    /// Every match in `content`: at most one per line for [`PatternScope::Lines`] (at the
+    /// match), one per matching scalar otherwise (at the scalar's start, replayed from the
+    /// buffer's shared `events`).
+    #[must_use]
+    pub(crate) fn check(
+        &self,
+        content: &str,
+        events: &ParsedEvents<'_>,
+    ) -> Vec<LintProblem> {
+        let positions = match self.scope {
+            PatternScope::Lines => self.line_matches(content),
+            PatternScope::Keys | PatternScope::Values => self.scalar_matches(events),
+        };
+        positions
+            .into_iter()
+            .map(|(line, column)| LintProblem {
+                line,
+                column,
+                level: Severity::from(self.level),
+                message: self.message.clone(),
+                rule: Some(self.id),
+            })
+            .collect()
+    }
+
+    fn line_matches(&self, content: &str) -> Vec<(usize, usize)> {
+        line_contents(content)
+            .into_iter()
+            .enumerate()
+            .filter_map(|(index, line)| {
+                let found = self.regex.find(line)?;
+                Some((index + 1, line[..found.start()].chars().count() + 1))
+            })
+            .collect()
+    }
+
+    fn scalar_matches(&self, events: &ParsedEvents<'_>) -> Vec<(usize, usize)> {
+        let mut receiver = ScalarReceiver {
+            rule: self,
+            walker: Walker::new(),
+            hits: Vec::new(),
+        };
+        events.replay(&mut receiver);
+        receiver.hits
+    }
+}
+
+struct ScalarReceiver<'r> {
+    rule: &'r PatternRule,
+    walker: Walker<()>,
+    hits: Vec<(usize, usize)>,
+}
+
+impl SpannedEventReceiver<'_> for ScalarReceiver<'_> {
+    fn on_event(&mut self, event: Event<'_>, span: Span) {
+        match event {
+            Event::StreamStart => self.walker.reset(),
+            Event::MappingStart(..) => self.walker.enter_mapping((), ()),
+            Event::SequenceStart(..) => self.walker.enter_sequence(()),
+            Event::MappingEnd | Event::SequenceEnd => self.walker.exit_container(),
+            Event::Scalar(value, ..) => {
+                let context = self.walker.begin_node();
+                // Scalars nested inside a complex key are neither keys nor values.
+                let wanted = match self.rule.scope {
+                    PatternScope::Keys => context.key_root(),
+                    PatternScope::Values => !context.active(),
+                    PatternScope::Lines => false,
+                };
+                if wanted && self.rule.regex.is_match(&value) {
+                    self.hits.push((span.start.line(), span.start.col() + 1));
+                }
+                self.walker.finish_node(context);
+            }
+            Event::Alias(_) => self.walker.skip_node(),
+            _ => {}
+        }
+    }
+}
+
+/// Load every `*.yaml`/`*.yml` rule file directly inside `dir`, in file-name order.
+///
+/// # Errors
+///
+/// Returns an error if `dir` cannot be read, a rule file is invalid, or a rule name
+/// repeats another pattern rule or a built-in rule.
+pub fn load_rules_dir(dir: &Path) -> Result<Vec<PatternRule>, String> {
+    let entries = fs::read_dir(dir).map_err(|err| {
+        format!("failed to read rules directory {}: {err}", dir.display())
+    })?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(Result::ok)
+        .map(|entry| entry.path())
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .and_then(|ext| ext.to_str())
+                    .is_some_and(|ext| ext == "yaml" || ext == "yml")
+        })
+        .collect();
+    paths.sort();
+
+    let mut rules: Vec<PatternRule> = Vec::with_capacity(paths.len());
+    for path in paths {
+        // Rule files are config, so they keep encoding detection under `--input-encoding`.
+        let source = std::fs::read(&path)
+            .map_err(|err| err.to_string())
+            .and_then(|bytes| crate::decoder::decode_bytes(&bytes))
+            .map_err(|err| format!("failed to read {}: {err}", path.display()))?;
+        let rule = parse_rule(&source)
+            .map_err(|err| format!("invalid rule file {}: {err}", path.display()))?;
+        if rules.iter().any(|seen| seen.id == rule.id) {
+            return Err(format!(
+                "invalid rule file {}: rule `{}` is already defined",
+                path.display(),
+                rule.id
+            ));
+        }
+        rules.push(rule);
+    }
+    Ok(rules)
+}
+
+fn parse_rule(source: &str) -> Result<PatternRule, String> {
+    let docs = YamlOwned::load_from_str(source).map_err(|err| err.to_string())?;
+    let doc = match docs.as_slice() {
+        [doc] if doc.as_mapping().is_some() => doc,
+        _ => return Err("expected a single mapping".to_string()),
+    };
+    let string_field = |key: &str| -> Result<Option<&str>, String> {
+        match doc.as_mapping_get(key) {
+            None => Ok(None),
+            Some(value) => value
+                .as_str()
+                .map(Some)
+                .ok_or_else(|| format!("`{key}` should be a string")),
+        }
+    };
+
+    let name = string_field("rule")?.ok_or("missing `rule`")?;
+    if name.is_empty() || name.chars().any(char::is_whitespace) {
+        return Err("`rule` should be a non-empty name without whitespace".to_string());
+    }
+    if ALL_RULE_IDS.contains(&name) {
+        return Err(format!("`{name}` is a built-in rule"));
+    }
+    let pattern = string_field("pattern")?.ok_or("missing `pattern`")?;
+    let regex = Regex::new(pattern)
+        .map_err(|err| format!("invalid `pattern` regex `{pattern}`: {err}"))?;
+    let scope = string_field("scope")?.ok_or("missing `scope`")?;
+    let scope = PatternScope::parse(scope).ok_or_else(|| {
+        format!("`scope` should be \"keys\", \"values\", or \"lines\", not \"{scope}\"")
+    })?;
+    let level = match string_field("level")? {
+        None | Some("error") => RuleLevel::Error,
+        Some("warning") => RuleLevel::Warning,
+        Some(other) => {
+            return Err(format!(
+                "`level` should be \"error\" or \"warning\", not \"{other}\""
+            ));
+        }
+    };
+    let message = string_field("message")?.map_or_else(
+        || format!("found forbidden pattern \"{pattern}\""),
+        str::to_string,
+    );
+
+    Ok(PatternRule {
+        // Leaked once per loaded rule so its diagnostics carry a `&'static str` id, like
+        // the built-in rules'.
+        id: Box::leak(name.to_string().into_boxed_str()),
+        regex,
+        scope,
+        level,
+        message,
+    })
+}
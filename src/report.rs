@@ -1,9 +1,10 @@
-//! Whole-document report formats: `JUnit` XML and `GitLab` code quality JSON.
+//! Whole-document report formats: `JUnit` XML, `GitLab` code quality JSON, Code Climate
+//! issue JSON, SARIF, GitHub pull-request review comments, and a flat JSON problem list.
 //!
 //! All user text is sanitized before serialization so it cannot break out of the
 //! structure: `JUnit` via `xml_sanitize` (control chars plus the U+FFFE/U+FFFF
-//! noncharacters XML forbids), `GitLab` via [`sanitize_control`], then quick-xml /
-//! `serde_json` apply structural escaping.
+//! noncharacters XML forbids), the JSON formats via [`sanitize_control`], then
+//! quick-xml / `serde_json` apply structural escaping.
 
 use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
@@ -17,6 +18,7 @@ use sha2::{Digest, Sha256};
 
 use crate::cli_support::sanitize_control;
 use crate::lint::{LintProblem, Severity};
+use crate::rules::{rule_docs_url, rule_summary};
 
 // Writes target an owned `Vec<u8>` whose `io::Write` never fails, so each write is an
 // `expect` rather than a `?` that would leave a dead, uncovered error arm.
@@ -272,6 +274,25 @@ fn make_issue(
     severity: &'static str,
     seen: &mut HashSet<String>,
 ) -> GitlabIssue {
+    GitlabIssue {
+        description: sanitize_control(message).into_owned(),
+        check_name: check_name.to_string(),
+        severity,
+        fingerprint: unique_fingerprint(path, check_name, message, seen),
+        location: GitlabLocation {
+            path: path.to_string(),
+            lines: GitlabLines { begin: line },
+        },
+    }
+}
+
+/// The diagnostic's [`fingerprint`], unique among those already in `seen`.
+fn unique_fingerprint(
+    path: &str,
+    check_name: &str,
+    message: &str,
+    seen: &mut HashSet<String>,
+) -> String {
     // GitLab requires fingerprints unique within a report, so two findings sharing the same
     // (path, rule, message) are disambiguated by an encounter-order salt: identity is then
     // stable under line shifts but a duplicate reordered ahead of another reassigns its salt
@@ -282,16 +303,7 @@ fn make_issue(
         salt += 1;
         hash = fingerprint(path, check_name, message, salt);
     }
-    GitlabIssue {
-        description: sanitize_control(message).into_owned(),
-        check_name: check_name.to_string(),
-        severity,
-        fingerprint: hash,
-        location: GitlabLocation {
-            path: path.to_string(),
-            lines: GitlabLines { begin: line },
-        },
-    }
+    hash
 }
 
 /// SHA-256 hex of the diagnostic's identity `(path, rule, message)`. Excludes line/column
@@ -313,3 +325,427 @@ fn fingerprint(path: &str, check_name: &str, message: &str, salt: u64) -> String
     }
     out
 }
+
+#[derive(Serialize)]
+struct CodeClimateIssue {
+    #[serde(rename = "type")]
+    kind: &'static str,
+    check_name: String,
+    description: String,
+    content: CodeClimateContent,
+    categories: [&'static str; 1],
+    location: GitlabLocation,
+    fingerprint: String,
+    severity: &'static str,
+}
+
+#[derive(Serialize)]
+struct CodeClimateContent {
+    body: String,
+}
+
+/// Render every diagnostic as a Code Climate issue JSON array: the `GitLab` fields plus
+/// `type`, `categories`, and a Markdown `content.body` explaining the rule. Fingerprints
+/// match `--format gitlab`'s, so a platform tracks the same issue across both formats.
+///
+/// # Panics
+///
+/// Does not panic in practice: serialization targets an in-memory buffer.
+#[must_use]
+pub fn render_codeclimate(entries: &[ReportEntry]) -> Vec<u8> {
+    let mut issues: Vec<CodeClimateIssue> = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    for entry in entries {
+        if let Some(error) = &entry.error {
+            let description = sanitize_control(error).into_owned();
+            issues.push(CodeClimateIssue {
+                kind: "issue",
+                check_name: "error".to_string(),
+                content: CodeClimateContent {
+                    body: "ryl could not read or process this file.".to_string(),
+                },
+                categories: ["Bug Risk"],
+                location: GitlabLocation {
+                    path: entry.path.clone(),
+                    lines: GitlabLines { begin: 1 },
+                },
+                fingerprint: unique_fingerprint(&entry.path, "error", error, &mut seen),
+                severity: "blocker",
+                description,
+            });
+        } else {
+            for problem in &entry.problems {
+                let check_name = problem.rule.unwrap_or("syntax");
+                issues.push(CodeClimateIssue {
+                    kind: "issue",
+                    check_name: check_name.to_string(),
+                    description: sanitize_control(&problem.message).into_owned(),
+                    content: CodeClimateContent {
+                        body: codeclimate_body(problem.rule),
+                    },
+                    categories: [if problem.rule.is_some() {
+                        "Style"
+                    } else {
+                        "Bug Risk"
+                    }],
+                    location: GitlabLocation {
+                        path: entry.path.clone(),
+                        lines: GitlabLines {
+                            begin: problem.line,
+                        },
+                    },
+                    fingerprint: unique_fingerprint(
+                        &entry.path,
+                        check_name,
+                        &problem.message,
+                        &mut seen,
+                    ),
+                    severity: gitlab_severity(problem.level),
+                });
+            }
+        }
+    }
+
+    let mut buffer = serde_json::to_vec(&issues)
+        .expect("serializing report issues to a Vec cannot fail");
+    buffer.push(b'\n');
+    buffer
+}
+
+/// The Markdown explanation for a diagnostic from `rule` (`None` for a syntax error): the
+/// rule's summary and docs link, or a generic line for a `--rulesdir` pattern rule.
+fn codeclimate_body(rule: Option<&str>) -> String {
+    match rule {
+        None => "The file is not valid YAML, so no rule could check it.".to_string(),
+        Some(id) => match rule_summary(id) {
+            Some(summary) => format!(
+                "`{id}`: {summary}\n\nSee [the `{id}` rule reference]({}).",
+                rule_docs_url(id)
+            ),
+            None => format!("`{id}` is a pattern rule loaded from `--rulesdir`."),
+        },
+    }
+}
+
+#[derive(Serialize)]
+struct SarifLog {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [SarifRun; 1],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRun {
+    tool: SarifTool,
+    invocations: [SarifInvocation; 1],
+    column_kind: &'static str,
+    results: Vec<SarifResult>,
+}
+
+#[derive(Serialize)]
+struct SarifTool {
+    driver: SarifDriver,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifDriver {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<SarifRule>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRule {
+    id: String,
+    name: String,
+    short_description: SarifMessage,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_uri: Option<String>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifInvocation {
+    execution_successful: bool,
+    tool_execution_notifications: Vec<SarifNotification>,
+}
+
+#[derive(Serialize)]
+struct SarifNotification {
+    level: &'static str,
+    message: SarifMessage,
+    locations: [SarifLocation; 1],
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult {
+    rule_id: String,
+    rule_index: usize,
+    level: &'static str,
+    message: SarifMessage,
+    locations: [SarifLocation; 1],
+}
+
+#[derive(Serialize)]
+struct SarifMessage {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifLocation {
+    physical_location: SarifPhysicalLocation,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifPhysicalLocation {
+    artifact_location: SarifArtifactLocation,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    region: Option<SarifRegion>,
+}
+
+#[derive(Serialize)]
+struct SarifArtifactLocation {
+    uri: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifRegion {
+    start_line: usize,
+    start_column: usize,
+}
+
+/// Render every diagnostic as a SARIF 2.1.0 log with a single run. `tool.driver.rules`
+/// describes each rule that produced a result (id, summary, and docs link), in order of
+/// first appearance, and each result points at its rule by `ruleIndex`. A processing
+/// error is a tool execution notification rather than a result.
+///
+/// # Panics
+///
+/// Does not panic in practice: serialization targets an in-memory buffer.
+#[must_use]
+pub fn render_sarif(entries: &[ReportEntry]) -> Vec<u8> {
+    let mut rules: Vec<SarifRule> = Vec::new();
+    let mut rule_indices: HashMap<&str, usize> = HashMap::new();
+    let mut results: Vec<SarifResult> = Vec::new();
+    let mut notifications: Vec<SarifNotification> = Vec::new();
+    for entry in entries {
+        let uri = sarif_uri(&entry.path);
+        if let Some(error) = &entry.error {
+            notifications.push(SarifNotification {
+                level: "error",
+                message: SarifMessage {
+                    text: sanitize_control(error).into_owned(),
+                },
+                locations: [sarif_location(uri, None)],
+            });
+            continue;
+        }
+        for problem in &entry.problems {
+            let rule_id = problem.rule.unwrap_or("syntax");
+            let rule_index = *rule_indices.entry(rule_id).or_insert_with(|| {
+                rules.push(sarif_rule(problem.rule));
+                rules.len() - 1
+            });
+            results.push(SarifResult {
+                rule_id: rule_id.to_string(),
+                rule_index,
+                level: problem.level.as_str(),
+                message: SarifMessage {
+                    text: sanitize_control(&problem.message).into_owned(),
+                },
+                locations: [sarif_location(
+                    uri.clone(),
+                    Some(SarifRegion {
+                        start_line: problem.line,
+                        start_column: problem.column,
+                    }),
+                )],
+            });
+        }
+    }
+
+    let log = SarifLog {
+        schema: "https://json.schemastore.org/sarif-2.1.0.json",
+        version: "2.1.0",
+        runs: [SarifRun {
+            tool: SarifTool {
+                driver: SarifDriver {
+                    name: "ryl",
+                    version: env!("CARGO_PKG_VERSION"),
+                    information_uri: "https://ryl-docs.pages.dev/",
+                    rules,
+                },
+            },
+            invocations: [SarifInvocation {
+                execution_successful: notifications.is_empty(),
+                tool_execution_notifications: notifications,
+            }],
+            // ryl columns count characters, not SARIF's default UTF-16 code units.
+            column_kind: "unicodeCodePoints",
+            results,
+        }],
+    };
+    let mut buffer =
+        serde_json::to_vec(&log).expect("serializing a SARIF log to a Vec cannot fail");
+    buffer.push(b'\n');
+    buffer
+}
+
+/// The `tool.driver.rules` entry for `rule` (`None` for a syntax error), with the same
+/// summary and docs link as [`codeclimate_body`].
+fn sarif_rule(rule: Option<&str>) -> SarifRule {
+    let (id, text, help_uri) = match rule {
+        None => (
+            "syntax",
+            "The file is not valid YAML, so no rule could check it.".to_string(),
+            None,
+        ),
+        Some(id) => match rule_summary(id) {
+            Some(summary) => (id, summary.to_string(), Some(rule_docs_url(id))),
+            None => (
+                id,
+                "Pattern rule loaded from `--rulesdir`.".to_string(),
+                None,
+            ),
+        },
+    };
+    SarifRule {
+        id: id.to_string(),
+        name: id.to_string(),
+        short_description: SarifMessage { text },
+        help_uri,
+    }
+}
+
+const fn sarif_location(uri: String, region: Option<SarifRegion>) -> SarifLocation {
+    SarifLocation {
+        physical_location: SarifPhysicalLocation {
+            artifact_location: SarifArtifactLocation { uri },
+            region,
+        },
+    }
+}
+
+/// A relative URI reference for a display path: bytes outside RFC 3986's unreserved set
+/// (and `/`) are percent-encoded, so a space or `#` in a file name stays part of the path.
+fn sarif_uri(path: &str) -> String {
+    let mut uri = String::with_capacity(path.len());
+    for byte in path.bytes() {
+        if byte.is_ascii_alphanumeric()
+            || matches!(byte, b'-' | b'.' | b'_' | b'~' | b'/')
+        {
+            uri.push(char::from(byte));
+        } else {
+            write!(uri, "%{byte:02X}").expect("writing to a String is infallible");
+        }
+    }
+    uri
+}
+
+#[derive(Serialize)]
+struct ReviewComment {
+    path: String,
+    line: usize,
+    side: &'static str,
+    body: String,
+}
+
+/// Render every diagnostic as a pull-request review comment: the `{path, line, side, body}`
+/// objects GitHub's create-review API takes in `comments`. Each sits on the `RIGHT` (new)
+/// side of the diff, and the Markdown body names the level, rule, and column. A processing
+/// error comments on line 1.
+///
+/// # Panics
+///
+/// Does not panic in practice: serialization targets an in-memory buffer.
+#[must_use]
+pub fn render_review(entries: &[ReportEntry]) -> Vec<u8> {
+    let mut comments: Vec<ReviewComment> = Vec::new();
+    for entry in entries {
+        if let Some(error) = &entry.error {
+            comments.push(ReviewComment {
+                path: entry.path.clone(),
+                line: 1,
+                side: "RIGHT",
+                body: format!("**error**: {}", sanitize_control(error)),
+            });
+        } else {
+            for problem in &entry.problems {
+                comments.push(ReviewComment {
+                    path: entry.path.clone(),
+                    line: problem.line,
+                    side: "RIGHT",
+                    body: format!(
+                        "**{}** `{}`: {} (column {})",
+                        problem.level.as_str(),
+                        problem.rule.unwrap_or("syntax"),
+                        sanitize_control(&problem.message),
+                        problem.column
+                    ),
+                });
+            }
+        }
+    }
+
+    let mut buffer = serde_json::to_vec(&comments)
+        .expect("serializing review comments to a Vec cannot fail");
+    buffer.push(b'\n');
+    buffer
+}
+
+#[derive(Serialize)]
+struct JsonProblem<'a> {
+    path: &'a str,
+    line: usize,
+    column: usize,
+    level: &'static str,
+    rule: Option<&'a str>,
+    message: Cow<'a, str>,
+}
+
+/// Render every diagnostic as one flat JSON array of `{path, line, column, level, rule,
+/// message}` objects. `rule` is `null` for a syntax error and for a file that could not be
+/// processed (reported as an error at 1:1).
+///
+/// # Panics
+///
+/// Does not panic in practice: serialization targets an in-memory buffer.
+#[must_use]
+pub fn render_json(entries: &[ReportEntry]) -> Vec<u8> {
+    let mut problems: Vec<JsonProblem> = Vec::new();
+    for entry in entries {
+        if let Some(error) = &entry.error {
+            problems.push(JsonProblem {
+                path: &entry.path,
+                line: 1,
+                column: 1,
+                level: Severity::Error.as_str(),
+                rule: None,
+                message: sanitize_control(error),
+            });
+        } else {
+            problems.extend(entry.problems.iter().map(|problem| JsonProblem {
+                path: &entry.path,
+                line: problem.line,
+                column: problem.column,
+                level: problem.level.as_str(),
+                rule: problem.rule,
+                message: sanitize_control(&problem.message),
+            }));
+        }
+    }
+
+    let mut buffer = serde_json::to_vec(&problems)
+        .expect("serializing report problems to a Vec cannot fail");
+    buffer.push(b'\n');
+    buffer
+}
@@ -9,9 +9,9 @@
 use std::collections::HashSet;
 
 use crate::config::YamlLintConfig;
+use crate::rules::support::events::ParsedEvents;
 use crate::rules::support::punctuation::{
-    build_line_starts, collect_alias_ends, collect_scalar_ranges, line_and_column,
-    skip_comment,
+    build_line_starts, collect_alias_ends, line_and_column, scalar_ranges, skip_comment,
 };
 use crate::rules::support::span_utils::{CharPos, containing_scalar_range};
 
@@ -84,11 +84,19 @@ enum AfterResult {
 
 #[must_use]
 pub fn check(buffer: &str, cfg: &Config) -> Vec<Violation> {
+    check_events(buffer, &ParsedEvents::parse(buffer), cfg)
+}
+
+pub(crate) fn check_events(
+    buffer: &str,
+    events: &ParsedEvents<'_>,
+    cfg: &Config,
+) -> Vec<Violation> {
     if buffer.is_empty() {
         return Vec::new();
     }
 
-    let scalar_ranges = collect_scalar_ranges(buffer);
+    let scalar_ranges = scalar_ranges(events);
     let alias_ends: HashSet<usize> = collect_alias_ends(buffer)
         .iter()
         .map(|pos| pos.get())
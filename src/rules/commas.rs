@@ -2,9 +2,10 @@
 //! exactly one after. Mirrors yamllint's `commas`. Safe `--fix` normalises the spacing.
 
 use crate::config::YamlLintConfig;
+use crate::rules::support::events::ParsedEvents;
 use crate::rules::support::punctuation::{
-    build_line_starts, collect_scalar_ranges, line_and_column, skip_comment,
-    template_double_curly_end,
+    build_line_starts, collect_scalar_ranges, line_and_column, scalar_ranges,
+    skip_comment, template_double_curly_end,
 };
 use crate::rules::support::span_utils::{
     BytePos, CharPos, apply_replacements, containing_scalar_range,
@@ -101,11 +102,19 @@ enum AfterResult {
 
 #[must_use]
 pub fn check(buffer: &str, cfg: &Config) -> Vec<Violation> {
+    check_events(buffer, &ParsedEvents::parse(buffer), cfg)
+}
+
+pub(crate) fn check_events(
+    buffer: &str,
+    events: &ParsedEvents<'_>,
+    cfg: &Config,
+) -> Vec<Violation> {
     if buffer.is_empty() {
         return Vec::new();
     }
 
-    let scalar_ranges = collect_scalar_ranges(buffer);
+    let scalar_ranges = scalar_ranges(events);
     let chars: Vec<(usize, char)> = buffer.char_indices().collect();
     let line_starts = build_line_starts(&chars);
 
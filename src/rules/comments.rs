@@ -1,8 +1,12 @@
 //! `comments`: `#` comment formatting: a required space after the `#`, a minimum
 //! gap from preceding inline content, and an optional shebang exemption. Mirrors
 //! yamllint's `comments`. Safe `--fix` inserts the missing spaces.
+//!
+//! The ryl-only, TOML-only `require-content-after-hash` regex (default unset) must match
+//! the comment text after the `#`s and any spaces; it is reported, never fixed.
 
 use granit_parser::Placement;
+use regex::Regex;
 
 use crate::config::YamlLintConfig;
 use crate::rules::support::comments_scan::collect_comments;
@@ -10,14 +14,19 @@ use crate::rules::support::span_utils::{BytePos, apply_replacements};
 
 pub const ID: &str = "comments";
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone)]
 pub struct Config {
     require_starting_space: bool,
     ignore_shebangs: bool,
     min_spaces_from_content: Option<usize>,
+    content_after_hash: Option<Regex>,
 }
 
 impl Config {
+    /// # Panics
+    ///
+    /// Panics when `require-content-after-hash` is not a valid regex; config
+    /// validation rejects that before any rule runs.
     #[must_use]
     pub fn resolve(cfg: &YamlLintConfig) -> Self {
         let require_starting_space =
@@ -31,10 +40,17 @@ impl Config {
             Some(usize::try_from(min_spaces_value).unwrap_or(usize::MAX))
         };
 
+        let content_after_hash = cfg
+            .rule_option_str(ID, "require-content-after-hash")
+            .map(|pattern| {
+                Regex::new(pattern).expect("comments require-content-after-hash regex")
+            });
+
         Self {
             require_starting_space,
             ignore_shebangs,
             min_spaces_from_content,
+            content_after_hash,
         }
     }
 
@@ -96,10 +112,6 @@ pub fn check(buffer: &str, cfg: &Config) -> Vec<Violation> {
             }
         }
 
-        if !cfg.require_starting_space() {
-            continue;
-        }
-
         let extra_hashes_count = comment.text.chars().take_while(|c| *c == '#').count();
         let after_hashes = comment.text.trim_start_matches('#');
         let Some(next_char) = after_hashes.chars().next() else {
@@ -110,13 +122,28 @@ pub fn check(buffer: &str, cfg: &Config) -> Vec<Violation> {
             continue;
         }
 
-        if next_char != ' ' {
+        if cfg.require_starting_space() && next_char != ' ' {
             violations.push(Violation {
                 line,
                 column: hash_column + 1 + extra_hashes_count,
                 message: "missing starting space in comment".to_string(),
             });
         }
+
+        if let Some(required) = &cfg.content_after_hash {
+            let content = after_hashes.trim_start_matches([' ', '\t']);
+            if !content.is_empty() && !required.is_match(content) {
+                let skipped = after_hashes.chars().count() - content.chars().count();
+                violations.push(Violation {
+                    line,
+                    column: hash_column + 1 + extra_hashes_count + skipped,
+                    message: format!(
+                        "comment content does not match \"{}\"",
+                        required.as_str()
+                    ),
+                });
+            }
+        }
     }
 
     violations
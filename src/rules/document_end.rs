@@ -1,13 +1,17 @@
 //! `document-end` rule: require (or forbid) the `...` end marker.
 //!
+//! The ryl-only, TOML-only `last-only` option (default off) relaxes `present: true` to
+//! the end of the stream: a document followed by `---` needs no `...`, only the final one.
+//!
 //! `--fix` rewrites only `present: true` on a single-document buffer: multi-document
 //! inputs need per-document end offsets the rule does not record, and removing `...`
 //! (`present: false`) can collide with document boundaries, so neither is fixed.
 use std::cmp;
 
-use granit_parser::{Event, Parser, Span, SpannedEventReceiver};
+use granit_parser::{Event, Span, SpannedEventReceiver};
 
 use crate::config::YamlLintConfig;
+use crate::rules::support::events::ParsedEvents;
 use crate::rules::support::line_syntax::{buffer_newline, line_contents};
 use crate::rules::support::span_utils::{byte_slice, marker_byte_offset};
 
@@ -30,6 +34,7 @@ pub fn classify_document_end_marker_bytes(bytes: &[u8]) -> Option<&'static str>
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Config {
     present: bool,
+    last_only: bool,
 }
 
 impl Config {
@@ -37,12 +42,22 @@ impl Config {
     pub fn resolve(cfg: &YamlLintConfig) -> Self {
         Self {
             present: cfg.rule_option_bool(ID, "present", true),
+            last_only: cfg.rule_option_bool(ID, "last-only", false),
         }
     }
 
     #[must_use]
     pub const fn new_for_tests(present: bool) -> Self {
-        Self { present }
+        Self {
+            present,
+            last_only: false,
+        }
+    }
+
+    #[must_use]
+    pub const fn with_last_only(mut self, value: bool) -> Self {
+        self.last_only = value;
+        self
     }
 
     #[must_use]
@@ -67,9 +82,16 @@ enum Marker {
 
 #[must_use]
 pub fn check(buffer: &str, cfg: &Config) -> Vec<Violation> {
-    let mut parser = Parser::new_from_str(buffer);
-    let mut receiver = DocumentEndReceiver::new(buffer, cfg);
-    let _ = parser.load(&mut receiver, true);
+    check_events(buffer, &ParsedEvents::parse(buffer), *cfg)
+}
+
+pub(crate) fn check_events<'input>(
+    buffer: &'input str,
+    events: &ParsedEvents<'input>,
+    cfg: Config,
+) -> Vec<Violation> {
+    let mut receiver = DocumentEndReceiver::new(buffer, &cfg);
+    events.replay(&mut receiver);
     receiver.violations
 }
 
@@ -152,6 +174,9 @@ impl<'src, 'cfg> DocumentEndReceiver<'src, 'cfg> {
             Marker::ExplicitEnd => {
                 self.pending_stream_end_violation = false;
             }
+            Marker::DocumentStart if self.config.last_only => {
+                self.pending_stream_end_violation = false;
+            }
             Marker::DocumentStart => {
                 self.pending_stream_end_violation = false;
                 self.violations.push(Violation {
@@ -5,9 +5,10 @@
 //! buffer start cannot repair a later document's missing marker (it would create an
 //! extra empty leading document), and removing `---` (`present: false`) can collide
 //! with document boundaries, so neither is fixed.
-use granit_parser::{Event, Parser, Span, SpannedEventReceiver};
+use granit_parser::{Event, Span, SpannedEventReceiver};
 
 use crate::config::YamlLintConfig;
+use crate::rules::support::events::ParsedEvents;
 use crate::rules::support::line_syntax::{buffer_newline, line_contents};
 
 pub const ID: &str = "document-start";
@@ -47,9 +48,12 @@ pub struct Violation {
 
 #[must_use]
 pub fn check(buffer: &str, cfg: &Config) -> Vec<Violation> {
-    let mut parser = Parser::new_from_str(buffer);
-    let mut receiver = DocumentStartReceiver::new(cfg);
-    let _ = parser.load(&mut receiver, true);
+    check_events(&ParsedEvents::parse(buffer), *cfg)
+}
+
+pub(crate) fn check_events(events: &ParsedEvents<'_>, cfg: Config) -> Vec<Violation> {
+    let mut receiver = DocumentStartReceiver::new(&cfg);
+    events.replay(&mut receiver);
     receiver.violations
 }
 
@@ -3,9 +3,10 @@
 //! safe `--fix`: the rule exists to make the author choose between `~`, `null`, or
 //! restructuring, so auto-inserting a literal would contradict its purpose.
 
-use granit_parser::{Event, Parser, Span, SpannedEventReceiver};
+use granit_parser::{Event, Span, SpannedEventReceiver};
 
 use crate::config::YamlLintConfig;
+use crate::rules::support::events::ParsedEvents;
 
 pub const ID: &str = "empty-values";
 
@@ -52,9 +53,16 @@ pub struct Violation {
 
 #[must_use]
 pub fn check(buffer: &str, cfg: &Config) -> Vec<Violation> {
-    let mut parser = Parser::new_from_str(buffer);
-    let mut receiver = EmptyValuesReceiver::new(cfg);
-    let _ = parser.load(&mut receiver, true);
+    check_events(buffer, &ParsedEvents::parse(buffer), *cfg)
+}
+
+pub(crate) fn check_events<'input>(
+    buffer: &'input str,
+    events: &ParsedEvents<'input>,
+    cfg: Config,
+) -> Vec<Violation> {
+    let mut receiver = EmptyValuesReceiver::new(&cfg);
+    events.replay(&mut receiver);
     // A tagged/anchored empty value (e.g. `a: !!str`) is an implicit scalar
     // that granit positions at a virtual spot which can fall past the document;
     // keep the report on a real position.
@@ -67,10 +75,32 @@ pub fn check(buffer: &str, cfg: &Config) -> Vec<Violation> {
         );
         violation.line = line;
         violation.column = column;
+        if violation.message == BLOCK_SEQUENCE_MESSAGE {
+            violation.column = entry_indicator_column(buffer, line, column);
+        }
     }
     diagnostics
 }
 
+/// yamllint reports an empty sequence entry just after its `-`, but granit places the
+/// empty scalar past any spaces or comment that follow it; walk back to the indicator.
+fn entry_indicator_column(buffer: &str, line: usize, column: usize) -> usize {
+    let Some(text) = buffer.lines().nth(line - 1) else {
+        return column;
+    };
+    let prefix: Vec<char> = text.chars().take(column - 1).collect();
+    let code_end = prefix
+        .iter()
+        .enumerate()
+        .find(|&(idx, &ch)| ch == '#' && (idx == 0 || prefix[idx - 1].is_whitespace()))
+        .map_or(prefix.len(), |(idx, _)| idx);
+    let code = &prefix[..code_end];
+    match code.iter().rposition(|ch| !ch.is_whitespace()) {
+        Some(idx) if code[idx] == '-' => idx + 2,
+        _ => column,
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 enum MappingStyle {
     Block,
@@ -4,9 +4,10 @@
 //! safe `--fix`: rewrites such as `.5` to `0.5` or expanding `1e3` to `1000` change the
 //! scalar's text and, for tagged or string consumers, its value.
 
-use granit_parser::{Event, Parser, ScalarStyle, Span, SpannedEventReceiver};
+use granit_parser::{Event, ScalarStyle, Span, SpannedEventReceiver};
 
 use crate::config::YamlLintConfig;
+use crate::rules::support::events::ParsedEvents;
 
 pub const ID: &str = "float-values";
 
@@ -66,9 +67,16 @@ pub struct Violation {
 
 #[must_use]
 pub fn check(buffer: &str, cfg: &Config) -> Vec<Violation> {
-    let mut parser = Parser::new_from_str(buffer);
+    check_events(buffer, &ParsedEvents::parse(buffer), cfg)
+}
+
+pub(crate) fn check_events<'input>(
+    buffer: &'input str,
+    events: &ParsedEvents<'input>,
+    cfg: &Config,
+) -> Vec<Violation> {
     let mut receiver = FloatValuesReceiver::new(cfg, buffer);
-    let _ = parser.load(&mut receiver, true);
+    events.replay(&mut receiver);
     receiver.diagnostics
 }
 
@@ -18,7 +18,6 @@
 use granit_parser::{Scanner, StrInput, TokenType};
 
 use crate::config::YamlLintConfig;
-use crate::rules::support::line_syntax::split_lines_preserve_endings;
 use crate::rules::support::punctuation::{build_line_starts, line_and_column};
 use crate::rules::support::span_utils::CharPos;
 
@@ -87,62 +86,42 @@ pub fn check(buffer: &str, cfg: &Config) -> Vec<Violation> {
     violations
 }
 
+/// Flag every block-sequence `-` followed by more than `max_spaces_after` spaces before
+/// a value on the same line. Token-driven like yamllint, so a `-` inside a block scalar
+/// or plain-scalar continuation is never a hyphen, and `- -  a` checks each dash. granit
+/// places a `BlockEntry` at the token after the dash's whitespace (or after a trailing
+/// comment), so the dash is found by walking back over that whitespace.
 fn collect_max_spaces(buffer: &str, max_spaces_after: i64) -> Vec<Violation> {
+    let char_indices: Vec<(usize, char)> = buffer.char_indices().collect();
+    let line_starts = build_line_starts(&char_indices);
     let mut violations = Vec::new();
 
-    for (idx, line, _ending) in split_lines_preserve_endings(buffer) {
-        if line.is_empty() {
+    for token in Scanner::new(StrInput::new(buffer)) {
+        if !matches!(token.1, TokenType::BlockEntry) {
             continue;
         }
-
-        let chars = line.char_indices();
-        let mut indent_chars = 0usize;
-        let mut hyphen_byte = None;
-
-        for (byte_idx, ch) in chars {
-            match ch {
-                ' ' | '\t' => {
-                    indent_chars += 1;
-                }
-                '-' => {
-                    hyphen_byte = Some(byte_idx);
-                    break;
-                }
-                _ => break,
-            }
-        }
-
-        let Some(hyphen_pos) = hyphen_byte else {
+        let value_idx = token.0.start.index();
+        // An entry with no same-line value (`-` then a break or EOF) has no spacing to check.
+        if !char_indices
+            .get(value_idx)
+            .is_some_and(|(_, ch)| !matches!(ch, '\n' | '\r'))
+        {
             continue;
-        };
-
-        let mut offset = hyphen_pos + 1;
-        let mut spaces_after = 0usize;
-
-        while let Some(ch) = line[offset..].chars().next() {
-            if matches!(ch, ' ' | '\t') {
-                spaces_after += 1;
-                offset += ch.len_utf8();
-            } else {
-                break;
-            }
         }
-
-        if offset >= line.len() {
-            continue;
+        let mut dash_idx = value_idx;
+        while dash_idx > 0 && matches!(char_indices[dash_idx - 1].1, ' ' | '\t') {
+            dash_idx -= 1;
         }
-
-        let next_byte = line.as_bytes()[offset];
-        if next_byte == b'#' {
+        // No dash before the whitespace: the entry was placed after a trailing comment.
+        if dash_idx == 0 || char_indices[dash_idx - 1].1 != '-' {
             continue;
         }
-
-        let spaces_count = i64::try_from(spaces_after).unwrap_or(i64::MAX);
-
-        if spaces_count > max_spaces_after {
-            let column = indent_chars + 1 + spaces_after;
+        let spaces_after = value_idx - dash_idx;
+        if i64::try_from(spaces_after).unwrap_or(i64::MAX) > max_spaces_after {
+            let (line, column) =
+                line_and_column(&line_starts, CharPos::new(value_idx - 1));
             violations.push(Violation {
-                line: idx + 1,
+                line,
                 column,
                 message: MESSAGE.to_string(),
             });
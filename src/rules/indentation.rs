@@ -156,14 +156,13 @@ impl<'a> Analyzer<'a> {
         }
 
         let analysis = LineAnalysis::analyze(content);
-        let compact_mapping_continuation =
-            self.is_compact_mapping_continuation(indent, analysis);
+        let compact_continuation = self.is_compact_continuation(indent, analysis);
 
         let Some(pushing_child) = self.update_context_for_indent(
             line_number,
             indent,
             analysis,
-            compact_mapping_continuation,
+            compact_continuation,
         ) else {
             return;
         };
@@ -178,8 +177,8 @@ impl<'a> Analyzer<'a> {
         if analysis.is_sequence_entry
             && self
                 .transient
-                .compact_sequence_parent_indent
-                .is_none_or(|parent| indent <= parent)
+                .compact_sequence
+                .is_none_or(|state| indent <= state.parent_indent)
         {
             self.check_sequence_indent(indent, line_number);
         }
@@ -200,7 +199,8 @@ impl<'a> Analyzer<'a> {
         content: &str,
     ) -> bool {
         if content.trim().is_empty() {
-            self.transient.prev_line_kind = Some(LineKind::Other);
+            // A blank line produces no token, so it must not change how the next line is
+            // read: a plain scalar may continue past it.
             return true;
         }
 
@@ -222,10 +222,10 @@ impl<'a> Analyzer<'a> {
     fn reset_transient_state(&mut self, indent: usize, content: &str) {
         if self
             .transient
-            .compact_sequence_parent_indent
-            .is_some_and(|parent| indent <= parent)
+            .compact_sequence
+            .is_some_and(|state| indent <= state.parent_indent)
         {
-            self.transient.compact_sequence_parent_indent = None;
+            self.transient.compact_sequence = None;
         }
         if self
             .transient
@@ -291,8 +291,11 @@ impl<'a> Analyzer<'a> {
                 });
         }
 
-        if syntax::is_compact_sequence_start(content) {
-            self.transient.compact_sequence_parent_indent = Some(indent);
+        if let Some(offset) = syntax::compact_sequence_entry_offset(content) {
+            self.transient.compact_sequence = Some(CompactSequence {
+                parent_indent: indent,
+                entry_indent: indent.saturating_add(offset),
+            });
         }
         if let Some(continuation_indent) =
             syntax::compact_flow_mapping_continuation_indent(content, indent)
@@ -311,7 +314,7 @@ impl<'a> Analyzer<'a> {
         line_number: usize,
         indent: usize,
         analysis: LineAnalysis,
-        compact_mapping_continuation: bool,
+        compact_continuation: bool,
     ) -> Option<bool> {
         while self.frames.last().map_or(0, |frame| frame.indent) > indent {
             self.frames.pop();
@@ -337,7 +340,7 @@ impl<'a> Analyzer<'a> {
                 kind,
                 sequence_expectation: None,
             });
-            if !compact_mapping_continuation {
+            if !compact_continuation {
                 self.spaces.observe_increase(
                     parent_indent,
                     indent,
@@ -347,7 +350,7 @@ impl<'a> Analyzer<'a> {
             }
             Some(true)
         } else {
-            if !compact_mapping_continuation {
+            if !compact_continuation {
                 self.spaces
                     .observe_indent(indent, line_number, &mut self.diagnostics);
             }
@@ -356,11 +359,16 @@ impl<'a> Analyzer<'a> {
         }
     }
 
-    fn is_compact_mapping_continuation(
-        &self,
-        indent: usize,
-        analysis: LineAnalysis,
-    ) -> bool {
+    /// Whether the line continues a block that opened on an indicator line (`- `, `? `,
+    /// `: `) rather than one level deeper: its indent is the column the compact content
+    /// started at, which need not be a multiple of `spaces`.
+    fn is_compact_continuation(&self, indent: usize, analysis: LineAnalysis) -> bool {
+        if analysis.is_sequence_entry {
+            return self
+                .transient
+                .compact_sequence
+                .is_some_and(|state| state.entry_indent == indent);
+        }
         if !matches!(analysis.kind, LineKind::Mapping { .. }) {
             return false;
         }
@@ -466,6 +474,12 @@ struct CompactFlowMapping {
     continuation_indent: usize,
 }
 
+#[derive(Debug, Clone, Copy)]
+struct CompactSequence {
+    parent_indent: usize,
+    entry_indent: usize,
+}
+
 #[derive(Debug, Clone, Copy)]
 struct SequenceMappingParent {
     owner_indent: usize,
@@ -502,12 +516,12 @@ impl LineAnalysis {
     fn analyze(content: &str) -> Self {
         let trimmed = strip_trailing_comment_preserving_quotes(content).trim();
         let is_sequence_entry = syntax::is_sequence_entry(trimmed);
-        let (is_mapping_key, opens_block) = syntax::classify_mapping(trimmed);
-        let sequence_offset = if is_mapping_key {
-            syntax::sequence_prefix_width(trimmed)
-        } else {
-            0
-        };
+        // Classify what follows the block indicators, so `? key: value` and
+        // `: key: value` open compact mappings just like `- key: value`.
+        let prefix = syntax::indicator_prefix_width(trimmed);
+        let (is_mapping_key, opens_block) =
+            syntax::classify_mapping(&trimmed[prefix..]);
+        let sequence_offset = if is_mapping_key { prefix } else { 0 };
         let kind = if is_mapping_key {
             LineKind::Mapping {
                 opens_block,
@@ -742,7 +756,7 @@ struct TransientState {
     pending_child: Option<ContextKind>,
     multiline: Option<MultilineState>,
     active_sequence_mapping_parent: Option<SequenceMappingParent>,
-    compact_sequence_parent_indent: Option<usize>,
+    compact_sequence: Option<CompactSequence>,
     compact_flow_mapping: Option<CompactFlowMapping>,
     prev_line_kind: Option<LineKind>,
 }
@@ -772,15 +786,45 @@ mod syntax {
         matches!(content.chars().nth(1), None | Some(' ' | '\t' | '\r' | '#'))
     }
 
-    pub(super) fn is_compact_sequence_start(content: &str) -> bool {
+    /// Width of the leading block indicators (`- `, `? `, `: `) before a line's content,
+    /// e.g. 4 for `- ? key: value`.
+    pub(super) fn indicator_prefix_width(content: &str) -> usize {
+        indicator_offsets(content)
+            .last()
+            .map_or(0, |&(offset, width)| offset + width)
+    }
+
+    /// Offset of the innermost `- ` entry that follows another indicator on the same line
+    /// (`- - a`, `? - a`, `: - a`), where a compact nested sequence starts.
+    pub(super) fn compact_sequence_entry_offset(content: &str) -> Option<usize> {
         let trimmed = content.trim();
-        if !is_sequence_entry(trimmed) {
-            return false;
+        indicator_offsets(trimmed)
+            .iter()
+            .skip(1)
+            .rev()
+            .find(|&&(offset, _)| trimmed[offset..].starts_with('-'))
+            .map(|&(offset, _)| offset)
+    }
+
+    /// `(offset, width)` of each leading block indicator and the whitespace after it.
+    fn indicator_offsets(content: &str) -> Vec<(usize, usize)> {
+        let mut offsets = Vec::new();
+        let mut offset = 0;
+        loop {
+            let rest = &content[offset..];
+            // An indicator is followed by whitespace or ends the line (`- -`).
+            if !rest.starts_with(['-', '?', ':'])
+                || !(rest.len() == 1 || rest[1..].starts_with([' ', '\t']))
+            {
+                return offsets;
+            }
+            let width = 1 + rest[1..]
+                .chars()
+                .take_while(|ch| matches!(ch, ' ' | '\t'))
+                .count();
+            offsets.push((offset, width));
+            offset += width;
         }
-        let stripped = trimmed
-            .strip_prefix('-')
-            .expect("sequence entry starts with '-'");
-        is_sequence_entry(stripped.trim_start())
     }
 
     pub(super) fn classify_mapping(content: &str) -> (bool, bool) {
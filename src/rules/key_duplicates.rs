@@ -32,9 +32,10 @@ use std::collections::hash_map::DefaultHasher;
 use std::collections::{HashMap, HashSet};
 use std::hash::{Hash, Hasher};
 
-use granit_parser::{Event, Parser, ScalarStyle, Span, SpannedEventReceiver, Tag};
+use granit_parser::{Event, ScalarStyle, Span, SpannedEventReceiver, Tag};
 
 use crate::config::YamlLintConfig;
+use crate::rules::support::events::ParsedEvents;
 use crate::rules::support::mapping_key_walker::Walker;
 use crate::yaml_dom::{Scalar, ScalarOwned, is_core_schema};
 
@@ -79,9 +80,12 @@ pub struct Violation {
 
 #[must_use]
 pub fn check(buffer: &str, cfg: &Config) -> Vec<Violation> {
-    let mut parser = Parser::new_from_str(buffer);
-    let mut receiver = KeyDuplicatesReceiver::new(cfg);
-    let _ = parser.load(&mut receiver, true);
+    check_events(&ParsedEvents::parse(buffer), *cfg)
+}
+
+pub(crate) fn check_events(events: &ParsedEvents<'_>, cfg: Config) -> Vec<Violation> {
+    let mut receiver = KeyDuplicatesReceiver::new(&cfg);
+    events.replay(&mut receiver);
     let mut violations = receiver.violations;
     violations.sort_by_key(|v| (v.line, v.column));
     // One key colliding across several merge sources reports once: the repeated
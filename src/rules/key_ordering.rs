@@ -2,11 +2,12 @@
 //! an ignore list). Mirrors yamllint's `key-ordering`. No safe `--fix`: reordering keys
 //! silently disassociates any comment the author placed above or beside a key.
 
-use granit_parser::{Event, Parser, Span, SpannedEventReceiver};
+use granit_parser::{Event, Span, SpannedEventReceiver};
 use regex::Regex;
 use unicode_normalization::{UnicodeNormalization, char::is_combining_mark};
 
 use crate::config::YamlLintConfig;
+use crate::rules::support::events::ParsedEvents;
 use crate::rules::support::mapping_key_walker::Walker;
 
 pub const ID: &str = "key-ordering";
@@ -125,9 +126,12 @@ pub struct Violation {
 
 #[must_use]
 pub fn check(buffer: &str, cfg: &Config) -> Vec<Violation> {
-    let mut parser = Parser::new_from_str(buffer);
+    check_events(&ParsedEvents::parse(buffer), cfg)
+}
+
+pub(crate) fn check_events(events: &ParsedEvents<'_>, cfg: &Config) -> Vec<Violation> {
     let mut receiver = KeyOrderingReceiver::new(cfg);
-    let _ = parser.load(&mut receiver, true);
+    events.replay(&mut receiver);
     receiver.violations
 }
 
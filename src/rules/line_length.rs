@@ -64,8 +64,18 @@ pub struct Violation {
     pub message: String,
 }
 
+/// Over-long lines in `buffer`; a leading `# ryl line-length max: N` directive replaces
+/// the configured `max` for this buffer (see [`crate::directives::line_length_max`]).
 #[must_use]
 pub fn check(buffer: &str, cfg: &Config) -> Vec<Violation> {
+    let file_cfg;
+    let cfg = match crate::directives::line_length_max(buffer) {
+        Some(max) => {
+            file_cfg = Config { max, ..cfg.clone() };
+            &file_cfg
+        }
+        None => cfg,
+    };
     let mut violations = Vec::new();
     for (idx, line, _ending) in split_lines_preserve_endings(buffer) {
         process_line(line, idx + 1, cfg, &mut violations);
@@ -13,8 +13,9 @@
 //!
 //! Sources: YAML 1.2.2 changes page; YAML merge type.
 
-use granit_parser::{Event, Parser, Span, SpannedEventReceiver};
+use granit_parser::{Event, Span, SpannedEventReceiver};
 
+use crate::rules::support::events::ParsedEvents;
 use crate::rules::support::mapping_key_walker::Walker;
 use crate::rules::support::merge_key::is_merge_directive;
 
@@ -29,12 +30,15 @@ pub struct Violation {
 
 #[must_use]
 pub fn check(buffer: &str) -> Vec<Violation> {
-    let mut parser = Parser::new_from_str(buffer);
+    check_events(&ParsedEvents::parse(buffer))
+}
+
+pub(crate) fn check_events(events: &ParsedEvents<'_>) -> Vec<Violation> {
     let mut receiver = MergeKeysReceiver {
         walker: Walker::new(),
         violations: Vec::new(),
     };
-    let _ = parser.load(&mut receiver, true);
+    events.replay(&mut receiver);
     receiver.violations
 }
 
@@ -27,6 +27,57 @@ pub mod trailing_spaces;
 pub mod truthy;
 pub mod unicode_line_breaks;
 
+/// The rules reference index on the docs site; each rule's page is `<id>/` beneath it.
+pub const RULES_DOCS_URL: &str = "https://ryl-docs.pages.dev/rules/";
+
+#[must_use]
+pub fn rule_docs_url(id: &str) -> String {
+    format!("{RULES_DOCS_URL}{id}/")
+}
+
+/// One-line summary of what rule `id` checks (the rules reference table's description), or
+/// `None` for an id that is not a built-in rule. Kept verbatim in step with the
+/// `docs/rules.md` index by the `rules_index_drift_guard` test.
+#[must_use]
+pub fn rule_summary(id: &str) -> Option<&'static str> {
+    Some(match id {
+        anchors::ID => "Anchor and alias declarations and usage.",
+        block_scalar_chomping::ID => {
+            "Explicit chomping indicator (`-`/`+`) on block scalars."
+        }
+        braces::ID => "Spaces inside flow mapping braces (`{...}`).",
+        brackets::ID => "Spaces inside flow sequence brackets (`[...]`).",
+        colons::ID => "Spaces around mapping colons.",
+        commas::ID => "Spaces around flow collection commas.",
+        comments::ID => "Spaces after `#` and before inline comments.",
+        comments_indentation::ID => "Comment alignment with surrounding content.",
+        document_end::ID => "Document end marker `...`.",
+        document_start::ID => "Document start marker `---`.",
+        empty_lines::ID => "Number of consecutive empty lines.",
+        empty_values::ID => "Empty values in mappings and sequences.",
+        float_values::ID => "Float value formats.",
+        hyphens::ID => "Spaces after sequence hyphens.",
+        indentation::ID => {
+            "Block indentation, sequence indentation, multi-line strings."
+        }
+        key_duplicates::ID => "Duplicate keys in mappings.",
+        key_ordering::ID => "Alphabetical ordering of mapping keys.",
+        line_length::ID => "Maximum line length.",
+        merge_keys::ID => "The `<<` merge key (a YAML 1.1 feature removed in 1.2).",
+        new_line_at_end_of_file::ID => "A trailing newline at end of file.",
+        new_lines::ID => "Consistent line endings (LF vs CRLF).",
+        octal_values::ID => "Octal value formats.",
+        quoted_strings::ID => "Quoted string styles and when to require quotes.",
+        tags::ID => "Unsafe and non-portable YAML tags.",
+        trailing_spaces::ID => "Trailing whitespace at end of lines.",
+        truthy::ID => "Truthy values like `yes`, `no`, `on`, `off`.",
+        unicode_line_breaks::ID => {
+            "Raw NEL / LS / PS characters (not YAML 1.2 line breaks)."
+        }
+        _ => return None,
+    })
+}
+
 /// Every rule id; the directive engine expands a bare `disable`/`enable` to this.
 /// Extend when adding a rule.
 pub const ALL_RULE_IDS: [&str; 27] = [
@@ -3,9 +3,10 @@
 //! `--fix`: resolving `010` needs the author's intent (integer 8, integer 10, or the
 //! string "010"), which the source alone cannot disambiguate.
 
-use granit_parser::{Event, Parser, ScalarStyle, Span, SpannedEventReceiver};
+use granit_parser::{Event, ScalarStyle, Span, SpannedEventReceiver};
 
 use crate::config::YamlLintConfig;
+use crate::rules::support::events::ParsedEvents;
 
 pub const ID: &str = "octal-values";
 
@@ -42,9 +43,12 @@ pub struct Violation {
 
 #[must_use]
 pub fn check(buffer: &str, cfg: &Config) -> Vec<Violation> {
-    let mut parser = Parser::new_from_str(buffer);
+    check_events(&ParsedEvents::parse(buffer), cfg)
+}
+
+pub(crate) fn check_events(events: &ParsedEvents<'_>, cfg: &Config) -> Vec<Violation> {
     let mut receiver = OctalValuesReceiver::new(cfg);
-    let _ = parser.load(&mut receiver, true);
+    events.replay(&mut receiver);
     receiver.diagnostics
 }
 
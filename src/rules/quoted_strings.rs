@@ -9,6 +9,7 @@ use granit_parser::{
 use regex::Regex;
 
 use crate::config::YamlLintConfig;
+use crate::rules::support::events::ParsedEvents;
 use crate::rules::support::mapping_key_walker::Walker;
 use crate::rules::support::span_utils::{
     BytePos, apply_replacements, marker_byte_offset,
@@ -193,9 +194,16 @@ pub struct Violation {
 
 #[must_use]
 pub fn check(buffer: &str, cfg: &Config) -> Vec<Violation> {
-    let mut parser = Parser::new_from_str(buffer);
+    check_events(buffer, &ParsedEvents::parse(buffer), cfg)
+}
+
+pub(crate) fn check_events<'input>(
+    buffer: &'input str,
+    events: &ParsedEvents<'input>,
+    cfg: &Config,
+) -> Vec<Violation> {
     let mut receiver = QuotedStringsReceiver::new(cfg, buffer);
-    let _ = parser.load(&mut receiver, true);
+    events.replay(&mut receiver);
     receiver.diagnostics
 }
 
@@ -0,0 +1,49 @@
+//! One recorded granit parse of a buffer, replayed into every event-driven rule.
+//!
+//! A replay hands the receiver exactly the `(Event, Span)` sequence
+//! `Parser::load(.., true)` would, stopping where a parse error stopped the load.
+
+use granit_parser::{Event, Parser, ScanError, Span, SpannedEventReceiver};
+
+pub(crate) struct ParsedEvents<'input> {
+    events: Vec<(Event<'input>, Span)>,
+    outcome: Result<(), ScanError>,
+}
+
+struct Recorder<'input> {
+    events: Vec<(Event<'input>, Span)>,
+}
+
+impl<'input> SpannedEventReceiver<'input> for Recorder<'input> {
+    fn on_event(&mut self, ev: Event<'input>, span: Span) {
+        self.events.push((ev, span));
+    }
+}
+
+impl<'input> ParsedEvents<'input> {
+    /// Load every document in `buffer`, recording events up to the end of the stream or
+    /// the first parse error.
+    pub(crate) fn parse(buffer: &'input str) -> Self {
+        let mut recorder = Recorder { events: Vec::new() };
+        let outcome = Parser::new_from_str(buffer).load(&mut recorder, true);
+        Self {
+            events: recorder.events,
+            outcome,
+        }
+    }
+
+    /// Feed the recorded events to `receiver` in parse order.
+    pub(crate) fn replay<R: SpannedEventReceiver<'input>>(&self, receiver: &mut R) {
+        for (event, span) in &self.events {
+            receiver.on_event(event.clone(), *span);
+        }
+    }
+
+    /// The parse outcome: the error that ended the load early, if any.
+    pub(crate) const fn outcome(&self) -> Result<(), &ScanError> {
+        match &self.outcome {
+            Ok(()) => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+}
@@ -3,6 +3,7 @@ use std::ops::Range;
 use granit_parser::{Event, Parser, Span, SpannedEventReceiver};
 
 use crate::config::YamlLintConfig;
+use crate::rules::support::events::ParsedEvents;
 use crate::rules::support::punctuation::{
     build_line_starts, line_and_column, skip_comment, template_double_curly_end,
 };
@@ -42,8 +43,21 @@ macro_rules! define_rule {
 
         #[must_use]
         pub fn check(buffer: &str, cfg: &Config) -> Vec<Violation> {
+            check_events(
+                buffer,
+                &$crate::rules::support::events::ParsedEvents::parse(buffer),
+                cfg,
+            )
+        }
+
+        pub(crate) fn check_events(
+            buffer: &str,
+            events: &$crate::rules::support::events::ParsedEvents<'_>,
+            cfg: &Config,
+        ) -> Vec<Violation> {
             $crate::rules::support::flow_collection::check(
                 buffer,
+                events,
                 cfg.inner(),
                 &DESCRIPTOR,
             )
@@ -294,6 +308,7 @@ struct SpacingMessages<'a> {
 #[must_use]
 pub fn check(
     buffer: &str,
+    events: &ParsedEvents<'_>,
     cfg: &Config,
     desc: &FlowCollectionDescriptor,
 ) -> Vec<Violation> {
@@ -301,9 +316,8 @@ pub fn check(
         return Vec::new();
     }
 
-    let mut parser = Parser::new_from_str(buffer);
     let mut collector = ScalarRangeCollector::new();
-    let _ = parser.load(&mut collector, true);
+    events.replay(&mut collector);
     let scalar_ranges = collector.into_sorted();
 
     let chars: Vec<(usize, char)> = buffer.char_indices().collect();
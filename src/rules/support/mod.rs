@@ -1,4 +1,5 @@
 pub(crate) mod comments_scan;
+pub(crate) mod events;
 pub(crate) mod flow_collection;
 pub(crate) mod line_syntax;
 pub(crate) mod mapping_key_walker;
@@ -1,15 +1,18 @@
 use std::ops::Range;
 
-use granit_parser::{
-    Event, Parser, Scanner, Span, SpannedEventReceiver, StrInput, TokenType,
-};
+use granit_parser::{Event, Scanner, Span, SpannedEventReceiver, StrInput, TokenType};
 
+use crate::rules::support::events::ParsedEvents;
 use crate::rules::support::span_utils::CharPos;
 
 pub(crate) fn collect_scalar_ranges(buffer: &str) -> Vec<Range<CharPos>> {
-    let mut parser = Parser::new_from_str(buffer);
+    scalar_ranges(&ParsedEvents::parse(buffer))
+}
+
+/// Sorted source ranges of every non-empty scalar in `events`.
+pub(crate) fn scalar_ranges(events: &ParsedEvents<'_>) -> Vec<Range<CharPos>> {
     let mut collector = ScalarRangeCollector::new();
-    let _ = parser.load(&mut collector, true);
+    events.replay(&mut collector);
     collector.into_sorted()
 }
 
@@ -15,9 +15,10 @@
 //!
 //! Sources: YAML 1.2.2 spec (tags); YAML 1.2.2 changes page; `PyYAML` docs.
 
-use granit_parser::{Event, Parser, Span, SpannedEventReceiver, Tag};
+use granit_parser::{Event, Span, SpannedEventReceiver, Tag};
 
 use crate::config::YamlLintConfig;
+use crate::rules::support::events::ParsedEvents;
 use crate::yaml_dom::{YamlOwned, core_schema_suffix};
 
 pub const ID: &str = "tags";
@@ -108,12 +109,15 @@ pub struct Violation {
 
 #[must_use]
 pub fn check(buffer: &str, cfg: &Config) -> Vec<Violation> {
-    let mut parser = Parser::new_from_str(buffer);
+    check_events(&ParsedEvents::parse(buffer), cfg)
+}
+
+pub(crate) fn check_events(events: &ParsedEvents<'_>, cfg: &Config) -> Vec<Violation> {
     let mut receiver = TagsReceiver {
         cfg,
         diagnostics: Vec::new(),
     };
-    let _ = parser.load(&mut receiver, true);
+    events.replay(&mut receiver);
     receiver.diagnostics
 }
 
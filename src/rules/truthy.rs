@@ -5,9 +5,10 @@
 
 use std::collections::HashSet;
 
-use granit_parser::{Event, Parser, ScalarStyle, Span, SpannedEventReceiver};
+use granit_parser::{Event, ScalarStyle, Span, SpannedEventReceiver};
 
 use crate::config::YamlLintConfig;
+use crate::rules::support::events::ParsedEvents;
 use crate::rules::support::span_utils::marker_byte_offset;
 use crate::rules::support::yaml_version::DocumentVersions;
 
@@ -37,22 +38,27 @@ impl Config {
     /// that configuration, so this only occurs with manual construction in tests.
     #[must_use]
     pub fn resolve(cfg: &YamlLintConfig) -> Self {
-        let mut allowed: HashSet<String> = HashSet::new();
-        allowed.insert("true".to_string());
-        allowed.insert("false".to_string());
+        // The message lists the configured values as given (duplicates included), sorted by
+        // code point like yamllint's `sorted()`, so it stays separate from the lookup set.
+        let mut display_values: Vec<&str> = vec!["true", "false"];
         let mut check_keys = true;
 
         if let Some(node) = cfg.rule_option(ID, "allowed-values")
             && let Some(seq) = node.as_sequence()
         {
-            allowed.clear();
-            for value in seq {
-                let text = value
-                    .as_str()
-                    .expect("truthy allowed-values should be strings");
-                allowed.insert(text.to_owned());
-            }
+            display_values = seq
+                .iter()
+                .map(|value| {
+                    value
+                        .as_str()
+                        .expect("truthy allowed-values should be strings")
+                })
+                .collect();
         }
+        let allowed: HashSet<String> = display_values
+            .iter()
+            .map(|value| (*value).to_owned())
+            .collect();
 
         if let Some(node) = cfg.rule_option(ID, "check-keys")
             && let Some(flag) = node.as_bool()
@@ -60,8 +66,6 @@ impl Config {
             check_keys = flag;
         }
 
-        let mut display_values: Vec<&str> =
-            allowed.iter().map(String::as_str).collect();
         display_values.sort_unstable();
         let allowed_display = format!("[{}]", display_values.join(", "));
 
@@ -301,9 +305,16 @@ impl SpannedEventReceiver<'_> for TruthyReceiver<'_> {
 
 #[must_use]
 pub fn check(buffer: &str, cfg: &Config) -> Vec<Violation> {
+    check_events(buffer, &ParsedEvents::parse(buffer), cfg)
+}
+
+pub(crate) fn check_events<'input>(
+    buffer: &'input str,
+    events: &ParsedEvents<'input>,
+    cfg: &Config,
+) -> Vec<Violation> {
     let versions = DocumentVersions::parse(buffer);
-    let mut parser = Parser::new_from_str(buffer);
     let mut receiver = TruthyReceiver::new(cfg, versions);
-    let _ = parser.load(&mut receiver, true);
+    events.replay(&mut receiver);
     receiver.diagnostics
 }
@@ -0,0 +1,166 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use tempfile::tempdir;
+
+mod common;
+use common::cli::{run, ryl};
+
+/// Lint `inputs` with `--cache-dir`, returning the exit code, the diagnostics, and the
+/// `--verbose` cache tally line.
+fn cached_run(
+    home: &Path,
+    cache: &Path,
+    extra: &[&str],
+    inputs: &[&Path],
+) -> (i32, String, String) {
+    let mut cmd: Command = ryl(home);
+    cmd.args(["--verbose", "--format", "parsable", "--cache-dir"])
+        .arg(cache)
+        .args(extra)
+        .args(inputs);
+    let (code, _out, err) = run(&mut cmd);
+    let (tally, diagnostics): (Vec<&str>, Vec<&str>) =
+        err.lines().partition(|line| line.starts_with("cache: "));
+    assert_eq!(tally.len(), 1, "{err}");
+    (code, diagnostics.join("\n"), tally[0].to_string())
+}
+
+#[test]
+fn second_run_reuses_cached_diagnostics_until_the_file_changes() {
+    let dir = tempdir().unwrap();
+    let cache = dir.path().join("cache");
+    let dirty = dir.path().join("dirty.yaml");
+    fs::write(&dirty, "key:  value\n").unwrap();
+    let clean = dir.path().join("clean.yaml");
+    fs::write(&clean, "key: value\n").unwrap();
+    let config = ["-d", "rules: {colons: enable}"];
+
+    let (code, first, tally) =
+        cached_run(dir.path(), &cache, &config, &[&dirty, &clean]);
+    assert_eq!(code, 1, "{first}");
+    assert!(
+        first.contains("dirty.yaml:1:6: [error] too many spaces after colon (colons)"),
+        "{first}"
+    );
+    assert_eq!(tally, "cache: hits=0 misses=2");
+
+    let (code, second, tally) =
+        cached_run(dir.path(), &cache, &config, &[&dirty, &clean]);
+    assert_eq!(code, 1, "{second}");
+    assert_eq!(second, first);
+    assert_eq!(tally, "cache: hits=2 misses=0");
+
+    fs::write(&dirty, "key:   value\nother:  1\n").unwrap();
+    let (code, third, tally) =
+        cached_run(dir.path(), &cache, &config, &[&dirty, &clean]);
+    assert_eq!(code, 1, "{third}");
+    assert!(
+        third.contains("dirty.yaml:2:8: [error] too many spaces after colon"),
+        "{third}"
+    );
+    assert_eq!(tally, "cache: hits=1 misses=1");
+}
+
+#[test]
+fn changing_the_config_invalidates_every_entry() {
+    let dir = tempdir().unwrap();
+    let cache = dir.path().join("cache");
+    let file = dir.path().join("a.yaml");
+    fs::write(&file, "key:  value \n").unwrap();
+
+    let (_, colons, _) = cached_run(
+        dir.path(),
+        &cache,
+        &["-d", "rules: {colons: enable}"],
+        &[&file],
+    );
+    let (_, trailing, tally) = cached_run(
+        dir.path(),
+        &cache,
+        &["-d", "rules: {trailing-spaces: enable}"],
+        &[&file],
+    );
+    assert_eq!(tally, "cache: hits=0 misses=1");
+    assert!(colons.contains("(colons)") && !colons.contains("trailing-spaces"));
+    assert!(trailing.contains("(trailing-spaces)") && !trailing.contains("colons"));
+}
+
+#[test]
+fn unreadable_entries_and_read_errors_are_misses() {
+    let dir = tempdir().unwrap();
+    let cache = dir.path().join("cache");
+    let file = dir.path().join("a.yaml");
+    fs::write(&file, "key:  value\n").unwrap();
+    let missing = dir.path().join("missing.yaml");
+    let config = ["-d", "rules: {colons: enable}"];
+
+    let (_, first, _) = cached_run(dir.path(), &cache, &config, &[&file]);
+    for entry in fs::read_dir(&cache).unwrap() {
+        fs::write(entry.unwrap().path(), "not json").unwrap();
+    }
+    let (_, second, tally) = cached_run(dir.path(), &cache, &config, &[&file]);
+    assert_eq!(tally, "cache: hits=0 misses=1");
+    assert_eq!(second, first);
+    let (_, _, tally) = cached_run(dir.path(), &cache, &config, &[&file]);
+    assert_eq!(tally, "cache: hits=1 misses=0", "the entry was rewritten");
+
+    // A file that cannot be read is linted (and fails) on every run.
+    for _ in 0..2 {
+        let (code, output, tally) =
+            cached_run(dir.path(), &cache, &config, &[&missing]);
+        assert_eq!(code, 1, "{output}");
+        assert!(output.contains("failed to read"), "{output}");
+        assert_eq!(tally, "cache: hits=0 misses=1");
+    }
+}
+
+#[test]
+fn cached_pattern_rule_diagnostics_keep_their_rule_id() {
+    let dir = tempdir().unwrap();
+    let cache = dir.path().join("cache");
+    let rules = dir.path().join("rules");
+    fs::create_dir(&rules).unwrap();
+    fs::write(
+        rules.join("no-todo.yaml"),
+        "rule: no-todo\npattern: TODO\nscope: values\nmessage: value contains TODO\n",
+    )
+    .unwrap();
+    let file = dir.path().join("a.yaml");
+    fs::write(&file, "key: TODO\n").unwrap();
+    let rulesdir = rules.to_str().unwrap();
+    let config = ["-d", "rules: {colons: enable}", "--rulesdir", rulesdir];
+
+    let (_, first, _) = cached_run(dir.path(), &cache, &config, &[&file]);
+    assert!(
+        first.ends_with(":1:6: [error] value contains TODO (no-todo)"),
+        "{first}"
+    );
+    let (_, second, tally) = cached_run(dir.path(), &cache, &config, &[&file]);
+    assert_eq!(tally, "cache: hits=1 misses=0");
+    assert_eq!(second, first);
+}
+
+#[test]
+fn cache_dir_is_rejected_for_stdin_and_alongside_fix() {
+    let dir = tempdir().unwrap();
+    let cache = dir.path().join("cache");
+    let (code, _out, err) = run(ryl(dir.path())
+        .args(["-d", "rules: {colons: enable}", "--cache-dir"])
+        .arg(&cache)
+        .arg("-"));
+    assert_eq!(code, 2, "{err}");
+    assert!(
+        err.contains("`--cache-dir` is not supported when reading from stdin"),
+        "{err}"
+    );
+
+    let (code, _out, err) = run(ryl(dir.path())
+        .args(["--fix", "--cache-dir"])
+        .arg(&cache)
+        .arg(dir.path()));
+    assert_eq!(code, 2, "{err}");
+    assert!(err.contains("cannot be used with"), "{err}");
+    assert!(!cache.exists());
+}
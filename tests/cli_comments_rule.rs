@@ -60,3 +60,30 @@ fn comments_rule_ignores_shebang_when_enabled() {
     assert!(stdout.is_empty(), "expected no stdout: {stdout}");
     assert!(stderr.is_empty(), "expected no stderr: {stderr}");
 }
+
+#[test]
+fn require_content_after_hash_via_toml() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("notes.yaml");
+    fs::write(&file, "# todo\n#- todo\nkey: value\n").unwrap();
+    let config = dir.path().join("ryl.toml");
+    fs::write(
+        &config,
+        "[rules.comments]\nrequire-starting-space = false\nrequire-content-after-hash = \"^[[:alpha:]]\"\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, stdout, stderr) = run(Command::new(exe)
+        .args(["-f", "parsable", "-c"])
+        .arg(&config)
+        .arg(&file));
+    assert_eq!(code, 1, "expected error: stdout={stdout} stderr={stderr}");
+    assert_eq!(
+        stderr,
+        format!(
+            "{}:2:2: [error] comment content does not match \"^[[:alpha:]]\" (comments)\n",
+            file.display()
+        )
+    );
+}
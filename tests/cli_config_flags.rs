@@ -96,3 +96,56 @@ fn format_strict_no_warnings_are_accepted() {
         .arg(td.path()));
     assert_eq!(code, 0, "expected success: {err}");
 }
+
+#[test]
+fn config_overlay_layers_a_rule_change_onto_the_project_config() {
+    let td = tempdir().unwrap();
+    let root = td.path();
+    fs::write(
+        root.join(".yamllint"),
+        "rules:\n  line-length: {max: 10}\n  trailing-spaces: enable\n",
+    )
+    .unwrap();
+    let file = root.join("a.yaml");
+    fs::write(&file, "key: a fairly long value \n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, _out, err) = run(Command::new(exe).arg("-f").arg("parsable").arg(&file));
+    assert_eq!(code, 1, "project config alone: {err}");
+    assert!(err.contains("(line-length)"), "{err}");
+    assert!(err.contains("(trailing-spaces)"), "{err}");
+
+    // The overlay raises the project's `line-length` max; `trailing-spaces`, which the
+    // overlay does not mention, still comes from the project config.
+    let (code, _out, err) = run(Command::new(exe)
+        .arg("-f")
+        .arg("parsable")
+        .arg("--config-overlay")
+        .arg("rules: {line-length: {max: 120}}")
+        .arg(&file));
+    assert_eq!(code, 1, "overlaid config: {err}");
+    assert!(!err.contains("(line-length)"), "{err}");
+    assert!(err.contains("(trailing-spaces)"), "{err}");
+}
+
+#[test]
+fn config_overlay_rejects_extends_and_unknown_rules() {
+    let td = tempdir().unwrap();
+    let file = td.path().join("a.yaml");
+    fs::write(&file, "a: 1\n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for (data, expected) in [
+        ("extends: relaxed", "cannot use extends"),
+        ("rules: {no-such-rule: enable}", "no such rule"),
+    ] {
+        let (code, _out, err) = run(Command::new(exe)
+            .arg("-d")
+            .arg("extends: default")
+            .arg("--config-overlay")
+            .arg(data)
+            .arg(&file));
+        assert_eq!(code, 2, "{data}: {err}");
+        assert!(err.contains(expected), "{data}: {err}");
+    }
+}
@@ -85,3 +85,29 @@ fn forbidding_marker_allows_implicit_document() {
     assert!(stdout.trim().is_empty(), "expected no stdout: {stdout}");
     assert!(stderr.trim().is_empty(), "expected no stderr: {stderr}");
 }
+
+#[test]
+fn last_only_flags_only_the_final_document_via_toml() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("stream.yaml");
+    fs::write(&file, "---\nfirst: 1\n---\nsecond: 2\n").unwrap();
+
+    let config = dir.path().join("ryl.toml");
+    fs::write(&config, "[rules.document-end]\nlast-only = true\n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, stdout, stderr) = run(Command::new(exe)
+        .arg("-f")
+        .arg("parsable")
+        .arg("-c")
+        .arg(&config)
+        .arg(&file));
+    assert_eq!(code, 1, "expected error: stdout={stdout} stderr={stderr}");
+    let output = if stderr.is_empty() { stdout } else { stderr };
+    assert_eq!(
+        output.matches("missing document end").count(),
+        1,
+        "only the final document is reported: {output}"
+    );
+    assert!(output.contains(":4:1:"), "final document line: {output}");
+}
@@ -44,3 +44,81 @@ fn non_existent_file_reports_read_error() {
         "expected read error in stderr: {err}"
     );
 }
+
+#[cfg(unix)]
+#[test]
+fn broken_symlink_reports_a_per_file_error_without_aborting_the_run() {
+    use std::os::unix::fs::symlink;
+
+    let dir = tempdir().unwrap();
+    let broken = dir.path().join("broken.yaml");
+    symlink(dir.path().join("nowhere.yaml"), &broken).unwrap();
+    let good = dir.path().join("good.yaml");
+    std::fs::write(&good, "key: value \n").unwrap();
+    let nested = dir.path().join("nested");
+    std::fs::create_dir(&nested).unwrap();
+    symlink(dir.path().join("nowhere.yaml"), nested.join("broken.yaml")).unwrap();
+    std::fs::write(nested.join("good.yaml"), "key: value \n").unwrap();
+    let config = "rules: {trailing-spaces: enable}";
+
+    // An explicit broken symlink is a read error for that file only.
+    let (code, out, err) = run(ryl(dir.path())
+        .args(["--format", "parsable", "-d", config])
+        .arg(&broken)
+        .arg(&good));
+    assert_eq!(code, 1, "{err}");
+    assert!(out.is_empty(), "{out}");
+    assert!(
+        err.contains(&format!("failed to read {}", broken.display())),
+        "{err}"
+    );
+    assert!(
+        err.contains("good.yaml:1:11: [error] trailing spaces"),
+        "{err}"
+    );
+
+    // A directory walk skips a broken symlink entry and lints its siblings.
+    let (code, _out, err) = run(ryl(dir.path())
+        .args(["--format", "parsable", "-d", config])
+        .arg(&nested));
+    assert_eq!(code, 1, "{err}");
+    assert!(!err.contains("failed to read"), "{err}");
+    assert!(
+        err.contains("good.yaml:1:11: [error] trailing spaces"),
+        "{err}"
+    );
+}
+
+#[test]
+fn strict_and_no_warnings_exit_codes_for_a_warning_only_run() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("warn.yaml");
+    std::fs::write(&file, "key: value \n").unwrap();
+    let config = "rules: {trailing-spaces: {level: warning}}";
+
+    // yamllint tracks the highest problem level before `--no-warnings` hides anything,
+    // so hidden warnings still fail a `--strict` run.
+    for (flags, expected_code, shows_warning) in [
+        (&[][..], 0, true),
+        (&["--strict"][..], 2, true),
+        (&["--no-warnings"][..], 0, false),
+        (&["--strict", "--no-warnings"][..], 2, false),
+    ] {
+        let (code, out, err) = run(ryl(dir.path())
+            .args(flags)
+            .args(["--format", "parsable", "-d", config])
+            .arg(&file));
+        assert_eq!(code, expected_code, "{flags:?}: {err}");
+        assert!(out.is_empty(), "{flags:?}: {out}");
+        assert_eq!(err.contains("[warning]"), shows_warning, "{flags:?}: {err}");
+    }
+
+    let (code, out, err) = run(ryl(dir.path())
+        .args(["--strict", "--no-warnings", "--count", "-d", config])
+        .arg(&file));
+    assert_eq!(code, 2, "{err}");
+    assert_eq!(
+        out, "errors=0 warnings=0 files=1\n",
+        "hidden warnings stay uncounted"
+    );
+}
@@ -201,3 +201,138 @@ fn cli_override_unknown_label_errors() {
         "expected unsupported label error, got:\n{stderr}"
     );
 }
+
+#[test]
+fn input_encoding_forces_latin1_for_every_input() {
+    let dir = tempdir().unwrap();
+    let cfg_path = dir.path().join("utf16-config.yml");
+    let yaml_path = dir.path().join("latin.yaml");
+
+    // The config keeps detection (a UTF-16 BOM here); only inputs are forced.
+    write_utf16le(&cfg_path, "rules:\n  truthy: enable\n");
+    write_latin1(&yaml_path, "acción: yes\n");
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, _, stderr) = run(Command::new(exe)
+        .args(["--format", "parsable", "-c"])
+        .arg(&cfg_path)
+        .arg(&yaml_path));
+    assert_eq!(code, 1, "undecoded latin-1 is an error: {stderr}");
+    assert_eq!(
+        stderr,
+        format!(
+            "{}:1:5: [error] file is not valid UTF-8\n",
+            yaml_path.display()
+        )
+    );
+
+    let (code, _, stderr) = run(Command::new(exe)
+        .args(["--input-encoding", "latin1", "--format", "parsable", "-c"])
+        .arg(&cfg_path)
+        .arg(&yaml_path));
+    assert_eq!(code, 1, "{stderr}");
+    assert_eq!(
+        stderr,
+        format!(
+            "{}:1:9: [error] truthy value should be one of [false, true] (truthy)\n",
+            yaml_path.display()
+        )
+    );
+    assert!(
+        !stderr.contains("YAMLLINT_FILE_ENCODING"),
+        "the flag is not the env workaround: {stderr}"
+    );
+
+    let (code, stdout, stderr) = run(Command::new(exe)
+        .args(["--input-encoding", "latin1", "-c"])
+        .arg(&cfg_path)
+        .arg("-")
+        .stdin(fs::File::open(&yaml_path).unwrap()));
+    assert_eq!(code, 1, "stdin is decoded the same way: {stdout} {stderr}");
+    assert!(stderr.contains("truthy"), "{stderr}");
+}
+
+#[test]
+fn input_encoding_overrides_a_bom_and_reports_invalid_input_per_file() {
+    let dir = tempdir().unwrap();
+    let cfg_path = dir.path().join("config.yml");
+    let utf16_path = dir.path().join("utf16.yaml");
+    let ascii_path = dir.path().join("ascii.yaml");
+
+    fs::write(&cfg_path, "rules:\n  truthy: enable\n").unwrap();
+    write_utf16le(&utf16_path, "key: value\n");
+    fs::write(&ascii_path, "value: yes\n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, _, stderr) = run(Command::new(exe)
+        .args(["--input-encoding", "utf-8", "--format", "parsable", "-c"])
+        .arg(&cfg_path)
+        .arg(&utf16_path)
+        .arg(&ascii_path));
+    assert_eq!(code, 1, "{stderr}");
+    assert!(
+        stderr.contains(&format!(
+            "{}:1:1: [error] file is not valid UTF-8",
+            utf16_path.display()
+        )),
+        "the BOM is not honoured and the file fails alone: {stderr}"
+    );
+    assert!(
+        stderr.contains(&format!("{}:1:8: [error] truthy", ascii_path.display())),
+        "other files still lint: {stderr}"
+    );
+
+    let (code, _, stderr) = run(Command::new(exe)
+        .args(["--input-encoding", "klingon", "-c"])
+        .arg(&cfg_path)
+        .arg(&ascii_path));
+    assert_eq!(code, 2, "{stderr}");
+    assert!(
+        stderr.contains(
+            "error: --input-encoding: invalid encoding: unsupported label 'klingon'"
+        ),
+        "{stderr}"
+    );
+}
+
+#[test]
+fn invalid_utf8_is_one_error_diagnostic_at_the_first_bad_byte() {
+    let dir = tempdir().unwrap();
+    let latin = dir.path().join("latin.yaml");
+    write_latin1(&latin, "key: value\nname: café\nother: x\n");
+    let clean = dir.path().join("clean.yaml");
+    fs::write(&clean, "key: value\n").unwrap();
+    let config = "rules: {trailing-spaces: enable}";
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, stdout, stderr) = run(Command::new(exe)
+        .args(["--format", "parsable", "-d", config])
+        .arg(&latin)
+        .arg(&clean));
+    assert_eq!(code, 1, "{stdout} {stderr}");
+    assert_eq!(
+        stderr,
+        format!(
+            "{}:2:10: [error] file is not valid UTF-8\n",
+            latin.display()
+        )
+    );
+
+    let (code, _, stderr) = run(Command::new(exe)
+        .args(["--format", "standard", "-d", config])
+        .arg(&latin));
+    assert_eq!(code, 1, "{stderr}");
+    assert_eq!(
+        stderr,
+        format!(
+            "{}\n  2:10      error    file is not valid UTF-8\n\n",
+            latin.display()
+        )
+    );
+
+    let (code, _, stderr) = run(Command::new(exe)
+        .args(["--format", "parsable", "-d", config, "-"])
+        .stdin(fs::File::open(&latin).unwrap()));
+    assert_eq!(code, 1, "{stderr}");
+    assert_eq!(stderr, "<stdin>:2:10: [error] file is not valid UTF-8\n");
+}
@@ -0,0 +1,125 @@
+use std::fs;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+use tempfile::tempdir;
+
+mod common;
+use common::cli::{run, ryl};
+
+fn project() -> tempfile::TempDir {
+    let dir = tempdir().unwrap();
+    let root = dir.path();
+    fs::write(
+        root.join(".ryl.toml"),
+        "ignore = ['vendor/']\n[rules]\ntrailing-spaces = 'enable'\n",
+    )
+    .unwrap();
+    for name in [
+        "listed.yaml",
+        "unlisted.yaml",
+        "vendor/dep.yaml",
+        "also.yml",
+    ] {
+        let path = root.join(name);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, "a: 1 \n").unwrap();
+    }
+    fs::write(root.join("notes.txt"), "a: 1 \n").unwrap();
+    dir
+}
+
+#[test]
+fn files_from_lints_only_the_listed_files() {
+    let dir = project();
+    let root = dir.path();
+    fs::write(
+        root.join("files.txt"),
+        "# precomputed list\nlisted.yaml\n\nvendor/dep.yaml\r\nnotes.txt\n",
+    )
+    .unwrap();
+
+    let (code, _, stderr) = run(ryl(root).current_dir(root).args([
+        "--files-from",
+        "files.txt",
+        "--format",
+        "parsable",
+    ]));
+    assert_eq!(code, 1, "{stderr}");
+    // `vendor/` is ignored and `notes.txt` is not YAML, so both are skipped, not errors.
+    assert_eq!(
+        stderr,
+        "listed.yaml:1:5: [error] trailing spaces (trailing-spaces)\n"
+    );
+}
+
+#[test]
+fn files_from_merges_with_positional_inputs_and_reads_stdin() {
+    let dir = project();
+    let root = dir.path();
+
+    let mut child = ryl(root)
+        .current_dir(root)
+        .args(["--files-from", "-", "--format", "parsable", "also.yml"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"listed.yaml\nalso.yml\n")
+        .unwrap();
+    let out = child.wait_with_output().unwrap();
+    let stderr = String::from_utf8_lossy(&out.stderr);
+    assert_eq!(out.status.code(), Some(1), "{stderr}");
+    let mut linted: Vec<&str> = stderr
+        .lines()
+        .map(|line| line.split(':').next().unwrap())
+        .collect();
+    linted.sort_unstable();
+    assert_eq!(
+        linted,
+        ["also.yml", "listed.yaml"],
+        "a file both listed and passed is linted once: {stderr}"
+    );
+}
+
+#[test]
+fn files_from_conflicts_with_stdin_input_and_since() {
+    let dir = project();
+    let root = dir.path();
+    fs::write(root.join("files.txt"), "listed.yaml\n").unwrap();
+    let exe = env!("CARGO_BIN_EXE_ryl");
+
+    let (code, _, stderr) = run(Command::new(exe).current_dir(root).args([
+        "--files-from",
+        "files.txt",
+        "-",
+    ]));
+    assert_eq!(code, 2, "{stderr}");
+    assert!(
+        stderr.contains("cannot be combined with other inputs"),
+        "{stderr}"
+    );
+
+    let (code, _, stderr) = run(Command::new(exe).current_dir(root).args([
+        "--files-from",
+        "files.txt",
+        "--since",
+        "HEAD",
+    ]));
+    assert_eq!(code, 2, "{stderr}");
+    assert!(stderr.contains("cannot be used with"), "{stderr}");
+
+    let (code, _, stderr) = run(Command::new(exe)
+        .current_dir(root)
+        .args(["--files-from", "missing.txt"]));
+    assert_eq!(code, 2, "{stderr}");
+    assert!(
+        stderr.contains("error: --files-from missing.txt:"),
+        "{stderr}"
+    );
+}
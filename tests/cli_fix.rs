@@ -1042,3 +1042,105 @@ fn fix_trims_consecutive_blank_lines_outside_block_scalars() {
         "outside runs trimmed to max=2, inner block-scalar blanks preserved: {fixed:?}"
     );
 }
+
+#[test]
+fn fix_trailing_spaces_and_final_newline_is_idempotent_and_keeps_crlf() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("input.yaml");
+    fs::write(&file, "a: 1  \r\nb: 2\t\r\nc: 3 ").unwrap();
+    fs::write(
+        dir.path().join(".ryl.toml"),
+        "[rules]\ntrailing-spaces = 'enable'\nnew-line-at-end-of-file = 'enable'\n\
+         new-lines = {type = 'dos'}\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, _, stderr) = run(Command::new(exe).arg("--fix").arg(&file));
+    assert_eq!(code, 0, "every problem is fixable: {stderr}");
+    assert!(stderr.contains("4 fixed, 0 remaining"), "{stderr}");
+    let fixed = fs::read_to_string(&file).unwrap();
+    assert_eq!(fixed, "a: 1\r\nb: 2\r\nc: 3\r\n", "{fixed:?}");
+
+    let (code, stdout, stderr) = run(Command::new(exe).arg("--fix").arg(&file));
+    assert_eq!(code, 0, "{stderr}");
+    assert!(
+        stdout.is_empty(),
+        "a second pass finds nothing to fix: {stdout}"
+    );
+    assert_eq!(fs::read_to_string(&file).unwrap(), fixed);
+}
+
+#[test]
+fn fixable_reports_fixable_and_manual_counts_without_changing_files() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("input.yaml");
+    let content = "a: yes \nb: 1  \nc: 2\n";
+    fs::write(&file, content).unwrap();
+    let config = dir.path().join(".ryl.toml");
+    fs::write(
+        &config,
+        "[rules]\ntrailing-spaces = 'enable'\ntruthy = 'enable'\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, stdout, stderr) = run(Command::new(exe).arg("--fixable").arg(&file));
+    assert_eq!(code, 1, "the exit code still reflects the errors: {stderr}");
+    assert_eq!(
+        stdout,
+        "trailing-spaces fixable=2 manual=0\ntruthy fixable=0 manual=1\n\
+         fixable=2 manual=1\n"
+    );
+    assert!(stderr.is_empty(), "no diagnostics are printed: {stderr}");
+    assert_eq!(fs::read_to_string(&file).unwrap(), content);
+
+    // A rule the `[fix]` table excludes needs a manual edit.
+    fs::write(
+        &config,
+        "[rules]\ntrailing-spaces = 'enable'\ntruthy = 'enable'\n\
+         [fix]\nunfixable = ['trailing-spaces']\n",
+    )
+    .unwrap();
+    let (_, stdout, _) = run(Command::new(exe).arg("--fixable").arg(&file));
+    assert_eq!(
+        stdout,
+        "trailing-spaces fixable=0 manual=2\ntruthy fixable=0 manual=1\n\
+         fixable=0 manual=3\n"
+    );
+
+    let (code, _, stderr) =
+        run(Command::new(exe).args(["--fixable", "--fix"]).arg(&file));
+    assert_eq!(code, 2, "{stderr}");
+    assert!(stderr.contains("cannot be used with"), "{stderr}");
+}
+
+#[test]
+fn fixable_counts_only_problems_the_fixes_resolve() {
+    // `braces` has a safe fix (spacing) and `[fix]` allows it, but no fix removes a
+    // forbidden flow mapping: `--fixable` must agree with what `--fix` then reports.
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("input.yaml");
+    fs::write(&file, "a: {b: \"c\"}   \nd: \"e\"\n").unwrap();
+    fs::write(
+        dir.path().join(".ryl.toml"),
+        "[rules.braces]\nforbid = true\n\
+         [rules.quoted-strings]\nquote-type = 'single'\n\
+         [rules.trailing-spaces]\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (_, stdout, _) = run(Command::new(exe).arg("--fixable").arg(&file));
+    assert_eq!(
+        stdout,
+        "braces fixable=0 manual=1\nquoted-strings fixable=2 manual=0\n\
+         trailing-spaces fixable=1 manual=0\nfixable=3 manual=1\n"
+    );
+
+    let (_, _, stderr) = run(Command::new(exe).arg("--fix").arg(&file));
+    assert!(
+        stderr.contains("Found 4 problems (3 fixed, 1 remaining)."),
+        "{stderr}"
+    );
+}
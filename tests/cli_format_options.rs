@@ -107,6 +107,114 @@ fn parsable_format_omits_rule_suffix_for_syntax_errors() {
     );
 }
 
+#[test]
+fn parsable_format_keeps_colons_in_messages_unescaped() {
+    let dir = tempdir().unwrap();
+    let cfg = disable_doc_start_config(dir.path());
+    let file = dir.path().join("colon.yaml");
+    fs::write(&file, "key: value: other\n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, _stdout, stderr) = run(Command::new(exe)
+        .arg("--format")
+        .arg("parsable")
+        .arg("-c")
+        .arg(&cfg)
+        .arg(&file));
+    assert_eq!(code, 1, "syntax errors should exit 1");
+    let line = stderr.lines().next().expect("a diagnostic line");
+
+    // Like yamllint, the message is not escaped: consumers split the fixed leading fields
+    // (after the known path) and treat everything after `] ` as the message.
+    let rest = line
+        .strip_prefix(&format!("{}:", file.display()))
+        .unwrap_or_else(|| panic!("path prefix: {line}"));
+    let mut fields = rest.splitn(3, ':');
+    assert_eq!(fields.next(), Some("1"), "{line}");
+    assert_eq!(fields.next(), Some("11"), "{line}");
+    let payload = fields.next().expect("level and message");
+    let message = payload
+        .strip_prefix(" [error] ")
+        .unwrap_or_else(|| panic!("level field: {line}"));
+    assert!(
+        message.starts_with("syntax error: mapping values are not allowed"),
+        "colon in message kept verbatim: {message}"
+    );
+    assert!(message.ends_with(" (syntax)"), "{message}");
+}
+
+#[test]
+fn parsable_format_appends_rule_url_when_requested() {
+    let dir = tempdir().unwrap();
+    let cfg = disable_doc_start_config(dir.path());
+    let file = dir.path().join("missing.yaml");
+    fs::write(&file, "key: value").unwrap();
+    let syntax = dir.path().join("invalid.yaml");
+    fs::write(&syntax, "foo: [1, 2\n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, _stdout, stderr) = run(Command::new(exe)
+        .arg("--format")
+        .arg("parsable")
+        .arg("--show-rule-url")
+        .arg("-c")
+        .arg(&cfg)
+        .arg(&file)
+        .arg(&syntax));
+    assert_eq!(code, 1, "rule URLs should not change the exit code");
+    let lines: Vec<&str> = stderr.lines().collect();
+    assert_eq!(lines.len(), 2, "expected two diagnostic lines: {stderr}");
+    assert!(
+        lines[0].ends_with(
+            "(new-line-at-end-of-file) https://ryl-docs.pages.dev/rules/new-line-at-end-of-file/"
+        ),
+        "rule URL should follow the rule id: {}",
+        lines[0]
+    );
+    assert!(
+        !lines[1].contains("https://"),
+        "a syntax error has no rule page: {}",
+        lines[1]
+    );
+}
+
+#[test]
+fn concise_format_prints_one_line_per_problem_without_headers() {
+    let dir = tempdir().unwrap();
+    let spaced = dir.path().join("spaced.yaml");
+    fs::write(&spaced, "key:   value \n").unwrap();
+    let broken = dir.path().join("broken.yaml");
+    fs::write(&broken, "key: [\n").unwrap();
+    let clean = dir.path().join("clean.yaml");
+    fs::write(&clean, "key: value\n").unwrap();
+    let config = "rules: {colons: enable, trailing-spaces: {level: warning}}";
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, stdout, stderr) = run(Command::new(exe)
+        .args(["--format", "concise", "-d", config])
+        .arg(&spaced)
+        .arg(&clean)
+        .arg(&broken));
+    assert_eq!(code, 1, "concise format should keep error exit: {stderr}");
+    assert!(stdout.is_empty(), "concise format should write to stderr");
+    assert_eq!(
+        stderr,
+        format!(
+            "{spaced}:1:7 colons too many spaces after colon\n\
+             {spaced}:1:13 trailing-spaces trailing spaces\n\
+             {broken}:1:6 syntax error: unclosed bracket '[' (syntax)\n",
+            spaced = spaced.display(),
+            broken = broken.display(),
+        )
+    );
+
+    let (code, _stdout, stderr) = run(Command::new(exe)
+        .args(["--format", "concise", "-d", config])
+        .arg(&clean));
+    assert_eq!(code, 0, "{stderr}");
+    assert!(stderr.is_empty(), "a clean run prints nothing: {stderr}");
+}
+
 #[test]
 fn github_format_emits_workflow_commands() {
     let dir = tempdir().unwrap();
@@ -137,6 +245,36 @@ fn github_format_emits_workflow_commands() {
     );
 }
 
+#[test]
+fn github_format_emits_warning_commands_for_warnings() {
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("config.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  new-line-at-end-of-file:\n    level: warning\n",
+    )
+    .unwrap();
+    let file = dir.path().join("missing.yaml");
+    fs::write(&file, "key: value").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, _stdout, stderr) = run(Command::new(exe)
+        .arg("--format")
+        .arg("github")
+        .arg("-c")
+        .arg(&cfg)
+        .arg(&file));
+    assert_eq!(code, 0, "warnings alone keep a zero exit: {stderr}");
+    assert!(
+        stderr.contains("::warning file="),
+        "a warning-level problem maps to GitHub's warning annotation: {stderr}"
+    );
+    assert!(
+        !stderr.contains("::error"),
+        "no error annotation for a warning: {stderr}"
+    );
+}
+
 #[test]
 fn github_format_escapes_newlines_to_prevent_command_injection() {
     let dir = tempdir().unwrap();
@@ -255,13 +393,12 @@ fn github_format_escapes_percent_cr_and_property_delimiters() {
 #[cfg(unix)]
 #[test]
 fn error_message_paths_are_escaped_in_github_format() {
-    // A filename with an embedded newline + a fake workflow command, whose content
-    // is invalid UTF-8 so linting fails and the error message (which embeds the
-    // path) is emitted. The path must be sanitized so the newline cannot start a
-    // new ::command:: in CI.
+    // A filename with an embedded newline + a fake workflow command, pointing nowhere
+    // so reading fails and the error message (which embeds the path) is emitted. The
+    // path must be sanitized so the newline cannot start a new ::command:: in CI.
     let dir = tempdir().unwrap();
     let file = dir.path().join("evil\n::error::ARM_INJECT.yaml");
-    fs::write(&file, [0x80u8]).unwrap();
+    std::os::unix::fs::symlink(dir.path().join("nowhere.yaml"), &file).unwrap();
 
     let exe = env!("CARGO_BIN_EXE_ryl");
     let (_code, _stdout, stderr) = run(Command::new(exe)
@@ -458,6 +595,122 @@ fn auto_format_respects_no_color_env() {
     );
 }
 
+#[test]
+fn explicit_auto_format_matches_the_default_detection() {
+    let dir = tempdir().unwrap();
+    let cfg = disable_doc_start_config(dir.path());
+    let file = dir.path().join("missing.yaml");
+    fs::write(&file, "key: value").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let lint = |envs: &[(&str, &str)], explicit: bool| {
+        let mut cmd = Command::new(exe);
+        for key in [
+            "GITHUB_ACTIONS",
+            "GITHUB_WORKFLOW",
+            "FORCE_COLOR",
+            "NO_COLOR",
+        ] {
+            cmd.env_remove(key);
+        }
+        cmd.envs(envs.iter().copied())
+            .arg("-c")
+            .arg(&cfg)
+            .arg(&file);
+        if explicit {
+            cmd.args(["--format", "auto"]);
+        }
+        let (code, stdout, stderr) = run(&mut cmd);
+        assert_eq!(code, 1, "{stderr}");
+        assert!(stdout.is_empty(), "auto output goes to stderr: {stdout}");
+        stderr
+    };
+
+    let github = [("GITHUB_ACTIONS", "true"), ("GITHUB_WORKFLOW", "ci")];
+    let annotations = lint(&github, true);
+    assert!(annotations.starts_with("::group::"), "{annotations}");
+    assert_eq!(annotations, lint(&github, false));
+
+    let force_color = [("FORCE_COLOR", "1")];
+    let colored = lint(&force_color, true);
+    assert!(colored.contains("\u{001b}[31m"), "{colored}");
+    assert_eq!(colored, lint(&force_color, false));
+}
+
+#[test]
+fn verbose_reports_why_auto_chose_github() {
+    let dir = tempdir().unwrap();
+    let cfg = disable_doc_start_config(dir.path());
+    let file = dir.path().join("missing.yaml");
+    fs::write(&file, "key: value").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let auto = |verbose: bool| {
+        let mut cmd = Command::new(exe);
+        cmd.env("GITHUB_ACTIONS", "true")
+            .env("GITHUB_WORKFLOW", "ci")
+            .arg("-c")
+            .arg(&cfg)
+            .arg(&file);
+        if verbose {
+            cmd.arg("--verbose");
+        }
+        let (code, stdout, stderr) = run(&mut cmd);
+        assert_eq!(code, 1, "auto format keeps the error exit: {stderr}");
+        assert!(stdout.is_empty(), "auto output goes to stderr: {stdout}");
+        stderr
+    };
+
+    let verbose = auto(true);
+    let mut lines = verbose.lines();
+    assert_eq!(
+        lines.next(),
+        Some("auto: GitHub Actions detected -> github"),
+        "the rationale precedes the output: {verbose}"
+    );
+    assert_eq!(
+        lines.next().map(|line| line.starts_with("::group::")),
+        Some(true),
+        "{verbose}"
+    );
+    let quiet = auto(false);
+    assert!(
+        !quiet.contains("auto:"),
+        "no rationale without --verbose: {quiet}"
+    );
+}
+
+#[test]
+fn verbose_reports_auto_rationale_only_when_auto_is_used() {
+    let dir = tempdir().unwrap();
+    let cfg = disable_doc_start_config(dir.path());
+    let file = dir.path().join("missing.yaml");
+    fs::write(&file, "key: value").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let verbose = |format: &str| {
+        let (code, _stdout, stderr) = run(Command::new(exe)
+            .env("NO_COLOR", "1")
+            .env_remove("GITHUB_ACTIONS")
+            .env_remove("GITHUB_WORKFLOW")
+            .args(["--verbose", "--format", format, "-c"])
+            .arg(&cfg)
+            .arg(&file));
+        assert_eq!(code, 1, "{format}: {stderr}");
+        stderr
+    };
+
+    assert!(
+        verbose("auto").starts_with("auto: NO_COLOR set -> standard\n"),
+        "explains the fallback to standard"
+    );
+    let explicit = verbose("parsable");
+    assert!(
+        !explicit.contains("auto:"),
+        "an explicit format has nothing to explain: {explicit}"
+    );
+}
+
 // --- JUnit / GitLab report formats ---
 
 /// A file missing its trailing newline trips `new-line-at-end-of-file`, giving every
@@ -468,6 +721,111 @@ fn dirty_yaml(dir: &std::path::Path) -> std::path::PathBuf {
     file
 }
 
+#[test]
+fn relative_paths_applies_to_the_parsable_path_field() {
+    let dir = tempdir().unwrap();
+    let cfg = disable_doc_start_config(dir.path());
+    let sub = dir.path().join("sub");
+    fs::create_dir(&sub).unwrap();
+    let file = dirty_yaml(&sub);
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let parsable = |relative: bool| {
+        let mut cmd = Command::new(exe);
+        cmd.current_dir(dir.path())
+            .env_remove("CI_PROJECT_DIR")
+            .arg("--format")
+            .arg("parsable")
+            .arg("-c")
+            .arg(&cfg)
+            .arg(&file);
+        if relative {
+            cmd.arg("--relative-paths");
+        }
+        let (code, _stdout, stderr) = run(&mut cmd);
+        assert_eq!(code, 1, "{stderr}");
+        stderr
+    };
+
+    let absolute = parsable(false);
+    assert!(
+        absolute.starts_with(&format!("{}:1:11: [error]", file.display())),
+        "the path is shown as given by default: {absolute}"
+    );
+    let relative = parsable(true);
+    assert!(
+        relative.starts_with("sub/dirty.yaml:1:11: [error]"),
+        "relative to the working directory: {relative}"
+    );
+}
+
+#[test]
+fn relative_alias_strips_the_working_directory_from_console_and_github_paths() {
+    let dir = tempdir().unwrap();
+    let cfg = disable_doc_start_config(dir.path());
+    let sub = dir.path().join("sub");
+    fs::create_dir(&sub).unwrap();
+    let file = dirty_yaml(&sub);
+    let prefix = dir.path().display().to_string();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for (format, expected) in [
+        ("standard", "sub/dirty.yaml\n"),
+        (
+            "github",
+            "::group::sub/dirty.yaml\n::error file=sub/dirty.yaml,",
+        ),
+    ] {
+        let (code, _stdout, stderr) = run(Command::new(exe)
+            .current_dir(dir.path())
+            .env_remove("CI_PROJECT_DIR")
+            .args(["--format", format, "--relative", "-c"])
+            .arg(&cfg)
+            .arg(&file));
+        assert_eq!(code, 1, "{stderr}");
+        assert!(stderr.starts_with(expected), "{format}: {stderr}");
+        assert!(!stderr.contains(&prefix), "{format}: {stderr}");
+    }
+}
+
+#[test]
+fn absolute_paths_resolves_relative_inputs_in_every_format() {
+    let dir = tempdir().unwrap();
+    let cfg = disable_doc_start_config(dir.path());
+    let sub = dir.path().join("sub");
+    fs::create_dir(&sub).unwrap();
+    let file = fs::canonicalize(dirty_yaml(&sub)).unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let lint = |format: &str| {
+        let (code, stdout, stderr) = run(Command::new(exe)
+            .current_dir(dir.path())
+            .env_remove("CI_PROJECT_DIR")
+            .args(["--format", format, "--absolute-paths", "-c"])
+            .arg(&cfg)
+            .arg("sub/../sub/dirty.yaml"));
+        assert_eq!(code, 1, "{stderr}");
+        (stdout, stderr)
+    };
+
+    let (_, parsable) = lint("parsable");
+    assert!(
+        parsable.starts_with(&format!("{}:1:11: [error]", file.display())),
+        "a relative input is shown absolute: {parsable}"
+    );
+    let (json, _) = lint("json");
+    let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(json[0]["path"], file.display().to_string());
+
+    let (code, _, stderr) = run(Command::new(exe).current_dir(dir.path()).args([
+        "--absolute-paths",
+        "--relative-paths",
+        "sub",
+    ]));
+    assert_eq!(code, 2, "{stderr}");
+    assert!(stderr.contains("cannot be used with"), "{stderr}");
+}
+
 #[test]
 fn gitlab_format_writes_json_array_to_stdout() {
     let dir = tempdir().unwrap();
@@ -495,6 +853,287 @@ fn gitlab_format_writes_json_array_to_stdout() {
     assert_eq!(issues[0]["location"]["lines"]["begin"], 1);
 }
 
+#[test]
+fn codeclimate_format_writes_issues_with_content_body_to_stdout() {
+    let dir = tempdir().unwrap();
+    let cfg = disable_doc_start_config(dir.path());
+    let file = dirty_yaml(dir.path());
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, stdout, stderr) = run(Command::new(exe)
+        .arg("--format")
+        .arg("codeclimate")
+        .arg("-c")
+        .arg(&cfg)
+        .arg(&file));
+    assert_eq!(code, 1, "codeclimate format keeps the error exit code");
+    assert!(
+        stderr.is_empty(),
+        "report formats go to stdout, not stderr: {stderr}"
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout).expect("codeclimate output is a JSON array");
+    let issues = json.as_array().expect("top level array");
+    assert_eq!(issues.len(), 1, "one diagnostic expected: {stdout}");
+    assert_eq!(issues[0]["type"], "issue");
+    assert_eq!(issues[0]["check_name"], "new-line-at-end-of-file");
+    assert_eq!(issues[0]["categories"], serde_json::json!(["Style"]));
+    assert!(
+        issues[0]["content"]["body"]
+            .as_str()
+            .unwrap()
+            .contains("A trailing newline at end of file."),
+        "{stdout}"
+    );
+}
+
+#[test]
+fn codeclimate_fingerprints_are_stable_across_runs_and_checkouts() {
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let issues = || {
+        // A fresh checkout each run: fingerprints hash the project-relative path, so the
+        // temp dir's absolute location must not leak into them.
+        let dir = tempdir().unwrap();
+        fs::write(
+            dir.path().join("a.yaml"),
+            "key: value  
+b:  1",
+        )
+        .unwrap();
+        let (code, stdout, stderr) = run(Command::new(exe)
+            .current_dir(dir.path())
+            .env_remove("CI_PROJECT_DIR")
+            .args(["--format", "codeclimate", "-d"])
+            .arg(
+                "rules: {trailing-spaces: enable, colons: {level: warning}, \
+                 new-line-at-end-of-file: enable}",
+            )
+            .arg("a.yaml"));
+        assert_eq!(code, 1, "{stderr}");
+        let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+        json.as_array()
+            .unwrap()
+            .iter()
+            .map(|issue| {
+                (
+                    issue["check_name"].as_str().unwrap().to_string(),
+                    issue["severity"].as_str().unwrap().to_string(),
+                    issue["fingerprint"].as_str().unwrap().to_string(),
+                )
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let first = issues();
+    let mut severities: Vec<(&str, &str)> = first
+        .iter()
+        .map(|(rule, severity, _)| (rule.as_str(), severity.as_str()))
+        .collect();
+    severities.sort_unstable();
+    assert_eq!(
+        severities,
+        [
+            ("colons", "minor"),
+            ("new-line-at-end-of-file", "major"),
+            ("trailing-spaces", "major"),
+        ]
+    );
+    assert_eq!(
+        first,
+        issues(),
+        "identical input yields identical fingerprints"
+    );
+}
+
+#[test]
+fn sarif_format_writes_a_log_with_rule_metadata_to_stdout() {
+    let dir = tempdir().unwrap();
+    let cfg = disable_doc_start_config(dir.path());
+    let file = dirty_yaml(dir.path());
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, stdout, stderr) = run(Command::new(exe)
+        .arg("--format")
+        .arg("sarif")
+        .arg("-c")
+        .arg(&cfg)
+        .arg(&file));
+    assert_eq!(code, 1, "sarif format keeps the error exit code");
+    assert!(
+        stderr.is_empty(),
+        "report formats go to stdout, not stderr: {stderr}"
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout).expect("sarif output is a JSON object");
+    let run = &json["runs"][0];
+    assert_eq!(run["results"][0]["ruleId"], "new-line-at-end-of-file");
+    assert_eq!(
+        run["tool"]["driver"]["rules"][0]["helpUri"],
+        "https://ryl-docs.pages.dev/rules/new-line-at-end-of-file/"
+    );
+}
+
+#[test]
+fn sarif_results_carry_regions_and_keep_the_warning_exit_code() {
+    let dir = tempdir().unwrap();
+    fs::write(
+        dir.path().join("a.yaml"),
+        "key: value  
+other: 1 
+",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("b.yaml"),
+        "b:  1
+",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, stdout, stderr) = run(Command::new(exe)
+        .current_dir(dir.path())
+        .env_remove("CI_PROJECT_DIR")
+        .args(["--format", "sarif", "-d"])
+        .arg("rules: {trailing-spaces: {level: warning}, colons: {level: warning}}")
+        .args(["a.yaml", "b.yaml"]));
+    assert_eq!(code, 0, "warnings alone keep a zero exit: {stderr}");
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout).expect("sarif output is a JSON object");
+    let results = json["runs"][0]["results"]
+        .as_array()
+        .expect("results is an array");
+    let summary: Vec<(&str, &str, u64, u64)> = results
+        .iter()
+        .map(|result| {
+            let location = &result["locations"][0]["physicalLocation"];
+            (
+                result["ruleId"].as_str().unwrap(),
+                location["artifactLocation"]["uri"].as_str().unwrap(),
+                location["region"]["startLine"].as_u64().unwrap(),
+                location["region"]["startColumn"].as_u64().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            ("trailing-spaces", "a.yaml", 1, 11),
+            ("trailing-spaces", "a.yaml", 2, 9),
+            ("colons", "b.yaml", 1, 4),
+        ]
+    );
+    assert!(
+        results.iter().all(|result| result["level"] == "warning"),
+        "{json}"
+    );
+}
+
+#[test]
+fn review_format_writes_pr_review_comments_to_stdout() {
+    let dir = tempdir().unwrap();
+    let cfg = disable_doc_start_config(dir.path());
+    let file = dirty_yaml(dir.path());
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, stdout, stderr) = run(Command::new(exe)
+        .current_dir(dir.path())
+        .env_remove("CI_PROJECT_DIR")
+        .args(["--format", "review", "-c"])
+        .arg(&cfg)
+        .arg(&file));
+    assert_eq!(code, 1, "review format keeps the error exit code");
+    assert!(
+        stderr.is_empty(),
+        "report formats go to stdout, not stderr: {stderr}"
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout).expect("review output is a JSON array");
+    assert_eq!(
+        json,
+        serde_json::json!([{
+            "path": "dirty.yaml",
+            "line": 1,
+            "side": "RIGHT",
+            "body": "**error** `new-line-at-end-of-file`: no new line character at the end \
+                     of file (column 11)",
+        }])
+    );
+}
+
+#[test]
+fn json_format_writes_problem_objects_to_stdout() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("mixed.yaml");
+    fs::write(
+        &file,
+        "key: value  
+bad: [
+",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, stdout, stderr) = run(Command::new(exe)
+        .current_dir(dir.path())
+        .env_remove("CI_PROJECT_DIR")
+        .args(["--format", "json", "-d", "rules: {trailing-spaces: enable}"])
+        .arg(&file));
+    assert_eq!(code, 1, "json format keeps the error exit code");
+    assert!(
+        stderr.is_empty(),
+        "report formats go to stdout, not stderr: {stderr}"
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout).expect("json output is a JSON array");
+    let problems = json.as_array().expect("top level is an array");
+    assert_eq!(problems.len(), 1, "a syntax error stops the rules: {json}");
+    let syntax = &problems[0];
+    assert_eq!(syntax["path"], "mixed.yaml");
+    assert_eq!(syntax["level"], "error");
+    assert_eq!(syntax["rule"], serde_json::Value::Null);
+    assert!(
+        syntax["line"].is_u64() && syntax["column"].is_u64(),
+        "{syntax}"
+    );
+    assert!(
+        syntax["message"]
+            .as_str()
+            .is_some_and(|message| message.starts_with("syntax error:")),
+        "{syntax}"
+    );
+
+    fs::write(
+        &file,
+        "key: value  
+",
+    )
+    .unwrap();
+    let (code, stdout, _stderr) = run(Command::new(exe)
+        .current_dir(dir.path())
+        .env_remove("CI_PROJECT_DIR")
+        .args([
+            "--format",
+            "json",
+            "-d",
+            "rules: {trailing-spaces: {level: warning}}",
+        ])
+        .arg(&file));
+    assert_eq!(code, 0, "warnings alone still pass");
+    let json: serde_json::Value = serde_json::from_str(&stdout).unwrap();
+    assert_eq!(
+        json,
+        serde_json::json!([{
+            "path": "mixed.yaml",
+            "line": 1,
+            "column": 11,
+            "level": "warning",
+            "rule": "trailing-spaces",
+            "message": "trailing spaces",
+        }])
+    );
+}
+
 #[test]
 fn junit_format_writes_xml_to_stdout() {
     let dir = tempdir().unwrap();
@@ -1580,6 +2219,41 @@ fn config_output_is_auto_discovered_from_project_config() {
     assert_eq!(issues.as_array().unwrap().len(), 1);
 }
 
+#[test]
+fn unknown_format_is_a_usage_error_from_the_cli_and_the_config() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("a.yaml");
+    fs::write(&file, "key: value \n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, stdout, stderr) =
+        run(Command::new(exe).args(["--format", "jsonl"]).arg(&file));
+    assert_eq!(code, 2, "{stderr}");
+    assert!(stdout.is_empty(), "nothing is linted: {stdout}");
+    assert!(
+        stderr.contains("invalid value 'jsonl' for '--format <FORMAT>'")
+            && stderr.contains("a similar value exists: 'json'"),
+        "{stderr}"
+    );
+
+    fs::write(
+        dir.path().join(".ryl.toml"),
+        "[rules]\ntrailing-spaces = \"enable\"\n\n[output.jsonl]\n",
+    )
+    .unwrap();
+    let (code, stdout, stderr) =
+        run(Command::new(exe).current_dir(dir.path()).arg("a.yaml"));
+    assert_eq!(
+        code, 2,
+        "an unknown [output] format is a config error: {stderr}"
+    );
+    assert!(stdout.is_empty(), "nothing is linted: {stdout}");
+    assert!(
+        stderr.contains("unknown field `jsonl`, expected one of `auto`"),
+        "{stderr}"
+    );
+}
+
 #[test]
 fn yaml_config_rejects_output_table() {
     let dir = tempdir().unwrap();
@@ -1798,3 +2472,514 @@ fn two_outputs_under_aliased_parent_dirs_are_rejected() {
         "expected the colliding-output message: {stderr}"
     );
 }
+
+#[test]
+fn count_prints_only_totals_line() {
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("config.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  document-start: disable\n  new-line-at-end-of-file: enable\n  \
+         trailing-spaces:\n    level: warning\n",
+    )
+    .unwrap();
+    let src = dir.path().join("src");
+    fs::create_dir(&src).unwrap();
+    fs::write(src.join("errors.yaml"), "a: 1 \nb: 2").unwrap();
+    fs::write(src.join("warnings.yaml"), "a: 1 \nb: 2 \n").unwrap();
+    fs::write(src.join("clean.yaml"), "a: 1\n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, stdout, stderr) = run(Command::new(exe)
+        .arg("--count")
+        .arg("-c")
+        .arg(&cfg)
+        .arg(&src));
+    assert_eq!(code, 1, "an error keeps the error exit: {stderr}");
+    assert_eq!(stdout, "errors=1 warnings=3 files=3\n");
+    assert!(
+        stderr.is_empty(),
+        "no per-problem output expected: {stderr}"
+    );
+
+    let (code, stdout, _) = run(Command::new(exe)
+        .arg("--count")
+        .arg("--strict")
+        .arg("-c")
+        .arg(&cfg)
+        .arg(src.join("warnings.yaml")));
+    assert_eq!(code, 2, "--strict still applies to a warning-only run");
+    assert_eq!(stdout, "errors=0 warnings=2 files=1\n");
+
+    let (code, _, stderr) = run(Command::new(exe)
+        .arg("--count")
+        .arg("--format")
+        .arg("parsable")
+        .arg(&src));
+    assert_eq!(code, 2, "--count conflicts with --format: {stderr}");
+}
+
+#[test]
+fn summary_footer_follows_console_diagnostics_only() {
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("config.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  new-line-at-end-of-file: enable\n  trailing-spaces:\n    level: warning\n",
+    )
+    .unwrap();
+    let dirty = dir.path().join("dirty.yaml");
+    fs::write(&dirty, "a: 1 \nb: 2 \nc: 3").unwrap();
+    let clean = dir.path().join("clean.yaml");
+    fs::write(&clean, "a: 1\n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let summary = |format: &str, file: &std::path::Path| {
+        run(Command::new(exe)
+            .arg("--summary")
+            .arg("--format")
+            .arg(format)
+            .arg("-c")
+            .arg(&cfg)
+            .arg(file))
+    };
+
+    for format in ["standard", "colored"] {
+        let (code, _, stderr) = summary(format, &dirty);
+        assert_eq!(code, 1, "{stderr}");
+        assert!(
+            stderr.ends_with("\n3 problems (1 error, 2 warnings)\n"),
+            "{format}: {stderr}"
+        );
+    }
+
+    let (code, stdout, stderr) = summary("standard", &clean);
+    assert_eq!(code, 0);
+    assert!(stdout.is_empty() && stderr.is_empty(), "{stderr}");
+
+    for format in ["github", "parsable", "json"] {
+        let (_, stdout, stderr) = summary(format, &dirty);
+        assert!(
+            !stdout.contains("problems (") && !stderr.contains("problems ("),
+            "{format}: {stdout}{stderr}"
+        );
+    }
+
+    let (code, _, stderr) = run(Command::new(exe)
+        .arg("--summary")
+        .arg("--count")
+        .arg(&dirty));
+    assert_eq!(code, 2, "--summary conflicts with --count: {stderr}");
+}
+
+#[cfg(unix)]
+#[test]
+fn status_fd_writes_totals_to_the_numbered_descriptor() {
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("config.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  new-line-at-end-of-file: enable\n  trailing-spaces:\n    level: warning\n",
+    )
+    .unwrap();
+    let file = dir.path().join("a.yaml");
+    fs::write(&file, "a: 1 \nb: 2 \nc: 3").unwrap();
+    let status = dir.path().join("status.txt");
+
+    // The shell opens fd 3 on the status file before exec'ing ryl with its arguments.
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, stdout, stderr) = run(Command::new("sh")
+        .arg("-c")
+        .arg("exec \"$0\" --status-fd 3 \"$@\" 3>\"$STATUS\"")
+        .env("STATUS", &status)
+        .arg(exe)
+        .args(["--format", "parsable", "-c"])
+        .arg(&cfg)
+        .arg(&file));
+    assert_eq!(code, 1, "{stderr}");
+    assert_eq!(
+        fs::read_to_string(&status).unwrap(),
+        "status errors=1 warnings=2\n"
+    );
+    assert!(stdout.is_empty(), "the status stays off stdout: {stdout}");
+    assert_eq!(
+        stderr.lines().count(),
+        3,
+        "diagnostics are unchanged: {stderr}"
+    );
+
+    let (code, _, stderr) = run(Command::new("sh")
+        .arg("-c")
+        .arg("exec \"$0\" --status-fd 3 \"$@\" 3>&-")
+        .arg(exe)
+        .arg("-c")
+        .arg(&cfg)
+        .arg(&file));
+    assert_eq!(code, 2, "a closed descriptor is an error: {stderr}");
+    assert!(stderr.contains("failed to write --status-fd 3"), "{stderr}");
+}
+
+#[test]
+fn github_format_collapses_duplicate_annotations() {
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("config.yml");
+    fs::write(&cfg, "rules:\n  empty-values: enable\n").unwrap();
+    let file = dir.path().join("dup.yaml");
+    // empty-values reports the empty explicit-key value twice at the same position.
+    fs::write(&file, "a: |\n? k\n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (_, _, parsable) = run(Command::new(exe)
+        .args(["--format", "parsable", "-c"])
+        .arg(&cfg)
+        .arg(&file));
+    assert_eq!(
+        parsable.lines().count(),
+        2,
+        "fixture must duplicate: {parsable}"
+    );
+
+    let (code, _, stderr) = run(Command::new(exe)
+        .args(["--format", "github", "-c"])
+        .arg(&cfg)
+        .arg(&file));
+    assert_eq!(code, 1);
+    let annotations: Vec<&str> = stderr
+        .lines()
+        .filter(|line| line.starts_with("::error "))
+        .collect();
+    assert_eq!(annotations.len(), 1, "duplicates collapse: {stderr}");
+}
+
+#[test]
+fn github_format_truncates_at_max_annotations() {
+    let dir = tempdir().unwrap();
+    let cfg = disable_doc_start_config(dir.path());
+    fs::write(dir.path().join("a.yaml"), "key: value").unwrap();
+    fs::write(dir.path().join("b.yaml"), "key: value").unwrap();
+    fs::write(dir.path().join("c.yaml"), "key: value").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, _, stderr) = run(Command::new(exe)
+        .args(["--format", "github", "--max-annotations", "1", "-c"])
+        .arg(&cfg)
+        .arg(dir.path().join("a.yaml"))
+        .arg(dir.path().join("b.yaml"))
+        .arg(dir.path().join("c.yaml")));
+    assert_eq!(code, 1, "truncation keeps the error exit");
+    let annotations: Vec<&str> = stderr
+        .lines()
+        .filter(|line| line.starts_with("::error "))
+        .collect();
+    assert_eq!(annotations.len(), 1, "capped at one annotation: {stderr}");
+    assert_eq!(stderr.matches("::group::").count(), 1, "{stderr}");
+    assert!(
+        stderr.ends_with("::notice::2 more annotations omitted by --max-annotations\n"),
+        "missing summary notice: {stderr}"
+    );
+}
+
+#[test]
+fn github_color_always_colors_only_the_message_after_the_command_prefix() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("a.yaml");
+    fs::write(&file, "key:  value \n").unwrap();
+    let config = "rules: {colons: enable, trailing-spaces: {level: warning}}";
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, _, stderr) = run(Command::new(exe)
+        .args(["--format", "github", "--color", "always", "-d", config])
+        .arg(&file));
+    assert_eq!(code, 1, "{stderr}");
+    let annotations: Vec<&str> = stderr
+        .lines()
+        .filter(|line| line.starts_with("::error ") || line.starts_with("::warning "))
+        .collect();
+    assert_eq!(annotations.len(), 2, "{stderr}");
+    for annotation in &annotations {
+        let (command, message) = annotation
+            .split_once("::")
+            .unwrap()
+            .1
+            .split_once("::")
+            .unwrap();
+        assert!(!command.contains('\u{001b}'), "{annotation:?}");
+        assert!(message.starts_with("\u{001b}[2m"), "{annotation:?}");
+        assert!(message.ends_with("\u{001b}[0m"), "{annotation:?}");
+    }
+    assert!(
+        annotations[0].ends_with(
+            "::\u{001b}[2m1:6\u{001b}[0m \u{001b}[2m[colons]\u{001b}[0m \
+             \u{001b}[31mtoo many spaces after colon\u{001b}[0m"
+        ),
+        "{stderr:?}"
+    );
+    assert!(
+        annotations[1].ends_with("\u{001b}[33mtrailing spaces\u{001b}[0m"),
+        "{stderr:?}"
+    );
+    for plain in ["::group::", "::endgroup::"] {
+        assert!(
+            stderr
+                .lines()
+                .any(|line| line.starts_with(plain) && !line.contains('\u{001b}'))
+        );
+    }
+
+    for color in [&["--color", "never"][..], &["--color", "auto"], &[]] {
+        let (_, _, stderr) = run(Command::new(exe)
+            .args(["--format", "github", "-d", config])
+            .args(color)
+            .arg(&file));
+        assert!(!stderr.contains('\u{001b}'), "{color:?}: {stderr:?}");
+    }
+}
+
+#[test]
+fn color_never_and_always_apply_to_colored_and_auto_formats() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("a.yaml");
+    fs::write(&file, "key:  value\n").unwrap();
+    let config = "rules: {colons: enable}";
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let lint = |args: &[&str], envs: &[(&str, &str)]| {
+        let mut cmd = Command::new(exe);
+        for key in [
+            "GITHUB_ACTIONS",
+            "GITHUB_WORKFLOW",
+            "FORCE_COLOR",
+            "NO_COLOR",
+        ] {
+            cmd.env_remove(key);
+        }
+        let (code, stdout, stderr) = run(cmd
+            .envs(envs.iter().copied())
+            .args(args)
+            .args(["-d", config])
+            .arg(&file));
+        assert_eq!(code, 1, "{stderr}");
+        assert!(stdout.is_empty(), "{stdout}");
+        stderr
+    };
+
+    let standard = lint(&["--format", "standard"], &[]);
+    assert!(!standard.contains('\u{001b}'), "{standard:?}");
+    assert_eq!(
+        lint(&["--format", "colored", "--color", "never"], &[]),
+        standard,
+        "--color never prints colored as standard"
+    );
+    assert_eq!(
+        lint(&["--color", "never"], &[("FORCE_COLOR", "1")]),
+        standard,
+        "--color never beats FORCE_COLOR for auto"
+    );
+
+    let colored = lint(&["--format", "colored"], &[]);
+    assert!(colored.contains("\u{001b}[31m"), "{colored:?}");
+    assert_eq!(
+        lint(&["--color", "always"], &[("NO_COLOR", "1")]),
+        colored,
+        "--color always beats NO_COLOR for auto"
+    );
+    assert_eq!(
+        lint(&["--format", "colored", "--color", "auto"], &[]),
+        colored
+    );
+
+    let verbose = lint(&["--verbose", "--color", "never"], &[]);
+    assert!(
+        verbose.starts_with("auto: --color never -> standard\n"),
+        "{verbose}"
+    );
+}
+
+#[test]
+fn no_warnings_leaves_no_empty_file_block_in_any_console_format() {
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("config.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  new-line-at-end-of-file:\n    level: warning\n",
+    )
+    .unwrap();
+    let file = dir.path().join("warn.yaml");
+    fs::write(&file, "key: value").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for format in ["standard", "colored", "github", "parsable"] {
+        let (code, stdout, stderr) = run(Command::new(exe)
+            .args(["--no-warnings", "--format", format, "-c"])
+            .arg(&cfg)
+            .arg(&file));
+        assert_eq!(code, 0, "{format}: only filtered warnings: {stderr}");
+        assert!(stdout.is_empty(), "{format}: unexpected stdout: {stdout}");
+        assert!(
+            stderr.is_empty(),
+            "{format}: a fully filtered file prints no header or block: {stderr}"
+        );
+    }
+}
+
+#[test]
+fn unknown_format_is_rejected_with_the_valid_choices() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("a.yaml");
+    fs::write(&file, "key: value\n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, stdout, stderr) =
+        run(Command::new(exe).args(["--format", "xml"]).arg(&file));
+    assert_eq!(code, 2, "an unknown format is a usage error: {stderr}");
+    assert!(stdout.is_empty(), "nothing is linted: {stdout}");
+    assert!(
+        stderr.contains("invalid value 'xml' for '--format <FORMAT>'"),
+        "names the rejected value: {stderr}"
+    );
+    assert!(
+        stderr.contains(
+            "[possible values: auto, standard, colored, github, parsable, concise, junit, \
+             gitlab, codeclimate, sarif, review, json]"
+        ),
+        "lists every valid format: {stderr}"
+    );
+}
+
+#[test]
+fn annotate_config_names_each_files_config_in_the_standard_header() {
+    let dir = tempdir().unwrap();
+    fs::write(
+        dir.path().join(".yamllint"),
+        "rules:\n  new-line-at-end-of-file: enable\n",
+    )
+    .unwrap();
+    let sub = dir.path().join("sub");
+    fs::create_dir(&sub).unwrap();
+    fs::write(
+        sub.join(".yamllint"),
+        "rules:\n  new-line-at-end-of-file: enable\n  document-start: enable\n",
+    )
+    .unwrap();
+    dirty_yaml(dir.path());
+    dirty_yaml(&sub);
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let standard = |annotate: bool, relative: bool| {
+        let mut cmd = Command::new(exe);
+        cmd.current_dir(dir.path())
+            .env_remove("CI_PROJECT_DIR")
+            .env_remove("YAMLLINT_CONFIG_FILE")
+            .args(["--format", "standard", "."]);
+        if annotate {
+            cmd.arg("--annotate-config");
+        }
+        if relative {
+            cmd.arg("--relative-paths");
+        }
+        let (code, stdout, stderr) = run(&mut cmd);
+        assert_eq!(code, 1, "{stderr}");
+        assert!(
+            stdout.is_empty(),
+            "standard format writes to stderr: {stdout}"
+        );
+        stderr
+    };
+
+    let headers = |output: &str| -> Vec<String> {
+        output
+            .lines()
+            .filter(|line| line.contains("dirty.yaml"))
+            .map(str::to_string)
+            .collect()
+    };
+
+    let plain = standard(false, true);
+    assert!(
+        !plain.contains("[config: "),
+        "headers are unannotated by default: {plain}"
+    );
+    let annotated = standard(true, true);
+    assert_eq!(
+        headers(&annotated),
+        vec![
+            "dirty.yaml  [config: .yamllint]",
+            "sub/dirty.yaml  [config: sub/.yamllint]",
+        ],
+        "each header names the config that applied: {annotated}"
+    );
+
+    // As-given paths keep the file path's `./` but clean the joined config path, which
+    // would otherwise read `././.yamllint`.
+    let as_given = standard(true, false);
+    assert_eq!(
+        headers(&as_given),
+        vec![
+            "./dirty.yaml  [config: .yamllint]",
+            "./sub/dirty.yaml  [config: sub/.yamllint]",
+        ],
+        "{as_given}"
+    );
+}
+
+#[test]
+fn format_template_renders_each_diagnostic_with_named_placeholders() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("t.yaml");
+    fs::write(&file, "a: 1 \nb:  2\n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, stdout, stderr) = run(Command::new(exe).current_dir(dir.path()).args([
+        "--format-template",
+        "{path}:{line}:{col}: {level} {message} ({rule}) {{}}",
+        "-d",
+        "rules: {trailing-spaces: enable, colons: {level: warning}}",
+        "t.yaml",
+    ]));
+    assert_eq!(code, 1, "{stderr}");
+    assert!(stdout.is_empty(), "templates go to stderr: {stdout}");
+    let mut lines: Vec<&str> = stderr.lines().collect();
+    lines.sort_unstable();
+    assert_eq!(
+        lines,
+        [
+            "t.yaml:1:5: error trailing spaces (trailing-spaces) {}",
+            "t.yaml:2:4: warning too many spaces after colon (colons) {}",
+        ]
+    );
+
+    let (code, _, stderr) = run(Command::new(exe).current_dir(dir.path()).args([
+        "--format-template",
+        "{path}:{column}",
+        "t.yaml",
+    ]));
+    assert_eq!(code, 2, "{stderr}");
+    assert!(stderr.contains("unknown placeholder {column}"), "{stderr}");
+}
+
+#[test]
+fn format_template_takes_an_output_file_and_runs_alongside_formats() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("t.yaml");
+    fs::write(&file, "a: [1\n").unwrap();
+    let out = dir.path().join("out.txt");
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, _, stderr) = run(Command::new(exe)
+        .current_dir(dir.path())
+        .args(["-d", "extends: default", "--format", "parsable"])
+        .args(["--format-template", "{rule}|{line}"])
+        .arg("--output-file")
+        .arg(&out)
+        .arg("t.yaml"));
+    assert_eq!(code, 1, "{stderr}");
+    assert!(
+        stderr.starts_with("t.yaml:"),
+        "parsable keeps stderr: {stderr}"
+    );
+    assert_eq!(
+        fs::read_to_string(&out).unwrap(),
+        "|1\n",
+        "a syntax error has no rule"
+    );
+}
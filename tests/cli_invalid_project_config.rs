@@ -32,6 +32,42 @@ fn invalid_project_config_for_explicit_file_causes_exit_2() {
     assert!(err.contains("failed to read"));
 }
 
+#[test]
+fn invalid_nested_config_error_names_the_offending_file() {
+    let td = tempdir().unwrap();
+    let root = td.path();
+    fs::write(root.join(".yamllint"), "extends: default\n").unwrap();
+    fs::write(root.join("a.yaml"), "---\na: 1\n").unwrap();
+    let sub = root.join("sub");
+    fs::create_dir(&sub).unwrap();
+    fs::write(sub.join("b.yaml"), "---\nb: 1\n").unwrap();
+
+    for (name, content, detail) in [
+        (
+            ".yamllint",
+            "rules:\n  colons: enable\n  bad: {\n",
+            "line 3 column 8",
+        ),
+        (".ryl.toml", "rules = [\n", "line 1, column 10"),
+        (
+            ".yamllint",
+            "rules: {no-such-rule: enable}\n",
+            "no such rule",
+        ),
+    ] {
+        let config = sub.join(name);
+        fs::write(&config, content).unwrap();
+        let (code, _out, err) = run(ryl(root).arg(root.join("a.yaml")).arg(&sub));
+        assert_eq!(code, 2, "{name}: {err}");
+        assert!(
+            err.starts_with(&format!("{}: ", config.display())),
+            "{name}: {err}"
+        );
+        assert!(err.contains(detail), "{name}: {err}");
+        fs::remove_file(&config).unwrap();
+    }
+}
+
 #[test]
 fn invalid_output_config_with_no_lintable_files_causes_exit_2() {
     // Linting an empty subdirectory finds no files, so per-file discovery (which descends
@@ -59,3 +95,19 @@ fn invalid_output_config_with_no_lintable_files_causes_exit_2() {
         "expected the empty-path config error: {err}"
     );
 }
+
+#[test]
+fn mutually_extending_configs_exit_2_with_a_cycle_error() {
+    let td = tempdir().unwrap();
+    let root = td.path();
+    fs::write(root.join(".yamllint"), "extends: base.yaml\n").unwrap();
+    fs::write(root.join("base.yaml"), "extends: .yamllint\n").unwrap();
+    fs::write(root.join("a.yaml"), "---\na: 1\n").unwrap();
+
+    let (code, _out, err) = run(ryl(root).arg(root.join("a.yaml")));
+    assert_eq!(code, 2, "expected exit 2: {err}");
+    assert!(
+        err.contains("invalid config: cyclic extends detected at "),
+        "{err}"
+    );
+}
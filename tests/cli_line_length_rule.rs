@@ -81,3 +81,46 @@ fn rule_ignore_skips_file() {
     assert!(stdout.trim().is_empty(), "expected no stdout: {stdout}");
     assert!(stderr.trim().is_empty(), "expected no stderr: {stderr}");
 }
+
+#[test]
+fn rule_ignore_skips_only_that_rule() {
+    let dir = tempdir().unwrap();
+    let long = format!("key: {} \n", "z".repeat(100));
+    let ignored = dir.path().join("generated.yaml");
+    fs::write(&ignored, &long).unwrap();
+    let checked = dir.path().join("checked.yaml");
+    fs::write(&checked, &long).unwrap();
+    fs::write(
+        dir.path().join("line-length-ignore.txt"),
+        "generated.yaml\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for ignore in [
+        "ignore: 'generated.yaml'",
+        "ignore-from-file: line-length-ignore.txt",
+    ] {
+        let config = dir.path().join(".yamllint");
+        fs::write(
+            &config,
+            format!(
+                "rules:\n  line-length:\n    {ignore}\n  trailing-spaces: enable\n"
+            ),
+        )
+        .unwrap();
+        let (code, _stdout, stderr) = run(Command::new(exe)
+            .current_dir(dir.path())
+            .args(["--format", "parsable", "-c"])
+            .arg(&config)
+            .args(["generated.yaml", "checked.yaml"]));
+        assert_eq!(code, 1, "{ignore}: {stderr}");
+        assert_eq!(
+            stderr,
+            "generated.yaml:1:106: [error] trailing spaces (trailing-spaces)\n\
+             checked.yaml:1:81: [error] line too long (106 > 80 characters) (line-length)\n\
+             checked.yaml:1:106: [error] trailing spaces (trailing-spaces)\n",
+            "{ignore}"
+        );
+    }
+}
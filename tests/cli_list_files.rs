@@ -22,3 +22,38 @@ fn list_files_outputs_expected_entries() {
         "expected stdout to include listed file: {stdout}"
     );
 }
+
+#[test]
+fn list_files_applies_config_data_file_selection_and_sorts() {
+    let dir = tempdir().unwrap();
+    let root = dir.path();
+    for name in ["b.yaml", "a.data", "z/c.data", "a/y.yml", "skip/d.data"] {
+        let path = root.join(name);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, "key: value\n").unwrap();
+    }
+    // A project config `-d` replaces, so its broader selection must not leak in.
+    fs::write(root.join(".yamllint"), "yaml-files: ['*.yaml', '*.yml']\n").unwrap();
+
+    let (code, stdout, stderr) = run(ryl(root)
+        .current_dir(root)
+        .arg("--list-files")
+        .arg("-d")
+        .arg("{extends: default, yaml-files: ['*.data'], ignore: ['skip/']}")
+        .arg("."));
+    assert_eq!(code, 0, "list-files should succeed: stderr={stderr}");
+    assert_eq!(stdout, "./a.data\n./z/c.data\n");
+
+    let (code, stdout, stderr) =
+        run(ryl(root).current_dir(root).arg("--list-files").arg(root));
+    assert_eq!(code, 0, "list-files should succeed: stderr={stderr}");
+    let listed: Vec<&str> = stdout.lines().collect();
+    let mut sorted = listed.clone();
+    sorted.sort_unstable();
+    assert_eq!(listed, sorted, "the listing is sorted");
+    assert_eq!(
+        listed.len(),
+        2,
+        "the project config selects yaml only: {stdout}"
+    );
+}
@@ -144,3 +144,37 @@ fn strict_mode_with_warning_exits_with_two() {
         "expected warning output: {output}"
     );
 }
+
+#[test]
+fn extra_trailing_newlines_are_left_to_empty_lines() {
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("blank_end.yaml");
+    fs::write(&file, "key: v\n\n").unwrap();
+    assert_eq!(
+        ryl::rules::new_line_at_end_of_file::check("key: v\n\n\n"),
+        None
+    );
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let (code, stdout, stderr) = run(Command::new(exe)
+        .arg("-d")
+        .arg("rules:\n  new-line-at-end-of-file: enable\n")
+        .arg(&file));
+    assert_eq!(
+        code, 0,
+        "ends with a newline: stdout={stdout} stderr={stderr}"
+    );
+
+    let (code, _stdout, stderr) = run(Command::new(exe)
+        .args(["-f", "parsable", "-d"])
+        .arg("rules:\n  new-line-at-end-of-file: enable\n  empty-lines: enable\n")
+        .arg(&file));
+    assert_eq!(code, 1, "{stderr}");
+    assert_eq!(
+        stderr,
+        format!(
+            "{}:2:1: [error] too many blank lines (1 > 0) (empty-lines)\n",
+            file.display()
+        )
+    );
+}
@@ -0,0 +1,69 @@
+use std::fs;
+
+use serde_json::Value;
+use tempfile::tempdir;
+
+mod common;
+use common::cli::{run, ryl};
+
+#[test]
+fn profile_json_records_file_and_rule_durations() {
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("config.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  colons: enable\n  trailing-spaces: enable\n",
+    )
+    .unwrap();
+    let yaml = dir.path().join("a.yaml");
+    fs::write(&yaml, "key:  value\n").unwrap();
+    let markdown = dir.path().join("b.md");
+    fs::write(&markdown, "```yaml\na: 1\n```\n\n```yaml\nb: 2\n```\n").unwrap();
+    let profile = dir.path().join("profile.json");
+
+    let (code, _stdout, stderr) = run(ryl(dir.path())
+        .arg("--markdown")
+        .arg("-c")
+        .arg(&cfg)
+        .arg("--profile-json")
+        .arg(&profile)
+        .arg(&yaml)
+        .arg(&markdown));
+    assert_eq!(code, 1, "profiling keeps the lint result: {stderr}");
+    assert!(stderr.contains("too many spaces after colon"), "{stderr}");
+
+    let report: Value =
+        serde_json::from_str(&fs::read_to_string(&profile).unwrap()).unwrap();
+    let files = report["files"].as_array().expect("files array");
+    assert_eq!(files.len(), 2, "{report}");
+    for file in files {
+        assert!(file["duration_ns"].is_u64(), "{file}");
+        let rules: Vec<&str> = file["rules"]
+            .as_array()
+            .expect("per-file rules array")
+            .iter()
+            .map(|rule| {
+                assert!(rule["duration_ns"].is_u64(), "{rule}");
+                rule["rule"].as_str().unwrap()
+            })
+            .collect();
+        // A rule run once per embedded Markdown region is reported once, summed.
+        assert_eq!(rules, ["colons", "trailing-spaces"], "{file}");
+    }
+    assert!(files[0]["path"].as_str().unwrap().ends_with("a.yaml"));
+    assert_eq!(report["rules"].as_array().unwrap().len(), 2, "{report}");
+}
+
+#[test]
+fn profile_json_is_rejected_for_stdin() {
+    let dir = tempdir().unwrap();
+    let (code, _stdout, stderr) = run(ryl(dir.path())
+        .arg("--profile-json")
+        .arg(dir.path().join("profile.json"))
+        .arg("-"));
+    assert_eq!(code, 2);
+    assert!(
+        stderr.contains("`--profile-json` is not supported when reading from stdin"),
+        "{stderr}"
+    );
+}
@@ -0,0 +1,183 @@
+use std::fs;
+
+use tempfile::tempdir;
+
+mod common;
+use common::cli::{command_output, run, ryl};
+
+#[test]
+fn rulesdir_pattern_rule_forbids_todo_in_values() {
+    let dir = tempdir().unwrap();
+    let rules = dir.path().join("rules");
+    fs::create_dir(&rules).unwrap();
+    fs::write(
+        rules.join("no-todo.yaml"),
+        "rule: no-todo\npattern: TODO\nscope: values\nmessage: value contains TODO\n",
+    )
+    .unwrap();
+    let cfg = dir.path().join("config.yml");
+    fs::write(&cfg, "rules:\n  document-start: disable\n").unwrap();
+    let yaml = dir.path().join("a.yaml");
+    fs::write(
+        &yaml,
+        "TODO_key: fine\nnote: TODO later\nitems:\n  - done\n  - \"TODO: ship\"\n",
+    )
+    .unwrap();
+
+    let (code, stdout, stderr) = run(ryl(dir.path())
+        .arg("-c")
+        .arg(&cfg)
+        .arg("--rulesdir")
+        .arg(&rules)
+        .arg("--format")
+        .arg("parsable")
+        .arg("--show-rule-url")
+        .arg(&yaml));
+    assert_eq!(code, 1, "stdout={stdout} stderr={stderr}");
+    let output = command_output(&stdout, &stderr);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 2, "{output}");
+    // The key matches the pattern but is out of scope; the rule has no docs URL to show.
+    assert!(
+        lines[0].ends_with(":2:7: [error] value contains TODO (no-todo)"),
+        "{output}"
+    );
+    assert!(
+        lines[1].ends_with(":5:5: [error] value contains TODO (no-todo)"),
+        "{output}"
+    );
+}
+
+#[test]
+fn rulesdir_scopes_and_levels_apply() {
+    let dir = tempdir().unwrap();
+    let rules = dir.path().join("rules");
+    fs::create_dir(&rules).unwrap();
+    fs::write(
+        rules.join("keys.yml"),
+        "rule: snake-keys\npattern: '[A-Z]'\nscope: keys\nlevel: warning\n",
+    )
+    .unwrap();
+    fs::write(
+        rules.join("lines.yaml"),
+        "rule: no-fixme\npattern: FIXME\nscope: lines\n",
+    )
+    .unwrap();
+    fs::write(rules.join("README.md"), "not a rule file\n").unwrap();
+    let cfg = dir.path().join("config.yml");
+    fs::write(&cfg, "rules:\n  document-start: disable\n").unwrap();
+    let yaml = dir.path().join("a.yaml");
+    fs::write(&yaml, "goodKey: 1\nbad: value  # FIXME\n").unwrap();
+
+    let (code, stdout, stderr) = run(ryl(dir.path())
+        .arg("-c")
+        .arg(&cfg)
+        .arg("--rulesdir")
+        .arg(&rules)
+        .arg("--format")
+        .arg("parsable")
+        .arg(&yaml));
+    assert_eq!(code, 1, "stdout={stdout} stderr={stderr}");
+    let output = command_output(&stdout, &stderr);
+    let lines: Vec<&str> = output.lines().collect();
+    assert_eq!(lines.len(), 2, "{output}");
+    assert!(
+        lines[0].ends_with(
+            ":1:1: [warning] found forbidden pattern \"[A-Z]\" (snake-keys)"
+        ),
+        "{output}"
+    );
+    assert!(
+        lines[1]
+            .ends_with(":2:15: [error] found forbidden pattern \"FIXME\" (no-fixme)"),
+        "{output}"
+    );
+}
+
+#[test]
+fn rulesdir_pattern_rules_honour_directives_and_per_line_ignores() {
+    let dir = tempdir().unwrap();
+    let rules = dir.path().join("rules");
+    fs::create_dir(&rules).unwrap();
+    fs::write(
+        rules.join("no-todo.yaml"),
+        "rule: no-todo\npattern: TODO\nscope: values\n",
+    )
+    .unwrap();
+    let cfg = dir.path().join(".ryl.toml");
+    fs::write(
+        &cfg,
+        "[rules.trailing-spaces]\n\n[[per-line-ignores]]\nregex = '^g:'\n\
+         rules = [\"ALL\"]\n",
+    )
+    .unwrap();
+    let yaml = dir.path().join("a.yaml");
+    fs::write(
+        &yaml,
+        "a: TODO\n\
+         b: TODO  # ryl disable-line rule:no-todo\n\
+         c: TODO  # yamllint disable-line\n\
+         # ryl disable rule:no-todo\n\
+         d: TODO\n\
+         # ryl enable\n\
+         e: TODO\n\
+         # yamllint disable\n\
+         f: TODO\n\
+         # yamllint enable\n\
+         g: TODO\n\
+         h: TODO\n",
+    )
+    .unwrap();
+
+    let (code, stdout, stderr) = run(ryl(dir.path())
+        .arg("-c")
+        .arg(&cfg)
+        .arg("--rulesdir")
+        .arg(&rules)
+        .arg("--format")
+        .arg("parsable")
+        .arg(&yaml));
+    assert_eq!(code, 1, "stdout={stdout} stderr={stderr}");
+    let output = command_output(&stdout, &stderr);
+    let lines: Vec<&str> = output.lines().collect();
+    // Only the lines no directive or `ALL` per-line entry covers are reported.
+    assert_eq!(lines.len(), 3, "{output}");
+    for (line, position) in lines.iter().zip([":1:4:", ":7:4:", ":12:4:"]) {
+        assert!(
+            line.ends_with(&format!(
+                "{position} [error] found forbidden pattern \"TODO\" (no-todo)"
+            )),
+            "{output}"
+        );
+    }
+}
+
+#[test]
+fn rulesdir_rejects_invalid_rule_files() {
+    let cases = [
+        (
+            "rule: colons\npattern: x\nscope: lines\n",
+            "`colons` is a built-in rule",
+        ),
+        (
+            "rule: r\npattern: '('\nscope: lines\n",
+            "invalid `pattern` regex",
+        ),
+        ("rule: r\npattern: x\nscope: docs\n", "`scope` should be"),
+        ("pattern: x\nscope: lines\n", "missing `rule`"),
+    ];
+    for (rule_file, expected) in cases {
+        let dir = tempdir().unwrap();
+        let rules = dir.path().join("rules");
+        fs::create_dir(&rules).unwrap();
+        fs::write(rules.join("bad.yaml"), rule_file).unwrap();
+        let yaml = dir.path().join("a.yaml");
+        fs::write(&yaml, "a: 1\n").unwrap();
+
+        let (code, _stdout, stderr) =
+            run(ryl(dir.path()).arg("--rulesdir").arg(&rules).arg(&yaml));
+        assert_eq!(code, 2, "{rule_file}: {stderr}");
+        assert!(stderr.contains("invalid rule file"), "{stderr}");
+        assert!(stderr.contains(expected), "{rule_file}: {stderr}");
+    }
+}
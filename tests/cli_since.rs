@@ -0,0 +1,89 @@
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+use tempfile::tempdir;
+
+mod common;
+use common::cli::{run, ryl};
+
+fn git(repo: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args([
+            "-c",
+            "user.name=ryl",
+            "-c",
+            "user.email=ryl@example.com",
+            "-c",
+            "commit.gpgsign=false",
+        ])
+        .args(args)
+        .current_dir(repo)
+        .status()
+        .expect("git should run");
+    assert!(status.success(), "git {args:?} failed");
+}
+
+#[test]
+fn since_lints_only_files_changed_after_ref() {
+    let dir = tempdir().unwrap();
+    let repo = dir.path();
+    fs::write(
+        repo.join(".ryl.toml"),
+        "[rules]\ntrailing-spaces = 'enable'\n",
+    )
+    .unwrap();
+    fs::write(repo.join("unchanged.yaml"), "a: 1 \n").unwrap();
+    fs::write(repo.join("changed.yaml"), "a: 1\n").unwrap();
+    fs::write(repo.join("removed.yaml"), "a: 1 \n").unwrap();
+    git(repo, &["init", "-q"]);
+    git(repo, &["add", "."]);
+    git(repo, &["commit", "-q", "-m", "base"]);
+    git(repo, &["tag", "base"]);
+
+    fs::write(repo.join("changed.yaml"), "a: 1 \n").unwrap();
+    fs::write(repo.join("notes.txt"), "not yaml\n").unwrap();
+    fs::remove_file(repo.join("removed.yaml")).unwrap();
+    git(repo, &["add", "-A"]);
+    git(repo, &["commit", "-q", "-m", "change"]);
+
+    let (code, _stdout, stderr) = run(ryl(repo)
+        .current_dir(repo)
+        .args(["--format", "parsable", "--since", "base"]));
+    assert_eq!(code, 1, "changed file has an error: {stderr}");
+    let lines: Vec<&str> = stderr.lines().collect();
+    assert_eq!(lines.len(), 1, "only the changed file is linted: {stderr}");
+    assert!(lines[0].starts_with("changed.yaml:1:5:"), "{stderr}");
+
+    let (code, _stdout, stderr) =
+        run(ryl(repo).current_dir(repo).args(["--since", "no-such-ref"]));
+    assert_eq!(code, 2, "an unknown ref is an error: {stderr}");
+    assert!(
+        stderr.contains("--since no-such-ref: git diff failed"),
+        "{stderr}"
+    );
+    assert!(
+        !stderr.contains("\\u{a}"),
+        "git's multi-line stderr is not shown as escaped newlines: {stderr}"
+    );
+    assert_eq!(stderr.lines().count(), 1, "{stderr}");
+}
+
+#[test]
+fn since_reports_missing_git() {
+    let dir = tempdir().unwrap();
+    let (code, _stdout, stderr) = run(ryl(dir.path())
+        .current_dir(dir.path())
+        .env("PATH", dir.path())
+        .args(["--since", "main"]));
+    assert_eq!(code, 2, "missing git is an error: {stderr}");
+    assert!(stderr.contains("--since requires git on PATH"), "{stderr}");
+}
+
+#[test]
+fn since_conflicts_with_path_inputs() {
+    let dir = tempdir().unwrap();
+    let (code, _stdout, stderr) =
+        run(ryl(dir.path()).args(["--since", "main"]).arg(dir.path()));
+    assert_eq!(code, 2, "--since replaces path inputs: {stderr}");
+}
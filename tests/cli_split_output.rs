@@ -0,0 +1,55 @@
+use std::fs;
+
+use tempfile::tempdir;
+
+mod common;
+use common::cli::{run, ryl};
+
+#[test]
+fn split_output_writes_one_report_per_input_in_each_format() {
+    let dir = tempdir().unwrap();
+    fs::create_dir(dir.path().join("sub")).unwrap();
+    fs::write(dir.path().join("sub/dirty.yaml"), "key:  value\n").unwrap();
+    fs::write(dir.path().join("clean.yaml"), "key: value\n").unwrap();
+    let reports = dir.path().join("reports");
+
+    let (code, out, err) = run(ryl(dir.path())
+        .current_dir(dir.path())
+        .args(["-d", "rules: {colons: enable}", "--format", "parsable"])
+        .args(["--format", "json", "-o", "all.json", "--split-output"])
+        .arg(&reports)
+        .args(["sub/dirty.yaml", "clean.yaml"]));
+    assert_eq!(code, 1, "{err}");
+    assert!(out.is_empty(), "{out}");
+    assert_eq!(
+        err,
+        "sub/dirty.yaml:1:6: [error] too many spaces after colon (colons)\n"
+    );
+
+    let report = |name: &str| fs::read_to_string(reports.join(name)).unwrap();
+    assert_eq!(report("sub%2Fdirty.yaml.parsable"), err);
+    assert_eq!(report("clean.yaml.parsable"), "");
+    let dirty: serde_json::Value =
+        serde_json::from_str(&report("sub%2Fdirty.yaml.json")).unwrap();
+    assert_eq!(dirty[0]["path"], "sub/dirty.yaml");
+    assert_eq!(dirty[0]["rule"], "colons");
+    assert_eq!(dirty.as_array().unwrap().len(), 1);
+    assert_eq!(report("clean.yaml.json").trim(), "[]");
+    assert_eq!(fs::read_dir(&reports).unwrap().count(), 4);
+}
+
+#[test]
+fn split_output_is_rejected_for_stdin() {
+    let dir = tempdir().unwrap();
+    let reports = dir.path().join("reports");
+    let (code, _out, err) = run(ryl(dir.path())
+        .args(["-d", "rules: {colons: enable}", "--split-output"])
+        .arg(&reports)
+        .arg("-"));
+    assert_eq!(code, 2, "{err}");
+    assert!(
+        err.contains("`--split-output` is not supported when reading from stdin"),
+        "{err}"
+    );
+    assert!(!reports.exists());
+}
@@ -339,14 +339,16 @@ fn stdin_strict_mode_treats_warnings_as_failure() {
 }
 
 #[test]
-fn stdin_invalid_utf8_reports_decode_error() {
+fn stdin_invalid_utf8_reports_a_diagnostic() {
     let exe = env!("CARGO_BIN_EXE_ryl");
     let (code, _stdout, stderr) =
         run_with_stdin(Command::new(exe).arg("-"), &[0xFF, 0xFF, 0xFF]);
     assert_eq!(code, 1, "expected lint failure: {stderr}");
     assert!(
-        stderr.contains("failed to read <stdin>"),
-        "expected decode error referencing stdin label: {stderr}"
+        stderr.contains("<stdin>")
+            && stderr.contains("1:1")
+            && stderr.contains("file is not valid UTF-8"),
+        "expected a diagnostic against the stdin label: {stderr}"
     );
 }
 
@@ -374,7 +376,7 @@ fn stdin_read_error_reports_lint_failure() {
 }
 
 #[test]
-fn stdin_filename_decode_error_uses_filename_in_message() {
+fn stdin_filename_invalid_utf8_diagnostic_uses_filename() {
     let exe = env!("CARGO_BIN_EXE_ryl");
     let (code, _stdout, stderr) = run_with_stdin(
         Command::new(exe)
@@ -385,8 +387,8 @@ fn stdin_filename_decode_error_uses_filename_in_message() {
     );
     assert_eq!(code, 1, "expected lint failure: {stderr}");
     assert!(
-        stderr.contains("failed to read buffer.yaml"),
-        "expected decode error to reference stdin-filename: {stderr}"
+        stderr.contains("buffer.yaml") && stderr.contains("file is not valid UTF-8"),
+        "expected the diagnostic to reference stdin-filename: {stderr}"
     );
 }
 
@@ -555,3 +557,40 @@ fn stdin_markdown_overlap_is_a_hard_error() {
     assert_eq!(code, 2, "overlap must be a usage error: {stderr}");
     assert!(stderr.contains("matches both"), "{stderr}");
 }
+
+#[test]
+fn stdin_label_and_filename_override_in_each_console_format() {
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    let cases = [
+        ("standard", "<stdin>\n  1:11      error    trailing spaces"),
+        ("parsable", "<stdin>:1:11: [error] trailing spaces"),
+        ("github", "::error file=<stdin>,line=1,col=11::"),
+    ];
+    for (format, expected) in cases {
+        let lint = |filename: Option<&str>| {
+            let mut cmd = Command::new(exe);
+            cmd.args([
+                "-",
+                "--format",
+                format,
+                "-d",
+                "rules: {trailing-spaces: enable}",
+            ]);
+            if let Some(name) = filename {
+                cmd.args(["--stdin-filename", name]);
+            }
+            let (code, stdout, stderr) = run_with_stdin(&mut cmd, b"key: value \n");
+            assert_eq!(code, 1, "{format}: an error fails the run: {stderr}");
+            assert!(stdout.is_empty(), "{format}: {stdout}");
+            stderr
+        };
+
+        let piped = lint(None);
+        assert!(piped.contains(expected), "{format}: {piped}");
+        let named = lint(Some("pkg/app.yaml"));
+        assert!(
+            named.contains(&expected.replace("<stdin>", "pkg/app.yaml")),
+            "{format}: {named}"
+        );
+    }
+}
@@ -148,6 +148,29 @@ fn skips_colons_inside_comments() {
     assert!(points.is_empty());
 }
 
+#[test]
+fn checks_colons_inside_flow_mappings() {
+    let cfg = Config::new_for_tests(0, 1);
+    let points = violation_points("a: {b : 1, c:   2}\n", cfg);
+    assert_eq!(
+        points,
+        vec![
+            (1, 6, "too many spaces before colon".to_string()),
+            (1, 16, "too many spaces after colon".to_string()),
+        ]
+    );
+}
+
+#[test]
+fn skips_colons_inside_block_scalars() {
+    let cfg = Config::new_for_tests(0, 1);
+    let points = violation_points("key: |\n  text  :   more\nnext: >\n  a :  b\n", cfg);
+    assert!(
+        points.is_empty(),
+        "block scalar content is not a mapping: {points:?}"
+    );
+}
+
 #[test]
 fn handles_crlf_after_colon() {
     let cfg = Config::new_for_tests(-1, 1);
@@ -379,3 +402,16 @@ fn columns_count_characters_not_bytes_with_multibyte_key() {
         ]
     );
 }
+
+#[test]
+fn colons_inside_urls_times_and_double_colons_are_not_mapping_colons() {
+    let cfg = Config::new_for_tests(0, 1);
+    let input = "url: http://example.com\ntime: \"12:30\"\nclock: 12:30:00\nns: a::b\n\
+                 flow: {url: http://x, t: '12:30'}\nspaced:  http://x:8080/a\n";
+    let points = violation_points(input, cfg);
+    assert_eq!(
+        points,
+        vec![(6, 9, "too many spaces after colon".to_string())],
+        "only the structural colon of `spaced` is judged"
+    );
+}
@@ -40,8 +40,10 @@ rules:
     )
     .expect_err("invalid value");
 
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.anchors"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"forbid-duplicated-anchors\" of \"anchors\" should be bool"
+    );
 }
 
 #[test]
@@ -61,8 +63,10 @@ rules:
     )
     .expect_err("unknown option");
 
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.anchors"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: unknown option \"unknown\" for rule \"anchors\""
+    );
 }
 
 #[test]
@@ -97,8 +101,10 @@ rules:
     )
     .expect_err("ryl-only option");
 
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.anchors"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: unknown option \"forbid-ambiguous-anchor-alias-names\" for rule \"anchors\""
+    );
 }
 
 #[test]
@@ -5,8 +5,10 @@ use ryl::rules::commas::Config;
 fn rejects_unknown_option() {
     let err = YamlLintConfig::from_yaml_str("rules:\n  commas:\n    unexpected: 1\n")
         .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.commas"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: unknown option \"unexpected\" for rule \"commas\""
+    );
 }
 
 #[test]
@@ -14,8 +16,10 @@ fn rejects_non_integer_max_spaces_before() {
     let err =
         YamlLintConfig::from_yaml_str("rules:\n  commas:\n    max-spaces-before: []\n")
             .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.commas"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"max-spaces-before\" of \"commas\" should be int"
+    );
 }
 
 #[test]
@@ -24,8 +28,10 @@ fn rejects_non_integer_min_spaces_after() {
         "rules:\n  commas:\n    min-spaces-after: true\n",
     )
     .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.commas"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"min-spaces-after\" of \"commas\" should be int"
+    );
 }
 
 #[test]
@@ -33,8 +39,10 @@ fn rejects_non_integer_max_spaces_after() {
     let err =
         YamlLintConfig::from_yaml_str("rules:\n  commas:\n    max-spaces-after: []\n")
             .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.commas"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"max-spaces-after\" of \"commas\" should be int"
+    );
 }
 
 #[test]
@@ -5,8 +5,10 @@ fn rejects_unknown_option() {
     let err =
         YamlLintConfig::from_yaml_str("rules:\n  comments:\n    unexpected: true\n")
             .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.comments"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: unknown option \"unexpected\" for rule \"comments\""
+    );
 }
 
 #[test]
@@ -15,8 +17,10 @@ fn rejects_non_bool_require_starting_space() {
         "rules:\n  comments:\n    require-starting-space: 1\n",
     )
     .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.comments"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"require-starting-space\" of \"comments\" should be bool"
+    );
 }
 
 #[test]
@@ -24,8 +28,10 @@ fn rejects_non_bool_ignore_shebangs() {
     let err =
         YamlLintConfig::from_yaml_str("rules:\n  comments:\n    ignore-shebangs: []\n")
             .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.comments"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"ignore-shebangs\" of \"comments\" should be bool"
+    );
 }
 
 #[test]
@@ -34,8 +40,10 @@ fn rejects_non_integer_min_spaces() {
         "rules:\n  comments:\n    min-spaces-from-content: true\n",
     )
     .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.comments"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"min-spaces-from-content\" of \"comments\" should be int"
+    );
 }
 
 #[test]
@@ -55,3 +63,29 @@ fn accepts_negative_min_spaces() {
     .expect("configuration should parse");
     assert!(cfg.rule_names().iter().any(|name| name == "comments"));
 }
+
+#[test]
+fn require_content_after_hash_rejected_in_yaml_config() {
+    let err = YamlLintConfig::from_yaml_str(
+        "rules:\n  comments:\n    require-content-after-hash: '^[a-z]'\n",
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        "invalid config: unknown option \"require-content-after-hash\" for rule \"comments\""
+    );
+}
+
+#[test]
+fn require_content_after_hash_rejects_invalid_regex() {
+    let err = YamlLintConfig::from_toml_str(
+        "[rules.comments]\nrequire-content-after-hash = \"(\"\n",
+    )
+    .unwrap_err();
+    assert!(
+        err.contains(
+            "invalid config: option \"require-content-after-hash\" of \"comments\" contains invalid regex '('"
+        ),
+        "{err}"
+    );
+}
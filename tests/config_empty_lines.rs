@@ -4,20 +4,26 @@ use ryl::config::YamlLintConfig;
 fn error_on_non_integer_limits() {
     let err = YamlLintConfig::from_yaml_str("rules:\n  empty-lines:\n    max: true\n")
         .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.empty-lines"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"max\" of \"empty-lines\" should be int"
+    );
 
     let err =
         YamlLintConfig::from_yaml_str("rules:\n  empty-lines:\n    max-start: false\n")
             .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.empty-lines"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"max-start\" of \"empty-lines\" should be int"
+    );
 
     let err =
         YamlLintConfig::from_yaml_str("rules:\n  empty-lines:\n    max-end: false\n")
             .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.empty-lines"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"max-end\" of \"empty-lines\" should be int"
+    );
 }
 
 #[test]
@@ -25,8 +31,10 @@ fn error_on_unknown_option() {
     let err =
         YamlLintConfig::from_yaml_str("rules:\n  empty-lines:\n    unexpected: 3\n")
             .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.empty-lines"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: unknown option \"unexpected\" for rule \"empty-lines\""
+    );
 }
 
 #[test]
@@ -6,8 +6,10 @@ fn error_on_non_bool_block_mapping_option() {
         "rules:\n  empty-values:\n    forbid-in-block-mappings: 1\n",
     )
     .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.empty-values"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"forbid-in-block-mappings\" of \"empty-values\" should be bool"
+    );
 }
 
 #[test]
@@ -16,8 +18,10 @@ fn error_on_unknown_option() {
         "rules:\n  empty-values:\n    unsupported: true\n",
     )
     .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.empty-values"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: unknown option \"unsupported\" for rule \"empty-values\""
+    );
 }
 
 #[test]
@@ -50,6 +50,13 @@ fn explicit_config_file_with_invalid_yaml_errors() {
         config_file: Some(file),
         config_data: None,
     };
-    let res = ryl::config::discover_config_with(&[], &overrides, &envx);
-    assert!(res.is_err());
+    let err = ryl::config::discover_config_with(&[], &overrides, &envx).unwrap_err();
+    assert!(
+        err.starts_with("cfg.yml: failed to parse config data: "),
+        "the error names the config file: {err}"
+    );
+    assert!(
+        err.contains("line 1 column"),
+        "and the parse position: {err}"
+    );
 }
@@ -121,7 +121,69 @@ fn cyclic_extends_is_rejected_without_overflowing_the_stack() {
         &env,
     )
     .expect_err("a cyclic extends chain must error, not overflow the stack");
-    assert!(err.contains("nested too deeply"), "{err}");
+    assert_eq!(
+        err,
+        "/workspace/a.yml: invalid config: cyclic extends detected at /workspace/a.yml \
+         (/workspace/a.yml -> /workspace/b.yml -> /workspace/a.yml)"
+    );
+}
+
+#[test]
+fn self_extends_through_a_dot_segment_is_a_cycle() {
+    let root = PathBuf::from("/workspace");
+    let a = root.join("a.yml");
+    let env = FakeEnv::new()
+        .with_cwd(root.clone())
+        .with_file(a.clone(), "extends: ./sub/../a.yml\n")
+        .with_exists(root.join("./sub/../a.yml"))
+        .with_file(root.join("./sub/../a.yml"), "extends: ./sub/../a.yml\n");
+
+    let err = discover_config_with(
+        &[],
+        &Overrides {
+            config_file: Some(a),
+            config_data: None,
+        },
+        &env,
+    )
+    .expect_err("a file extending itself must error");
+    assert_eq!(
+        err,
+        "/workspace/a.yml: invalid config: cyclic extends detected at /workspace/a.yml \
+         (/workspace/a.yml -> /workspace/a.yml)"
+    );
+}
+
+#[test]
+fn grandchild_disables_a_rule_the_builtin_enabled() {
+    let root = PathBuf::from("/workspace");
+    let child = root.join("child.yml");
+    let grandchild = root.join("grandchild.yml");
+    let env = FakeEnv::new()
+        .with_cwd(root.clone())
+        .with_file(
+            child.clone(),
+            "extends: default\nrules:\n  line-length: {max: 120}\n",
+        )
+        .with_file(
+            grandchild.clone(),
+            "extends: child.yml\nrules:\n  trailing-spaces: disable\n",
+        )
+        .with_exists(child)
+        .with_exists(grandchild.clone());
+
+    let ctx = discover_config_with(
+        &[],
+        &Overrides {
+            config_file: Some(grandchild),
+            config_data: None,
+        },
+        &env,
+    )
+    .expect("a three-level extends chain should resolve");
+    assert_eq!(ctx.config.rule_level("trailing-spaces"), None);
+    assert!(ctx.config.rule_level("colons").is_some());
+    assert!(ctx.config.rule_level("line-length").is_some());
 }
 
 #[test]
@@ -81,3 +81,38 @@ fn child_ignore_from_file_replaces_parent_patterns() {
         "parent ignores should be replaced by child ignore-from-file"
     );
 }
+
+#[test]
+fn overlay_reads_ignore_from_file_through_the_env() {
+    let root = PathBuf::from("/workspace");
+    let cfg = root.join("config.yml");
+    let env = FakeEnv::new()
+        .with_cwd(root.clone())
+        .with_file(cfg.clone(), "rules: {}\nignore: ['parent.yaml']\n")
+        .with_file(root.join("overlay.ignore"), "overlay.yaml\n");
+
+    let mut ctx = discover_config_with(
+        &[],
+        &Overrides {
+            config_file: Some(cfg),
+            config_data: None,
+        },
+        &env,
+    )
+    .expect("config should parse");
+    let base_dir = ctx.base_dir.clone();
+    ctx.config
+        .apply_overlay("ignore-from-file: overlay.ignore\n", &base_dir, &env)
+        .expect("the overlay's ignore file exists only in the fake env");
+
+    assert!(
+        ctx.config
+            .is_file_ignored(&base_dir.join("overlay.yaml"), &base_dir),
+        "overlay.yaml should respect the overlay's ignore-from-file"
+    );
+    assert!(
+        !ctx.config
+            .is_file_ignored(&base_dir.join("parent.yaml"), &base_dir),
+        "the overlay's ignore-from-file replaces the config's ignore"
+    );
+}
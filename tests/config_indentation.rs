@@ -1,12 +1,26 @@
 use ryl::config::YamlLintConfig;
 
+const SPACES_ERROR: &str = "invalid config: option \"spaces\" of \"indentation\" should be a positive integer or \"consistent\"";
+
+const INDENT_SEQUENCES_ERROR: &str = "invalid config: option \"indent-sequences\" of \"indentation\" should be in (true, false, \"whatever\", \"consistent\")";
+
 #[test]
 fn rejects_invalid_spaces_type() {
     let err =
         YamlLintConfig::from_yaml_str("rules:\n  indentation:\n    spaces: foo\n")
             .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.indentation"), "{err}");
+    assert_eq!(err, SPACES_ERROR);
+}
+
+#[test]
+fn rejects_non_positive_spaces() {
+    for spaces in ["0", "-2"] {
+        let err = YamlLintConfig::from_yaml_str(&format!(
+            "rules:\n  indentation:\n    spaces: {spaces}\n"
+        ))
+        .unwrap_err();
+        assert_eq!(err, SPACES_ERROR, "spaces: {spaces}");
+    }
 }
 
 #[test]
@@ -15,8 +29,30 @@ fn rejects_invalid_indent_sequences_type() {
         "rules:\n  indentation:\n    indent-sequences: 1\n",
     )
     .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.indentation"), "{err}");
+    assert_eq!(err, INDENT_SEQUENCES_ERROR);
+}
+
+#[test]
+fn rejects_unknown_indent_sequences_mode() {
+    let err = YamlLintConfig::from_yaml_str(
+        "rules:\n  indentation:\n    indent-sequences: maybe\n",
+    )
+    .unwrap_err();
+    assert_eq!(err, INDENT_SEQUENCES_ERROR);
+}
+
+#[test]
+fn toml_config_rejects_invalid_indentation_values() {
+    for (options, expected) in [
+        ("spaces = 0", SPACES_ERROR),
+        ("spaces = \"tabs\"", SPACES_ERROR),
+        ("indent-sequences = \"sometimes\"", INDENT_SEQUENCES_ERROR),
+    ] {
+        let err =
+            YamlLintConfig::from_toml_str(&format!("[rules.indentation]\n{options}\n"))
+                .unwrap_err();
+        assert_eq!(err, expected, "{options}");
+    }
 }
 
 #[test]
@@ -25,8 +61,10 @@ fn rejects_invalid_multiline_flag() {
         "rules:\n  indentation:\n    check-multi-line-strings: []\n",
     )
     .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.indentation"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"check-multi-line-strings\" of \"indentation\" should be bool"
+    );
 }
 
 #[test]
@@ -43,8 +81,10 @@ fn rejects_unknown_option_key() {
     let err =
         YamlLintConfig::from_yaml_str("rules:\n  indentation:\n    unexpected: true\n")
             .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.indentation"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: unknown option \"unexpected\" for rule \"indentation\""
+    );
 }
 
 #[test]
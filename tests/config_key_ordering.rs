@@ -20,8 +20,10 @@ fn ignored_keys_sequence_non_string_errors() {
         "rules:\n  key-ordering:\n    ignored-keys: [1]\n",
     )
     .expect_err("non-string sequence entries should error");
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.key-ordering"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"ignored-keys\" of \"key-ordering\" should be a list of str"
+    );
 }
 
 #[test]
@@ -39,8 +41,10 @@ fn ignored_keys_scalar_invalid_regex_errors() {
         "rules:\n  key-ordering:\n    ignored-keys: \"[\"\n",
     )
     .expect_err("invalid scalar regex should error");
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.key-ordering"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"ignored-keys\" of \"key-ordering\" should be a list of str"
+    );
 }
 
 #[test]
@@ -49,8 +53,10 @@ fn ignored_keys_invalid_type_errors() {
         "rules:\n  key-ordering:\n    ignored-keys: {bad: true}\n",
     )
     .expect_err("non sequence/string should error");
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.key-ordering"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"ignored-keys\" of \"key-ordering\" should be a list of str"
+    );
 }
 
 #[test]
@@ -59,8 +65,10 @@ fn key_ordering_unknown_option_errors() {
         "rules:\n  key-ordering:\n    unexpected: true\n",
     )
     .expect_err("unknown option should error");
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.key-ordering"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: unknown option \"unexpected\" for rule \"key-ordering\""
+    );
 }
 
 #[test]
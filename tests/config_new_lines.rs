@@ -14,24 +14,30 @@ fn discover_with_yaml(yaml: &str) -> Result<(), String> {
 #[test]
 fn unknown_option_errors() {
     let err = discover_with_yaml("rules:\n  new-lines:\n    foo: bar\n").unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.new-lines"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: unknown option \"foo\" for rule \"new-lines\""
+    );
 }
 
 #[test]
 fn invalid_type_value_errors() {
     let err =
         discover_with_yaml("rules:\n  new-lines:\n    type: invalid\n").unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.new-lines"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"type\" of \"new-lines\" should be in (\"unix\", \"dos\", \"platform\")"
+    );
 }
 
 #[test]
 fn invalid_type_kind_errors_on_non_string() {
     let err =
         discover_with_yaml("rules:\n  new-lines:\n    type: [unix]\n").unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.new-lines"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"type\" of \"new-lines\" should be in (\"unix\", \"dos\", \"platform\")"
+    );
 }
 
 #[test]
@@ -7,8 +7,10 @@ fn error_when_quote_type_invalid() {
         "rules:\n  quoted-strings:\n    quote-type: bad\n",
     )
     .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.quoted-strings"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"quote-type\" of \"quoted-strings\" should be in (\"any\", \"single\", \"double\", \"consistent\")"
+    );
 }
 
 #[test]
@@ -24,8 +26,10 @@ fn error_when_quote_type_not_string() {
     let err =
         YamlLintConfig::from_yaml_str("rules:\n  quoted-strings:\n    quote-type: 1\n")
             .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.quoted-strings"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"quote-type\" of \"quoted-strings\" should be in (\"any\", \"single\", \"double\", \"consistent\")"
+    );
 }
 
 #[test]
@@ -33,8 +37,10 @@ fn error_when_required_invalid() {
     let err =
         YamlLintConfig::from_yaml_str("rules:\n  quoted-strings:\n    required: 3\n")
             .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.quoted-strings"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"required\" of \"quoted-strings\" should be in (true, false, \"only-when-needed\")"
+    );
 }
 
 #[test]
@@ -52,8 +58,10 @@ fn error_when_extra_required_not_sequence() {
         "rules:\n  quoted-strings:\n    extra-required: foo\n",
     )
     .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.quoted-strings"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"extra-required\" of \"quoted-strings\" should be a list of str"
+    );
 }
 
 #[test]
@@ -62,8 +70,10 @@ fn error_when_extra_required_contains_non_string() {
         "rules:\n  quoted-strings:\n    extra-required: [1]\n",
     )
     .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.quoted-strings"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"extra-required\" of \"quoted-strings\" should be a list of str"
+    );
 }
 
 #[test]
@@ -72,8 +82,10 @@ fn error_when_extra_allowed_contains_non_string() {
         "rules:\n  quoted-strings:\n    extra-allowed: [true]\n",
     )
     .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.quoted-strings"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"extra-allowed\" of \"quoted-strings\" should be a list of str"
+    );
 }
 
 #[test]
@@ -82,8 +94,10 @@ fn error_when_allow_quoted_quotes_not_bool() {
         "rules:\n  quoted-strings:\n    allow-quoted-quotes: 1\n",
     )
     .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.quoted-strings"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"allow-quoted-quotes\" of \"quoted-strings\" should be bool"
+    );
 }
 
 #[test]
@@ -92,8 +106,10 @@ fn error_when_allow_double_quotes_for_escaping_is_in_yaml_config() {
         "rules:\n  quoted-strings:\n    allow-double-quotes-for-escaping: true\n",
     )
     .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.quoted-strings"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: unknown option \"allow-double-quotes-for-escaping\" for rule \"quoted-strings\""
+    );
 }
 
 #[test]
@@ -101,8 +117,10 @@ fn error_when_check_keys_not_bool() {
     let err =
         YamlLintConfig::from_yaml_str("rules:\n  quoted-strings:\n    check-keys: 2\n")
             .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.quoted-strings"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"check-keys\" of \"quoted-strings\" should be bool"
+    );
 }
 
 #[test]
@@ -179,8 +197,10 @@ fn error_when_unknown_quoted_strings_option() {
         "rules:\n  quoted-strings:\n    unknown: value\n",
     )
     .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.quoted-strings"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: unknown option \"unknown\" for rule \"quoted-strings\""
+    );
 }
 
 #[test]
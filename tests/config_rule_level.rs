@@ -1,6 +1,7 @@
 use std::fs;
 
 use ryl::config::{Overrides, RuleLevel, YamlLintConfig, discover_config};
+use ryl::lint::Severity;
 use tempfile::tempdir;
 
 #[test]
@@ -32,6 +33,23 @@ fn unknown_rule_name_is_rejected() {
     assert!(err.contains("no such rule: \"tariling-spaces\""), "{err}");
 }
 
+#[test]
+fn parsing_config_text_rejects_a_misspelled_rule() {
+    let err = YamlLintConfig::from_yaml_str("rules:\n  trailing-space: enable\n")
+        .expect_err("a misspelled rule must not parse");
+    assert_eq!(err, "invalid config: no such rule: \"trailing-space\"");
+    let err = YamlLintConfig::from_toml_str("[rules]\ntrailing-space = \"enable\"\n")
+        .expect_err("a misspelled rule must not parse");
+    assert_eq!(err, "invalid config: no such rule: \"trailing-space\"");
+
+    let cfg = YamlLintConfig::from_yaml_str("rules:\n  trailing-spaces: enable\n")
+        .expect("a known rule parses");
+    assert_eq!(cfg.rule_names(), ["trailing-spaces"]);
+    let cfg = YamlLintConfig::from_toml_str("[rules]\ntrailing-spaces = \"enable\"\n")
+        .expect("a known rule parses");
+    assert_eq!(cfg.rule_names(), ["trailing-spaces"]);
+}
+
 #[test]
 fn unknown_rule_with_float_and_datetime_options_is_rejected() {
     // An unknown rule's option values still flow through TOML->YAML scalar
@@ -101,6 +119,31 @@ rules:
     );
 }
 
+#[test]
+fn level_alongside_options_downgrades_and_configures_the_rule() {
+    let long = format!("key: {}\n", "x".repeat(100));
+    let longer = format!("key: {}\n", "x".repeat(130));
+    let content = format!("{long}{longer}");
+    for cfg in [
+        YamlLintConfig::from_yaml_str(
+            "rules:\n  line-length:\n    level: warning\n    max: 120\n",
+        ),
+        YamlLintConfig::from_toml_str(
+            "[rules.line-length]\nlevel = \"warning\"\nmax = 120\n",
+        ),
+    ] {
+        let cfg = cfg.expect("config should parse");
+        assert_eq!(cfg.rule_level("line-length"), Some(RuleLevel::Warning));
+
+        let problems = ryl::lint_source(&content, &cfg);
+        assert_eq!(problems.len(), 1, "{problems:?}");
+        let problem = &problems[0];
+        assert_eq!((problem.line, problem.column), (2, 121));
+        assert_eq!(problem.level, Severity::Warning);
+        assert_eq!(problem.message, "line too long (135 > 120 characters)");
+    }
+}
+
 #[test]
 fn rule_level_defaults_to_error_for_enable() {
     let cfg = r#"
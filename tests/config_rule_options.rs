@@ -0,0 +1,135 @@
+use ryl::config::YamlLintConfig;
+
+fn yaml_error(rule: &str, options: &str) -> String {
+    YamlLintConfig::from_yaml_str(&format!("rules:\n  {rule}: {{{options}}}\n"))
+        .expect_err("config should be rejected")
+}
+
+/// Every rule YAML config accepts: the built-ins minus the ryl-only ones (TOML only).
+fn yaml_rules() -> impl Iterator<Item = &'static str> {
+    ryl::rules::ALL_RULE_IDS
+        .into_iter()
+        .filter(|rule| !ryl::rules::RYL_ONLY_RULE_IDS.contains(rule))
+}
+
+/// Guards the hand-maintained option table: a rule missing from it would fall through to
+/// typed parsing, whose error names no option.
+#[test]
+fn every_rule_rejects_an_unknown_option_by_name() {
+    for rule in yaml_rules() {
+        assert_eq!(
+            yaml_error(rule, "level: warning, max-spaces: 1"),
+            format!(
+                "invalid config: unknown option \"max-spaces\" for rule \"{rule}\""
+            )
+        );
+    }
+}
+
+/// The reverse guard: every option the typed YAML options struct declares (read from the
+/// generated schema) is one the option table knows, so none is rejected as unknown.
+#[test]
+fn every_typed_option_is_in_the_option_table() {
+    let schema = ryl::config_schema::yaml_schema_value();
+    let defs = &schema["$defs"];
+    let resolve = |value: &serde_json::Value| -> serde_json::Value {
+        let name = value["$ref"]
+            .as_str()
+            .unwrap()
+            .trim_start_matches("#/$defs/");
+        defs[name].clone()
+    };
+    for rule in yaml_rules() {
+        let entry = resolve(&defs["RulesTable"]["properties"][rule]["anyOf"][0]);
+        let options = entry["anyOf"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter(|variant| variant.get("$ref").is_some())
+            .map(resolve)
+            .find(|variant| variant["type"] == "object")
+            .unwrap_or_else(|| panic!("{rule} has an options object in the schema"));
+        for option in options["properties"].as_object().unwrap().keys() {
+            let result = YamlLintConfig::from_yaml_str(&format!(
+                "rules:\n  {rule}: {{{option}: []}}\n"
+            ));
+            if let Err(err) = result {
+                assert!(
+                    !err.contains("unknown option"),
+                    "`{option}` of `{rule}` is typed but not in the option table: {err}"
+                );
+            }
+        }
+    }
+}
+
+#[test]
+fn every_rule_names_the_expected_type_of_a_mismatched_option() {
+    for (rule, options, expected) in [
+        ("anchors", "forbid-unused-anchors: 1", "should be bool"),
+        ("braces", "max-spaces-inside: \"1\"", "should be int"),
+        (
+            "brackets",
+            "forbid: empty",
+            "should be in (true, false, \"non-empty\")",
+        ),
+        ("colons", "max-spaces-after: {}", "should be int"),
+        ("commas", "min-spaces-after: true", "should be int"),
+        ("comments", "ignore-shebangs: yes please", "should be bool"),
+        ("document-end", "present: 1", "should be bool"),
+        ("document-start", "present: []", "should be bool"),
+        ("empty-lines", "max-end: \"2\"", "should be int"),
+        (
+            "empty-values",
+            "forbid-in-flow-mappings: 0",
+            "should be bool",
+        ),
+        ("float-values", "forbid-nan: \"true\"", "should be bool"),
+        ("hyphens", "max-spaces-after: []", "should be int"),
+        (
+            "indentation",
+            "spaces: -2",
+            "should be a positive integer or \"consistent\"",
+        ),
+        (
+            "key-duplicates",
+            "forbid-duplicated-merge-keys: {}",
+            "should be bool",
+        ),
+        (
+            "key-ordering",
+            "ignored-keys: [a, 1]",
+            "should be a list of str",
+        ),
+        ("line-length", "max: eighty", "should be int"),
+        (
+            "new-lines",
+            "type: mac",
+            "should be in (\"unix\", \"dos\", \"platform\")",
+        ),
+        ("octal-values", "forbid-explicit-octal: 1", "should be bool"),
+        (
+            "quoted-strings",
+            "quote-type: backtick",
+            "should be in (\"any\", \"single\", \"double\", \"consistent\")",
+        ),
+        ("truthy", "check-keys: \"no\"", "should be bool"),
+    ] {
+        let option = options.split(':').next().unwrap();
+        assert_eq!(
+            yaml_error(rule, options),
+            format!("invalid config: option \"{option}\" of \"{rule}\" {expected}")
+        );
+    }
+}
+
+#[test]
+fn well_typed_options_and_common_keys_still_parse() {
+    YamlLintConfig::from_yaml_str(
+        "rules:\n  brackets: {forbid: non-empty, level: warning, ignore: [a.yaml]}\n  \
+         indentation: {spaces: consistent, indent-sequences: whatever}\n  \
+         truthy: {allowed-values: [\"yes\", \"no\"], ignore-from-file: .gitignore}\n  \
+         key-ordering: {ignored-keys: [\"^x\"]}\n",
+    )
+    .expect("config should parse");
+}
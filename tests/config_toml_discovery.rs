@@ -393,7 +393,7 @@ fn toml_ignore_and_ignore_from_file_conflict_errors() {
 
     assert_eq!(
         err,
-        "invalid config: ignore and ignore-from-file keys cannot be used together"
+        "/repo/.ryl.toml: invalid config: ignore and ignore-from-file keys cannot be used together"
     );
 }
 
@@ -416,7 +416,7 @@ fn toml_quoted_strings_conflict_errors() {
 
     assert_eq!(
         err,
-        "invalid config: quoted-strings: cannot use both \"required: true\" and \"extra-required\""
+        "/repo/.ryl.toml: invalid config: quoted-strings: cannot use both \"required: true\" and \"extra-required\""
     );
 }
 
@@ -1,12 +1,13 @@
 use ryl::config::YamlLintConfig;
 
+const ALLOWED_VALUES_ERROR: &str = "invalid config: option \"allowed-values\" of \"truthy\" should only contain values in (\"YES\", \"Yes\", \"yes\", \"NO\", \"No\", \"no\", \"TRUE\", \"True\", \"true\", \"FALSE\", \"False\", \"false\", \"ON\", \"On\", \"on\", \"OFF\", \"Off\", \"off\")";
+
 #[test]
 fn error_when_allowed_values_not_sequence() {
     let err =
         YamlLintConfig::from_yaml_str("rules:\n  truthy:\n    allowed-values: foo\n")
             .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.truthy"), "{err}");
+    assert_eq!(err, ALLOWED_VALUES_ERROR);
 }
 
 #[test]
@@ -14,8 +15,7 @@ fn error_when_allowed_values_has_invalid_entry() {
     let err =
         YamlLintConfig::from_yaml_str("rules:\n  truthy:\n    allowed-values: [foo]\n")
             .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.truthy"), "{err}");
+    assert_eq!(err, ALLOWED_VALUES_ERROR);
 }
 
 #[test]
@@ -23,24 +23,27 @@ fn error_when_allowed_values_contains_non_string() {
     let err =
         YamlLintConfig::from_yaml_str("rules:\n  truthy:\n    allowed-values: [1]\n")
             .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.truthy"), "{err}");
+    assert_eq!(err, ALLOWED_VALUES_ERROR);
 }
 
 #[test]
 fn error_when_check_keys_not_bool() {
     let err = YamlLintConfig::from_yaml_str("rules:\n  truthy:\n    check-keys: 1\n")
         .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.truthy"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: option \"check-keys\" of \"truthy\" should be bool"
+    );
 }
 
 #[test]
 fn error_on_unknown_truthy_option() {
     let err = YamlLintConfig::from_yaml_str("rules:\n  truthy:\n    unknown: true\n")
         .unwrap_err();
-    assert!(err.contains("failed to parse config data:"), "{err}");
-    assert!(err.contains("rules.truthy"), "{err}");
+    assert_eq!(
+        err,
+        "invalid config: unknown option \"unknown\" for rule \"truthy\""
+    );
 }
 
 #[test]
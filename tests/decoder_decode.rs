@@ -336,3 +336,48 @@ fn read_file_missing_file_errors() {
     let err = decoder::read_file(Path::new("no_such_decoder_file.yml")).unwrap_err();
     assert!(err.contains("failed to read"));
 }
+
+#[test]
+fn read_file_matches_decode_bytes_for_utf8_with_and_without_bom() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("bom.yml");
+    for data in [
+        &b"\xEF\xBB\xBFkey: \xC3\xA9\n"[..],
+        b"key: \xC3\xA9\n",
+        b"\xEF\xBB\xBFkey: \xFF\n",
+        b"\xEF\xBB\xBF",
+    ] {
+        std::fs::write(&path, data).unwrap();
+        let read = decoder::read_file(&path).map_err(|err| {
+            err.replace(&format!("failed to read {}: ", path.display()), "")
+        });
+        assert_eq!(read, decoder::decode_bytes(data), "{data:?}");
+    }
+}
+
+#[test]
+fn read_file_large_generated_yaml_lints_like_decode_bytes() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("generated.yaml");
+    let mut yaml = String::from("---\nitems:\n");
+    for i in 0..50_000 {
+        let trailing = if i % 1000 == 0 { " " } else { "" };
+        yaml.push_str(&format!(
+            "  - name: item-{i}{trailing}\n    value: \"é {i}\"\n"
+        ));
+    }
+    assert!(yaml.len() > 2 * 1024 * 1024, "{}", yaml.len());
+    std::fs::write(&path, &yaml).unwrap();
+
+    let cfg = ryl::config::YamlLintConfig::from_yaml_str(
+        "rules: {trailing-spaces: enable, quoted-strings: enable, line-length: enable}\n",
+    )
+    .unwrap();
+    let base = dir.path();
+    let from_file = ryl::lint_file(&path, &cfg, base).unwrap();
+    let bytes = std::fs::read(&path).unwrap();
+    let from_str =
+        ryl::lint_str(&decoder::decode_bytes(&bytes).unwrap(), &path, &cfg, base);
+    assert_eq!(from_file.len(), 50_050, "{:?}", &from_file[..3]);
+    assert_eq!(from_file, from_str);
+}
@@ -54,6 +54,35 @@ fn inline_disable_line_suppresses_each_rule_for_both_spellings() {
     }
 }
 
+#[test]
+fn inline_disable_line_suppresses_whitespace_and_value_rules() {
+    // (rule, trigger line, the same line carrying the directive as `{}`): trailing-spaces
+    // needs its trailing blank after the directive, so the lines are spelled out whole.
+    let cases = [
+        ("trailing-spaces", "a: 1 ", "a: 1  # {} "),
+        ("octal-values", "a: 010", "a: 010  # {}"),
+        ("key-duplicates", "{a: 1, a: 2}", "{a: 1, a: 2}  # {}"),
+        ("empty-values", "a:", "a:  # {}"),
+        ("hyphens", "-  a", "-  a  # {}"),
+        ("quoted-strings", "a: b", "a: b  # {}"),
+    ];
+    for (rule, trigger, disabled) in cases {
+        let config = cfg(&format!("rules:\n  {rule}: enable\n"));
+        assert!(
+            !rule_lines(&format!("{trigger}\n"), &config).is_empty(),
+            "control: {rule} should fire on its trigger line"
+        );
+        for keyword in ["ryl", "yamllint"] {
+            let directive = format!("{keyword} disable-line rule:{rule}");
+            let line = format!("{}\n", disabled.replace("{}", &directive));
+            assert!(
+                rule_lines(&line, &config).is_empty(),
+                "{line:?} should suppress {rule}"
+            );
+        }
+    }
+}
+
 #[test]
 fn own_line_disable_line_targets_the_next_line_only() {
     let config = cfg(COLONS);
@@ -118,6 +147,25 @@ fn disable_then_enable_specific_rule_leaves_others_disabled() {
     );
 }
 
+#[test]
+fn overlapping_rule_blocks_close_independently_via_lint_source() {
+    let config = cfg("rules:\n  colons: enable\n  truthy: enable\n");
+    let input = "# yamllint disable rule:colons\na:  yes\n\
+        # yamllint disable rule:truthy\nb:  no\n\
+        # yamllint enable rule:colons\nc:  yes\n\
+        # yamllint enable\nd:  no\n";
+    let mut fired: Vec<(usize, &str)> = ryl::lint_source(input, &config)
+        .into_iter()
+        .filter_map(|problem| problem.rule.map(|rule| (problem.line, rule)))
+        .collect();
+    fired.sort_unstable();
+    assert_eq!(
+        fired,
+        vec![(2, "truthy"), (6, "colons"), (8, "colons"), (8, "truthy"),],
+        "each rule is muted from its own disable until its own (or a bare) enable"
+    );
+}
+
 #[test]
 fn multiple_rule_tokens_disable_each_listed_rule() {
     let config = cfg("rules:\n  colons: enable\n  truthy: enable\n");
@@ -41,13 +41,12 @@ fn run_config_suite<C>(suite: ConfigSuite<C>) {
         suite.rule_name
     ))
     .expect_err("config should fail");
-    assert!(
-        err.contains("failed to parse config data:"),
-        "unexpected error: {err}"
-    );
-    assert!(
-        err.contains(&format!("rules.{}", suite.rule_name)),
-        "unexpected error: {err}"
+    assert_eq!(
+        err,
+        format!(
+            "invalid config: option \"forbid\" of \"{}\" should be in (true, false, \"non-empty\")",
+            suite.rule_name
+        )
     );
 
     for option in [
@@ -61,13 +60,12 @@ fn run_config_suite<C>(suite: ConfigSuite<C>) {
             suite.rule_name, option
         ))
         .expect_err("config should fail");
-        assert!(
-            err.contains("failed to parse config data:"),
-            "unexpected error: {err}"
-        );
-        assert!(
-            err.contains(&format!("rules.{}", suite.rule_name)),
-            "unexpected error: {err}"
+        assert_eq!(
+            err,
+            format!(
+                "invalid config: option \"{option}\" of \"{}\" should be int",
+                suite.rule_name
+            )
         );
     }
 
@@ -76,13 +74,12 @@ fn run_config_suite<C>(suite: ConfigSuite<C>) {
         suite.rule_name
     ))
     .expect_err("config should fail");
-    assert!(
-        err.contains("failed to parse config data:"),
-        "unexpected error: {err}"
-    );
-    assert!(
-        err.contains(&format!("rules.{}", suite.rule_name)),
-        "unexpected error: {err}"
+    assert_eq!(
+        err,
+        format!(
+            "invalid config: unknown option \"unexpected-option\" for rule \"{}\"",
+            suite.rule_name
+        )
     );
 
     let err = YamlLintConfig::from_yaml_str(&format!(
@@ -285,6 +285,111 @@ fn brackets_rule_suite() {
     );
 }
 
+#[test]
+fn brackets_count_each_tab_as_one_space() {
+    let max_inside = BracketsConfig::new_for_tests(Forbid::None, 0, 1, -1, -1);
+    assert_clean(&max_inside, "object: [\t1, 2\t]\n", brackets::check);
+    assert_hits(
+        &max_inside,
+        "object: [ \t1, 2\t ]\n",
+        brackets::check,
+        vec![
+            BracketsViolation {
+                line: 1,
+                column: 11,
+                message: "too many spaces inside brackets".to_string(),
+            },
+            BracketsViolation {
+                line: 1,
+                column: 17,
+                message: "too many spaces inside brackets".to_string(),
+            },
+        ],
+    );
+
+    let min_inside = BracketsConfig::new_for_tests(Forbid::None, 2, -1, -1, -1);
+    assert_clean(&min_inside, "object: [\t 1, 2 \t]\n", brackets::check);
+
+    let empty_spacing = BracketsConfig::new_for_tests(Forbid::None, 0, 0, 1, 1);
+    assert_clean(&empty_spacing, "object: [\t]\n", brackets::check);
+    assert_hits(
+        &empty_spacing,
+        "object: [\t\t]\n",
+        brackets::check,
+        vec![BracketsViolation {
+            line: 1,
+            column: 11,
+            message: "too many spaces inside empty brackets".to_string(),
+        }],
+    );
+}
+
+#[test]
+fn brackets_forbid_reports_every_nested_sequence_outer_first() {
+    // Like yamllint, each diagnostic points just past its `[` (the token's end column).
+    let forbidden = |column| BracketsViolation {
+        line: 1,
+        column,
+        message: "forbidden flow sequence".to_string(),
+    };
+
+    let forbid_all = BracketsConfig::new_for_tests(Forbid::All, 0, 0, -1, -1);
+    assert_hits(
+        &forbid_all,
+        "a: [[1], [2, [3]]]\n",
+        brackets::check,
+        vec![forbidden(5), forbidden(6), forbidden(11), forbidden(15)],
+    );
+
+    // `non-empty` still exempts a nested `[]`, while its non-empty parent is reported.
+    let forbid_non_empty =
+        BracketsConfig::new_for_tests(Forbid::NonEmpty, 0, 0, -1, -1);
+    assert_hits(
+        &forbid_non_empty,
+        "a: [[], [1]]\n",
+        brackets::check,
+        vec![forbidden(5), forbidden(10)],
+    );
+}
+
+#[test]
+fn brackets_forbid_non_empty_treats_a_sequence_empty_across_lines_as_empty() {
+    let forbid_non_empty =
+        BracketsConfig::new_for_tests(Forbid::NonEmpty, 0, 0, -1, -1);
+    for empty in [
+        "a: [\n]\n",
+        "a: [\n\n  ]\n",
+        "a: [\r\n]\n",
+        "a: [  # nothing here\n]\n",
+    ] {
+        assert_clean(&forbid_non_empty, empty, brackets::check);
+    }
+    for (non_empty, line, column) in [
+        ("a: [1]\n", 1, 5),
+        ("a: [\n  1\n]\n", 1, 5),
+        ("a: [\n\n  # note\n  b,\n]\n", 1, 5),
+        ("a:\n  - [\n    []\n  ]\n", 2, 6),
+    ] {
+        assert_hits(
+            &forbid_non_empty,
+            non_empty,
+            brackets::check,
+            vec![BracketsViolation {
+                line,
+                column,
+                message: "forbidden flow sequence".to_string(),
+            }],
+        );
+    }
+}
+
+#[test]
+fn brackets_fix_replaces_tab_spacing() {
+    let cfg = BracketsConfig::new_for_tests(Forbid::None, 0, 0, -1, -1);
+    let fixed = brackets::fix("object: [\t1, 2 \t]\nempty: [\t]\n", &cfg);
+    assert_eq!(fixed, Some("object: [1, 2]\nempty: []\n".to_string()));
+}
+
 #[test]
 fn braces_fix_normalizes_spacing() {
     let cfg = BracesConfig::new_for_tests(Forbid::None, 0, 0, 1, 2);
@@ -0,0 +1,100 @@
+use std::fs;
+use std::path::Path;
+
+use ryl::config::YamlLintConfig;
+use ryl::{LintProblem, Severity, lint_file, lint_source};
+use tempfile::tempdir;
+
+fn cfg(yaml: &str) -> YamlLintConfig {
+    YamlLintConfig::from_yaml_str(yaml).expect("config parses")
+}
+
+#[test]
+fn lint_source_returns_problems_for_an_in_memory_buffer() {
+    let config = cfg("rules:\n  trailing-spaces: enable\n  colons: {level: warning}\n");
+    let mut problems = lint_source("key: value \nb:  1\n", &config);
+    problems.sort_by_key(|problem| (problem.line, problem.column));
+    assert_eq!(
+        problems,
+        vec![
+            LintProblem {
+                line: 1,
+                column: 11,
+                level: Severity::Error,
+                message: "trailing spaces".to_string(),
+                rule: Some("trailing-spaces"),
+            },
+            LintProblem {
+                line: 2,
+                column: 4,
+                level: Severity::Warning,
+                message: "too many spaces after colon".to_string(),
+                rule: Some("colons"),
+            },
+        ]
+    );
+}
+
+#[test]
+fn lint_source_reports_syntax_errors_without_a_rule() {
+    let problems = lint_source("a: [1\n", &cfg("extends: default\n"));
+    assert_eq!(problems.len(), 1, "{problems:?}");
+    assert_eq!(problems[0].rule, None);
+    assert_eq!(problems[0].level, Severity::Error);
+}
+
+#[test]
+fn lint_source_matches_lint_file_on_the_same_content() {
+    let td = tempdir().unwrap();
+    let path = td.path().join("doc.yaml");
+    let content = "key: value \nlist: [ 1,2]\nempty:\n";
+    fs::write(&path, content).unwrap();
+    let config = cfg("extends: default\n");
+
+    let from_file = lint_file(&path, &config, Path::new(".")).expect("file reads");
+    assert!(!from_file.is_empty());
+    assert_eq!(lint_source(content, &config), from_file);
+}
+
+#[test]
+fn lint_source_ignores_a_leading_bom_in_columns_and_directives() {
+    let config = cfg(
+        "rules:\n  document-start: enable\n  trailing-spaces: enable\n  colons: enable\n",
+    );
+    let plain = "key: value \nb:  1\n";
+    let mut expected = lint_source(plain, &config);
+    expected.sort_by_key(|problem| (problem.line, problem.column));
+    assert_eq!(
+        expected
+            .iter()
+            .map(|problem| (problem.line, problem.column, problem.rule))
+            .collect::<Vec<_>>(),
+        [
+            (1, 1, Some("document-start")),
+            (1, 11, Some("trailing-spaces")),
+            (2, 4, Some("colons")),
+        ]
+    );
+    let mut with_bom = lint_source(&format!("\u{feff}{plain}"), &config);
+    with_bom.sort_by_key(|problem| (problem.line, problem.column));
+    assert_eq!(with_bom, expected);
+
+    let disabled = format!("\u{feff}# yamllint disable-file\n{plain}");
+    assert!(lint_source(&disabled, &config).is_empty());
+}
+
+#[test]
+fn lint_file_reports_bom_prefixed_files_at_unshifted_columns() {
+    let dir = tempdir().unwrap();
+    let path = dir.path().join("bom.yaml");
+    fs::write(&path, "\u{feff}key: value \n").unwrap();
+    let config = cfg("rules:\n  document-start: enable\n  trailing-spaces: enable\n");
+    let problems = lint_file(&path, &config, dir.path()).unwrap();
+    assert_eq!(
+        problems
+            .iter()
+            .map(|problem| (problem.line, problem.column))
+            .collect::<Vec<_>>(),
+        [(1, 1), (1, 11)]
+    );
+}
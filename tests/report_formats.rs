@@ -1,4 +1,5 @@
-//! In-process validation of the JUnit XML and GitLab JSON report emitters
+//! In-process validation of the JUnit XML, GitLab JSON, Code Climate JSON, SARIF, PR
+//! review, and flat JSON report emitters
 //! (`ryl::report`). GitLab output is checked against the vendored schema
 //! (`tests/fixtures/gitlab-code-quality.schema.json`, the published GitLab contract);
 //! JUnit output is re-parsed with quick-xml so structure and escaping are verified without
@@ -11,7 +12,10 @@ use jsonschema::validator_for;
 use quick_xml::Reader;
 use quick_xml::escape::unescape;
 use quick_xml::events::Event;
-use ryl::report::{ReportEntry, render_gitlab, render_junit};
+use ryl::report::{
+    ReportEntry, render_codeclimate, render_gitlab, render_json, render_junit,
+    render_review, render_sarif,
+};
 use ryl::{LintProblem, Severity};
 use serde_json::Value;
 
@@ -36,6 +40,15 @@ fn gitlab_json(entries: &[ReportEntry]) -> Value {
         .expect("gitlab output is valid JSON")
 }
 
+fn codeclimate_json(entries: &[ReportEntry]) -> Value {
+    serde_json::from_slice(&render_codeclimate(entries))
+        .expect("codeclimate output is valid JSON")
+}
+
+fn sarif_json(entries: &[ReportEntry]) -> Value {
+    serde_json::from_slice(&render_sarif(entries)).expect("sarif output is valid JSON")
+}
+
 fn junit_xml(entries: &[ReportEntry]) -> String {
     String::from_utf8(render_junit(entries)).expect("junit output is UTF-8")
 }
@@ -396,3 +409,283 @@ fn gitlab_message_cannot_inject_json_structure() {
         "control characters are stripped from the description: {description:?}"
     );
 }
+
+#[test]
+fn codeclimate_issues_carry_type_categories_and_content_body() {
+    let entries = vec![
+        ReportEntry {
+            path: "sub/dirty.yaml".to_string(),
+            problems: vec![
+                problem(3, 5, Severity::Error, Some("commas"), "too many spaces"),
+                problem(4, 1, Severity::Warning, Some("no-todo"), "TODO left"),
+                problem(5, 2, Severity::Error, None, "syntax error: boom"),
+            ],
+            error: None,
+        },
+        ReportEntry {
+            path: "broken.yaml".to_string(),
+            problems: Vec::new(),
+            error: Some("failed to read broken.yaml".to_string()),
+        },
+    ];
+
+    let json = codeclimate_json(&entries);
+    let issues = json.as_array().expect("top level is an array");
+    assert_eq!(
+        issues.len(),
+        4,
+        "three diagnostics plus one processing error"
+    );
+    for issue in issues {
+        assert_eq!(issue["type"], "issue", "{issue}");
+        assert!(issue["fingerprint"].is_string(), "{issue}");
+        assert!(
+            !issue["content"]["body"].as_str().unwrap().is_empty(),
+            "every issue explains itself: {issue}"
+        );
+    }
+
+    let commas = issues[0]["content"]["body"].as_str().unwrap();
+    assert!(
+        commas.contains("Spaces around flow collection commas."),
+        "{commas}"
+    );
+    assert!(
+        commas.contains("https://ryl-docs.pages.dev/rules/commas/"),
+        "{commas}"
+    );
+    assert!(
+        issues[1]["content"]["body"]
+            .as_str()
+            .unwrap()
+            .contains("--rulesdir"),
+        "a non-built-in rule gets the pattern-rule body: {}",
+        issues[1]
+    );
+
+    let categories: Vec<&str> = issues
+        .iter()
+        .map(|issue| issue["categories"][0].as_str().unwrap())
+        .collect();
+    assert_eq!(categories, ["Style", "Style", "Bug Risk", "Bug Risk"]);
+    let severities: Vec<&str> = issues
+        .iter()
+        .map(|issue| issue["severity"].as_str().unwrap())
+        .collect();
+    assert_eq!(severities, ["major", "minor", "major", "blocker"]);
+}
+
+#[test]
+fn codeclimate_fingerprints_are_stable_and_match_gitlab() {
+    let at = |line| {
+        vec![ReportEntry {
+            path: "a.yaml".to_string(),
+            problems: vec![
+                problem(line, 1, Severity::Error, Some("commas"), "too many spaces"),
+                problem(line, 1, Severity::Error, Some("commas"), "too many spaces"),
+            ],
+            error: None,
+        }]
+    };
+    let first = codeclimate_json(&at(3));
+    let again = codeclimate_json(&at(3));
+    let shifted = codeclimate_json(&at(40));
+    let gitlab = gitlab_json(&at(3));
+    for index in 0..2 {
+        let fingerprint = &first[index]["fingerprint"];
+        assert_eq!(
+            fingerprint, &again[index]["fingerprint"],
+            "stable across runs"
+        );
+        assert_eq!(
+            fingerprint, &shifted[index]["fingerprint"],
+            "independent of the diagnostic's line"
+        );
+        assert_eq!(
+            fingerprint, &gitlab[index]["fingerprint"],
+            "the same issue keeps its identity across report formats"
+        );
+    }
+    assert_ne!(
+        first[0]["fingerprint"], first[1]["fingerprint"],
+        "duplicate diagnostics still get unique fingerprints"
+    );
+}
+
+#[test]
+fn sarif_rules_describe_every_rule_with_a_result() {
+    let entries = vec![
+        ReportEntry {
+            path: "sub/dirty file.yaml".to_string(),
+            problems: vec![
+                problem(3, 5, Severity::Error, Some("commas"), "too many spaces"),
+                problem(4, 1, Severity::Warning, Some("no-todo"), "TODO left"),
+                problem(6, 2, Severity::Error, Some("commas"), "too few spaces"),
+                problem(7, 2, Severity::Error, None, "syntax error: boom"),
+            ],
+            error: None,
+        },
+        ReportEntry {
+            path: "clean.yaml".to_string(),
+            problems: Vec::new(),
+            error: None,
+        },
+    ];
+
+    let json = sarif_json(&entries);
+    assert_eq!(json["version"], "2.1.0");
+    let run = &json["runs"][0];
+    assert_eq!(run["tool"]["driver"]["name"], "ryl");
+    assert_eq!(run["columnKind"], "unicodeCodePoints");
+    assert_eq!(run["invocations"][0]["executionSuccessful"], true);
+
+    let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+    let results = run["results"].as_array().unwrap();
+    assert_eq!(results.len(), 4);
+    let ids: Vec<&str> = rules
+        .iter()
+        .map(|rule| rule["id"].as_str().unwrap())
+        .collect();
+    assert_eq!(
+        ids,
+        ["commas", "no-todo", "syntax"],
+        "one entry per rule, first-seen order"
+    );
+    for result in results {
+        let index = usize::try_from(result["ruleIndex"].as_u64().unwrap()).unwrap();
+        assert_eq!(rules[index]["id"], result["ruleId"], "{result}");
+    }
+    for rule in rules {
+        assert_eq!(rule["name"], rule["id"], "{rule}");
+        assert!(
+            !rule["shortDescription"]["text"]
+                .as_str()
+                .unwrap()
+                .is_empty(),
+            "{rule}"
+        );
+    }
+    assert_eq!(
+        rules[0]["shortDescription"]["text"],
+        "Spaces around flow collection commas."
+    );
+    assert_eq!(
+        rules[0]["helpUri"],
+        "https://ryl-docs.pages.dev/rules/commas/"
+    );
+    assert!(
+        rules[1].get("helpUri").is_none(),
+        "a pattern rule has no docs page"
+    );
+
+    assert_eq!(results[1]["level"], "warning");
+    let location = &results[0]["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "sub/dirty%20file.yaml");
+    assert_eq!(location["region"]["startLine"], 3);
+    assert_eq!(location["region"]["startColumn"], 5);
+}
+
+#[test]
+fn sarif_reports_processing_errors_as_notifications() {
+    let entries = vec![ReportEntry {
+        path: "broken.yaml".to_string(),
+        problems: Vec::new(),
+        error: Some("failed to read broken.yaml".to_string()),
+    }];
+
+    let json = sarif_json(&entries);
+    let run = &json["runs"][0];
+    assert_eq!(run["results"], serde_json::json!([]));
+    assert_eq!(run["tool"]["driver"]["rules"], serde_json::json!([]));
+    let invocation = &run["invocations"][0];
+    assert_eq!(invocation["executionSuccessful"], false);
+    let notification = &invocation["toolExecutionNotifications"][0];
+    assert_eq!(notification["level"], "error");
+    assert_eq!(
+        notification["message"]["text"],
+        "failed to read broken.yaml"
+    );
+    assert_eq!(
+        notification["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+        "broken.yaml"
+    );
+}
+
+#[test]
+fn review_comments_name_the_rule_on_the_diagnostic_line() {
+    let entries = vec![
+        ReportEntry {
+            path: "sub/dirty.yaml".to_string(),
+            problems: vec![
+                problem(3, 5, Severity::Error, Some("commas"), "too many spaces"),
+                problem(
+                    5,
+                    2,
+                    Severity::Warning,
+                    None,
+                    "syntax error: boom\u{1b}[31m",
+                ),
+            ],
+            error: None,
+        },
+        ReportEntry {
+            path: "clean.yaml".to_string(),
+            problems: Vec::new(),
+            error: None,
+        },
+        ReportEntry {
+            path: "broken.yaml".to_string(),
+            problems: Vec::new(),
+            error: Some("failed to read broken.yaml".to_string()),
+        },
+    ];
+
+    let json: Value = serde_json::from_slice(&render_review(&entries))
+        .expect("review output is valid JSON");
+    assert_eq!(
+        json,
+        serde_json::json!([
+            {
+                "path": "sub/dirty.yaml",
+                "line": 3,
+                "side": "RIGHT",
+                "body": "**error** `commas`: too many spaces (column 5)",
+            },
+            {
+                "path": "sub/dirty.yaml",
+                "line": 5,
+                "side": "RIGHT",
+                "body": "**warning** `syntax`: syntax error: boom\\u{1b}[31m (column 2)",
+            },
+            {
+                "path": "broken.yaml",
+                "line": 1,
+                "side": "RIGHT",
+                "body": "**error**: failed to read broken.yaml",
+            },
+        ]),
+        "clean files get no comment"
+    );
+}
+
+#[test]
+fn json_reports_processing_errors_with_a_null_rule() {
+    let entries = vec![ReportEntry {
+        path: "broken.yaml".to_string(),
+        problems: Vec::new(),
+        error: Some("failed to read broken.yaml".to_string()),
+    }];
+    let json: Value = serde_json::from_slice(&render_json(&entries))
+        .expect("json output is valid JSON");
+    assert_eq!(
+        json,
+        serde_json::json!([{
+            "path": "broken.yaml",
+            "line": 1,
+            "column": 1,
+            "level": "error",
+            "rule": null,
+            "message": "failed to read broken.yaml",
+        }])
+    );
+}
@@ -8,7 +8,7 @@ use ryl::config::YamlLintConfig;
 fn resolve_ctx_handles_path_without_parent() {
     let mut cache: HashMap<PathBuf, (PathBuf, YamlLintConfig, bool)> = HashMap::new();
     let (base_dir, cfg, notices, config_found) =
-        resolve_ctx(Path::new(""), None, false, &mut cache)
+        resolve_ctx(Path::new(""), None, false, None, &mut cache)
             .expect("resolve_ctx should fall back to current directory");
     assert_eq!(base_dir, PathBuf::from("."));
     assert!(notices.is_empty());
@@ -103,6 +103,44 @@ fn reports_unused_anchor() {
     );
 }
 
+#[test]
+fn option_combinations_report_only_enabled_checks() {
+    // One document exercising all three checks: `b` is duplicated, `f` undeclared, and
+    // `i`/`unused` never aliased.
+    let yaml = "---\n- &b true\n- &i 42\n- &b dup\n- *b\n- *f\n- &unused x\n";
+    let undeclared = violation(6, 3, &format!(r#"{MESSAGE_UNDECLARED_ALIAS} "f""#));
+    let duplicated = violation(4, 3, &format!(r#"{MESSAGE_DUPLICATED_ANCHOR} "b""#));
+    let unused = [
+        violation(3, 3, &format!(r#"{MESSAGE_UNUSED_ANCHOR} "i""#)),
+        violation(7, 3, &format!(r#"{MESSAGE_UNUSED_ANCHOR} "unused""#)),
+    ];
+
+    for undeclared_on in [false, true] {
+        for duplicated_on in [false, true] {
+            for unused_on in [false, true] {
+                let cfg =
+                    Config::new_for_tests(undeclared_on, duplicated_on, unused_on);
+                let mut expected = Vec::new();
+                if duplicated_on {
+                    expected.push(duplicated.clone());
+                }
+                if undeclared_on {
+                    expected.push(undeclared.clone());
+                }
+                if unused_on {
+                    expected.extend(unused.iter().cloned());
+                }
+                assert_eq!(
+                    anchors::check(yaml, &cfg),
+                    expected,
+                    "undeclared={undeclared_on} duplicated={duplicated_on} \
+                     unused={unused_on}"
+                );
+            }
+        }
+    }
+}
+
 #[test]
 fn alias_glued_to_colon_is_distinct_from_the_plain_anchor() {
     // Per the YAML spec / reference parser, `*x:` in `{*x: 2}` is the alias name
@@ -165,6 +203,21 @@ fn resets_state_between_documents() {
     );
 }
 
+#[test]
+fn anchor_from_previous_document_is_undeclared() {
+    let cfg = Config::new_for_tests(true, false, false);
+    let yaml = "---\nbase: &base 1\nsame: *base\n---\nother: *base\n";
+    let hits = anchors::check(yaml, &cfg);
+    assert_eq!(
+        hits,
+        vec![violation(
+            5,
+            8,
+            &format!(r#"{MESSAGE_UNDECLARED_ALIAS} "base""#)
+        )]
+    );
+}
+
 #[test]
 fn ignores_ampersand_in_strings_and_block_scalars() {
     let cfg = Config::new_for_tests(true, true, true);
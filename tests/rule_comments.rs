@@ -55,6 +55,33 @@ fn disables_min_spacing_with_negative_one() {
     );
 }
 
+#[test]
+fn disabled_min_spacing_still_requires_starting_space() {
+    let resolved = build_config(
+        "rules:\n  comments:\n    require-starting-space: true\n    min-spaces-from-content: -1\n",
+    );
+    let hits = comments::check("value: 1 #comment\n", &resolved);
+    assert_eq!(
+        hits,
+        vec![Violation {
+            line: 1,
+            column: 11,
+            message: "missing starting space in comment".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn hash_glued_to_content_is_part_of_the_scalar() {
+    // `#` only starts a comment after whitespace, so `1# comment` is the plain scalar
+    // "1# comment" (as yamllint, which sees no comment token here, also treats it).
+    let resolved = build_config(
+        "rules:\n  comments:\n    require-starting-space: true\n    min-spaces-from-content: -1\n",
+    );
+    let hits = comments::check("value: 1# comment\n", &resolved);
+    assert!(hits.is_empty(), "no comment to check: {hits:?}");
+}
+
 #[test]
 fn shebang_respected_when_ignored() {
     let resolved = build_config(
@@ -282,3 +309,48 @@ fn fix_inserts_spaces_for_comment_after_undeclared_anchor_alias() {
     let fixed = comments::fix("a: *missing\nb: 1 #bad\n", &resolved);
     assert_eq!(fixed, Some("a: *missing\nb: 1  # bad\n".to_string()));
 }
+
+#[test]
+fn require_content_after_hash_flags_comments_not_matching_the_pattern() {
+    let cfg = YamlLintConfig::from_toml_str(
+        "[rules.comments]\nrequire-content-after-hash = \"^[[:alpha:]]\"\n",
+    )
+    .expect("config parses");
+    let resolved = Config::resolve(&cfg);
+    let input = "#- todo\n# todo\n## - nested\nkey: value  #  - inline\n####\n#\n";
+    let hits = comments::check(input, &resolved);
+    let mismatch = "comment content does not match \"^[[:alpha:]]\"".to_string();
+    assert_eq!(
+        hits,
+        vec![
+            Violation {
+                line: 1,
+                column: 2,
+                message: "missing starting space in comment".to_string(),
+            },
+            Violation {
+                line: 1,
+                column: 2,
+                message: mismatch.clone(),
+            },
+            Violation {
+                line: 3,
+                column: 4,
+                message: mismatch.clone(),
+            },
+            Violation {
+                line: 4,
+                column: 16,
+                message: mismatch,
+            },
+        ],
+        "`# todo`, hash art and empty comments pass"
+    );
+}
+
+#[test]
+fn require_content_after_hash_is_unset_by_default() {
+    let resolved = build_config("rules:\n  comments: {}\n");
+    let hits = comments::check("# - todo\n", &resolved);
+    assert!(hits.is_empty(), "no content pattern by default: {hits:?}");
+}
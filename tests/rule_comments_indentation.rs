@@ -387,3 +387,16 @@ fn allow_any_open_indent_fix_reindents_genuine_violation() {
         Some("a:\n  b:\n    deep: 1\n  c: 2\n  # stale level\ne: 3\n".to_string())
     );
 }
+
+#[test]
+fn comment_after_document_end_is_judged_at_top_level() {
+    let hits = run("---\nkey: val\n...\n  # comment\n");
+    assert_eq!(hits, vec![Violation { line: 4, column: 3 }]);
+
+    // The nested mapping before `...` no longer offers its indent.
+    let hits = run("---\nkey:\n  sub: val\n...\n  # comment\n---\na: 1\n");
+    assert_eq!(hits, vec![Violation { line: 5, column: 3 }]);
+
+    let hits = run("---\nkey:\n  sub: val\n...\n# comment\n---\na: 1\n");
+    assert!(hits.is_empty());
+}
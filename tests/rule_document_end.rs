@@ -40,6 +40,21 @@ fn reports_missing_marker_between_documents() {
     assert_eq!(hits[1].message, MISSING_MESSAGE);
 }
 
+#[test]
+fn last_only_reports_only_the_final_missing_marker() {
+    let cfg = Config::new_for_tests(true).with_last_only(true);
+    let input = "---\nfirst: document\n---\nsecond: document\n";
+    let hits = document_end::check(input, &cfg);
+    assert_eq!(hits.len(), 1, "expected only the final violation: {hits:?}");
+    assert_eq!(hits[0].line, 4);
+    assert_eq!(hits[0].column, 1);
+    assert_eq!(hits[0].message, MISSING_MESSAGE);
+
+    let ended = "---\nfirst: document\n---\nsecond: document\n...\n";
+    let hits = document_end::check(ended, &cfg);
+    assert!(hits.is_empty(), "a final marker suffices: {hits:?}");
+}
+
 #[test]
 fn explicit_marker_satisfies_requirement() {
     let cfg = Config::new_for_tests(true);
@@ -78,6 +93,17 @@ fn empty_stream_has_no_diagnostics() {
     assert!(hits.is_empty(), "empty stream should not warn: {hits:?}");
 }
 
+#[test]
+fn stream_without_documents_has_no_diagnostics() {
+    for present in [true, false] {
+        let cfg = Config::new_for_tests(present);
+        for input in ["\n\n", "# only a comment\n", "  # indented\n\n# another\n"] {
+            let hits = document_end::check(input, &cfg);
+            assert!(hits.is_empty(), "{present} {input:?}: {hits:?}");
+        }
+    }
+}
+
 #[test]
 fn explicit_marker_with_trailing_spaces_is_still_detected() {
     let cfg = Config::new_for_tests(false);
@@ -58,3 +58,51 @@ fn empty_stream_has_no_diagnostics() {
     let hits = document_start::check("", &cfg);
     assert!(hits.is_empty(), "empty stream should not warn: {hits:?}");
 }
+
+#[test]
+fn stream_without_documents_has_no_diagnostics() {
+    // Blank lines and comments alone produce `StreamStart`/`StreamEnd` with no document in
+    // between, so there is no document whose `---` could be missing (or forbidden).
+    for present in [true, false] {
+        let cfg = Config::new_for_tests(present);
+        for input in ["\n\n", "# only a comment\n", "  # indented\n\n# another\n"] {
+            let hits = document_start::check(input, &cfg);
+            assert!(hits.is_empty(), "{present} {input:?}: {hits:?}");
+        }
+    }
+}
+
+#[test]
+fn reports_missing_marker_at_first_content_after_leading_comments() {
+    let cfg = Config::new_for_tests(true);
+    let cases = [
+        ("# one\n# two\n# three\nfoo: bar\n", 4),
+        ("# license\n\n# notes\n\nfoo:\n  bar: baz\n", 5),
+        ("# header\n  foo: bar\n", 2),
+        ("#!shebang-like\n# header\n- item\n- other\n", 3),
+        ("# header\r\n# more\r\nfoo: bar\r\n", 3),
+        ("  # indented comment\n\nkey: |\n  # not a comment\n", 3),
+    ];
+    for (input, line) in cases {
+        let hits = document_start::check(input, &cfg);
+        assert_eq!(
+            hits,
+            vec![document_start::Violation {
+                line,
+                column: 1,
+                message: MISSING_MESSAGE.to_string(),
+            }],
+            "input: {input:?}"
+        );
+    }
+}
+
+#[test]
+fn leading_comments_before_explicit_marker_satisfy_requirement() {
+    let cfg = Config::new_for_tests(true);
+    let hits = document_start::check("# header\n\n---\nfoo: bar\n", &cfg);
+    assert!(
+        hits.is_empty(),
+        "marker after comments should pass: {hits:?}"
+    );
+}
@@ -82,6 +82,22 @@ fn single_newline_file_is_ignored() {
     );
 }
 
+#[test]
+fn max_end_zero_treats_a_single_final_newline_as_no_blank_line() {
+    let cfg = resolve("rules:\n  empty-lines: {max-end: 0}\n");
+    for clean in ["key: v", "key: v\n", "key: v\r\n"] {
+        let hits = empty_lines::check(clean, &cfg);
+        assert!(hits.is_empty(), "{clean:?}: {hits:?}");
+    }
+
+    for blank in ["key: v\n\n", "key: v\r\n\r\n"] {
+        let hits = empty_lines::check(blank, &cfg);
+        assert_eq!(hits.len(), 1, "{blank:?}: {hits:?}");
+        assert_eq!((hits[0].line, hits[0].column), (2, 1), "{blank:?}");
+        assert_eq!(hits[0].message, "too many blank lines (1 > 0)");
+    }
+}
+
 #[test]
 fn space_only_lines_are_not_blank() {
     let cfg = resolve(
@@ -94,3 +110,72 @@ fn space_only_lines_are_not_blank() {
         "space-only lines should not be treated as blank"
     );
 }
+
+#[test]
+fn trailing_blanks_after_final_document_end_count_toward_max_end() {
+    use std::path::Path;
+
+    let cfg = YamlLintConfig::from_yaml_str(
+        "rules:\n  document-end: enable\n  empty-lines: {max-end: 0}\n  new-line-at-end-of-file: enable\n",
+    )
+    .expect("config parses");
+    let lint = |input: &str| -> Vec<(usize, usize, &'static str, String)> {
+        ryl::lint::lint_str(input, Path::new("in.yaml"), &cfg, Path::new("."))
+            .into_iter()
+            .map(|p| (p.line, p.column, p.rule.unwrap_or("syntax"), p.message))
+            .collect()
+    };
+
+    // The blanks follow the `...`, so the marker still ends the only document and the file
+    // still ends in a newline: only `empty-lines` reports, on the last blank line.
+    assert_eq!(
+        lint("---\nkey: value\n...\n\n\n"),
+        vec![(
+            5,
+            1,
+            "empty-lines",
+            "too many blank lines (2 > 0)".to_string()
+        )]
+    );
+    assert_eq!(
+        lint("---\nkey: value\n...\n\n"),
+        vec![(
+            4,
+            1,
+            "empty-lines",
+            "too many blank lines (1 > 0)".to_string()
+        )]
+    );
+    assert!(lint("---\nkey: value\n...\n").is_empty());
+}
+
+#[test]
+fn crlf_runs_count_like_lf_runs() {
+    let cfg = resolve("rules:\n  empty-lines: enable\n");
+    for newline in ["\n", "\r\n"] {
+        let input = format!("a: 1{nl}{nl}{nl}{nl}b: 2{nl}{nl}", nl = newline);
+        let hits: Vec<(usize, String)> = empty_lines::check(&input, &cfg)
+            .into_iter()
+            .map(|hit| (hit.line, hit.message))
+            .collect();
+        assert_eq!(
+            hits,
+            vec![
+                (4, "too many blank lines (3 > 2)".to_string()),
+                (6, "too many blank lines (1 > 0)".to_string()),
+            ],
+            "{newline:?}"
+        );
+    }
+}
+
+#[test]
+fn entirely_blank_file_reports_its_last_line() {
+    let cfg = resolve("rules:\n  empty-lines: enable\n");
+    for input in ["\n\n\n", "\r\n\r\n\r\n"] {
+        let hits = empty_lines::check(input, &cfg);
+        assert_eq!(hits.len(), 1, "{input:?}: {hits:?}");
+        assert_eq!(hits[0].line, 3, "{input:?}");
+        assert_eq!(hits[0].message, "too many blank lines (3 > 0)", "{input:?}");
+    }
+}
@@ -115,7 +115,85 @@ fn clamps_tagged_empty_value_at_eof_into_bounds() {
     );
 }
 
+#[test]
+fn defaults_forbid_empty_values_in_every_context() {
+    let cfg = resolve_config("rules:\n  empty-values: enable\n");
+    let yaml = "block:\nflow: {a: }\nseq:\n  - \n";
+    let hits = empty_values::check(yaml, &cfg);
+    let messages: Vec<_> = hits.iter().map(|hit| hit.message.as_str()).collect();
+    assert_eq!(
+        messages,
+        [
+            "empty value in block mapping",
+            "empty value in flow mapping",
+            "empty value in block sequence",
+        ]
+    );
+}
+
+#[test]
+fn reports_empty_value_of_last_key_at_eof() {
+    let cfg = resolve_config("rules:\n  empty-values: enable\n");
+    for yaml in ["key:", "key:\n", "outer:\n  key:"] {
+        let hits = empty_values::check(yaml, &cfg);
+        assert_eq!(hits.len(), 1, "{yaml:?}: {hits:?}");
+        assert_eq!(hits[0].message, "empty value in block mapping", "{yaml:?}");
+    }
+}
+
+#[test]
+fn reports_compact_flow_mapping_and_spaced_sequence_entry() {
+    // Like yamllint, a sequence entry is reported just after its `-`, whatever spaces or
+    // comment follow it.
+    let cfg = resolve_config("rules:\n  empty-values: enable\n");
+    assert_eq!(
+        empty_values::check("{a: }\n", &cfg),
+        vec![Violation {
+            line: 1,
+            column: 4,
+            message: "empty value in flow mapping".to_string(),
+        }]
+    );
+    for yaml in ["- \n- value\n", "-   # note\n- value\n"] {
+        assert_eq!(
+            empty_values::check(yaml, &cfg),
+            vec![Violation {
+                line: 1,
+                column: 2,
+                message: "empty value in block sequence".to_string(),
+            }],
+            "{yaml:?}"
+        );
+    }
+    assert_eq!(empty_values::check("- -  \n", &cfg)[0].column, 4);
+}
+
 #[test]
 fn covers_nothing_event_branch() {
     empty_values::coverage_touch_nothing_branch();
 }
+
+#[test]
+fn trailing_comment_after_the_colon_is_not_a_value() {
+    let yaml = "key: # note\nnested:\n  inner:  # note\nset: # note\n  value\n";
+    let cfg = resolve_config(
+        "rules:\n  empty-values:\n    forbid-in-block-mappings: true\n    forbid-in-flow-mappings: false\n    forbid-in-block-sequences: false\n",
+    );
+    let hits = empty_values::check(yaml, &cfg);
+    assert_eq!(
+        hits,
+        vec![
+            Violation {
+                line: 1,
+                column: 5,
+                message: "empty value in block mapping".to_string(),
+            },
+            Violation {
+                line: 3,
+                column: 9,
+                message: "empty value in block mapping".to_string(),
+            },
+        ],
+        "a comment leaves the value empty; a value on the next line still counts"
+    );
+}
@@ -80,6 +80,41 @@ fn ignores_entries_without_inline_values() {
     );
 }
 
+#[test]
+fn checks_each_dash_of_a_nested_sequence() {
+    let cfg = Config::new_for_tests(1);
+    let diagnostics = hyphens::check("- -  a\n  -   b\n-  - c\n", &cfg);
+    assert_eq!(
+        diagnostics,
+        vec![
+            too_many_spaces(1, 5),
+            too_many_spaces(2, 6),
+            too_many_spaces(3, 3)
+        ]
+    );
+}
+
+#[test]
+fn ignores_dashes_inside_scalars() {
+    let cfg = Config::new_for_tests(1);
+    let input = "---\nblock: |\n  -    literal\nplain: a\n  -    continued\nquoted: \"-   x\"\n";
+    let diagnostics = hyphens::check(input, &cfg);
+    assert!(
+        diagnostics.is_empty(),
+        "scalar content is not a sequence entry: {diagnostics:?}"
+    );
+}
+
+#[test]
+fn trailing_spaces_after_a_bare_dash_are_not_counted() {
+    let cfg = Config::new_for_tests(1);
+    let diagnostics = hyphens::check("-   \n  key: value\n-   ", &cfg);
+    assert!(
+        diagnostics.is_empty(),
+        "unexpected diagnostics: {diagnostics:?}"
+    );
+}
+
 // `dash-on-own-line` is off by default, so the spec-style requirement never fires
 // unless explicitly enabled.
 #[test]
@@ -495,3 +495,116 @@ fn allows_indented_mapping_sequence_in_mapping() {
     let yaml = "subjects:\n  - apiGroup: rbac.authorization.k8s.io\n    kind: User\n";
     assert!(indentation::check(yaml, &cfg).is_empty());
 }
+
+#[test]
+fn allows_root_level_sequence_under_every_indent_sequences_setting() {
+    for setting in [
+        IndentSequencesSetting::True,
+        IndentSequencesSetting::False,
+        IndentSequencesSetting::Whatever,
+        IndentSequencesSetting::Consistent,
+    ] {
+        let cfg = config(SpacesSetting::Fixed(2), setting, false);
+        for yaml in ["- a\n- b\n", "---\n- a\n- - b\n  - c\n", "- a\n---\n- b\n"] {
+            let hits = indentation::check(yaml, &cfg);
+            assert!(hits.is_empty(), "{setting:?} flagged {yaml:?}: {hits:?}");
+        }
+    }
+}
+
+#[test]
+fn blank_lines_keep_the_expected_indent() {
+    let cfg = config(SpacesSetting::Fixed(2), IndentSequencesSetting::True, false);
+    let yaml = "root:\n  first: 1\n\n  nested:\n\n    - a\n\n    - b:\n\n        c: d\n\n  note: plain\n\n   continued\n\n  bad:\n\n     deep: 1\n";
+    let hits = indentation::check(yaml, &cfg);
+    assert_eq!(
+        hits,
+        vec![Violation {
+            line: 18,
+            column: 6,
+            message: "wrong indentation: expected 4 but found 5".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn blank_line_does_not_end_a_plain_scalar_continuation() {
+    let cfg = config(SpacesSetting::Fixed(2), IndentSequencesSetting::True, false);
+    for yaml in [
+        "a:\n  b: plain\n   more\n  c: 1\n",
+        "a:\n  b: plain\n\n   more\n  c: 1\n",
+        "a:\n  b: plain\n  \n\n   more\n  c: 1\n",
+    ] {
+        let hits = indentation::check(yaml, &cfg);
+        assert!(hits.is_empty(), "{yaml:?}: {hits:?}");
+    }
+}
+
+#[test]
+fn explicit_key_entries_indent_their_compact_content() {
+    // `?`/`:` open their content two columns in, like `- `, whatever `spaces` is.
+    for spaces in [2, 4] {
+        let cfg = config(
+            SpacesSetting::Fixed(spaces),
+            IndentSequencesSetting::True,
+            false,
+        );
+        for yaml in [
+            "? key\n: value\n",
+            "? a: 1\n  b: 2\n: c: 3\n  d: 4\n",
+            "? - a\n  - b\n: - c\n  - d\n",
+            "- ? a\n  : b\n- ? c: 1\n    d: 2\n  : e\n",
+            "root:\n  ? |\n    block\n  : >\n    folded\n",
+        ] {
+            let hits = indentation::check(yaml, &cfg);
+            assert!(hits.is_empty(), "spaces {spaces}, {yaml:?}: {hits:?}");
+        }
+    }
+}
+
+#[test]
+fn explicit_key_content_is_still_checked() {
+    let cfg = config(SpacesSetting::Fixed(2), IndentSequencesSetting::True, false);
+    let hits = indentation::check("? a: 1\n  b:\n     c: 1\n: d\n", &cfg);
+    assert_eq!(
+        hits,
+        vec![Violation {
+            line: 3,
+            column: 6,
+            message: "wrong indentation: expected 4 but found 5".to_string(),
+        }]
+    );
+}
+
+#[test]
+fn over_indented_comment_lines_are_left_to_comments_indentation() {
+    // The first indented line is a comment, so it must not set the `consistent` unit.
+    let cfg = parse_config("rules:\n  indentation:\n    spaces: consistent\n");
+    let yaml = "key:\n      # deep first\n  child:\n        # deeper\n    leaf: 1\nother:\n  - a\n          # in seq\n  - b\n";
+    let hits = indentation::check(yaml, &cfg);
+    assert!(hits.is_empty(), "only content is judged: {hits:?}");
+}
+
+#[test]
+fn comment_between_key_and_nested_block_does_not_set_the_child_indent() {
+    let cfg = config(SpacesSetting::Fixed(2), IndentSequencesSetting::True, false);
+    for yaml in [
+        "parent:\n  # note\n  child: 1\n",
+        "parent:\n# note\n  child: 1\n",
+        "parent:\n    # note\n  child: 1\n",
+        "parent:\n  # note\n\n  - 1\n",
+    ] {
+        let hits = indentation::check(yaml, &cfg);
+        assert!(hits.is_empty(), "{yaml:?}: {hits:?}");
+    }
+
+    let hits = indentation::check("parent:\n  # note\n   child: 1\n", &cfg);
+    assert_eq!(
+        hits,
+        vec![Violation {
+            line: 3,
+            column: 4,
+            message: "wrong indentation: expected 2 but found 3".to_string(),
+        }]
+    );
+}
@@ -18,6 +18,21 @@ fn reports_duplicate_scalar_keys() {
     assert_eq!(hit.message, "duplication of key \"first\" in mapping");
 }
 
+#[test]
+fn reports_each_later_occurrence_but_not_the_first() {
+    let cfg = build_config("rules:\n  key-duplicates: enable\n");
+    let input = "dup: 1\nother: 2\ndup: 3\nnested:\n  x: 4\n  dup: 5\ndup: 6\n";
+    let hits = key_duplicates::check(input, &cfg);
+    let positions: Vec<(usize, usize)> =
+        hits.iter().map(|hit| (hit.line, hit.column)).collect();
+    assert_eq!(positions, [(3, 1), (7, 1)], "{hits:?}");
+    assert!(
+        hits.iter()
+            .all(|hit| hit.message == "duplication of key \"dup\" in mapping"),
+        "{hits:?}"
+    );
+}
+
 #[test]
 fn reports_duplicate_flow_keys() {
     let cfg = build_config("rules:\n  key-duplicates: enable\n");
@@ -74,3 +89,22 @@ fn complex_keys_are_ignored_without_panicking() {
         "complex keys should not produce diagnostics: {hits:?}"
     );
 }
+
+#[test]
+fn sibling_and_nested_mappings_keep_independent_key_sets() {
+    let cfg = build_config("rules:\n  key-duplicates: enable\n");
+    let input = "a:\n  x: 1\n  a: 2\nb:\n  x: 3\n  y: {x: 4, x: 5}\nc: [{k: 1}, {k: 2, k: 3}]\n";
+    let hits = key_duplicates::check(input, &cfg);
+    let reported: Vec<(usize, usize, &str)> = hits
+        .iter()
+        .map(|hit| (hit.line, hit.column, hit.message.as_str()))
+        .collect();
+    assert_eq!(
+        reported,
+        [
+            (6, 13, "duplication of key \"x\" in mapping"),
+            (7, 20, "duplication of key \"k\" in mapping"),
+        ],
+        "only repeats within one mapping count: {hits:?}"
+    );
+}
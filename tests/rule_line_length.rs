@@ -25,6 +25,21 @@ fn flags_lines_longer_than_max() {
     );
 }
 
+#[test]
+fn counts_characters_not_bytes_for_length_and_column() {
+    let resolved = build_config("rules:\n  line-length: {max: 10}\n");
+    // Line 1 is ten characters (fourteen bytes) and fits; line 2 is one over.
+    let hits = line_length::check("k: éééé éé\nk: éééé ééé\n", &resolved);
+    assert_eq!(
+        hits,
+        vec![Violation {
+            line: 2,
+            column: 11,
+            message: "line too long (11 > 10 characters)".to_string(),
+        }]
+    );
+}
+
 #[test]
 fn allows_long_single_word_by_default() {
     let resolved = build_config("rules:\n  line-length: {max: 20}\n");
@@ -108,6 +123,20 @@ fn handles_comment_prefixes_like_yamllint() {
     assert_eq!(disallowed_hits.len(), 1, "spaced hash comments should fail");
 }
 
+#[test]
+fn long_trailing_comment_is_not_a_non_breakable_word() {
+    let resolved = build_config(
+        "rules:\n  line-length:\n    max: 20\n    allow-non-breakable-words: true\n",
+    );
+    // Like yamllint, the overflow is only excused when nothing after the leading
+    // indicator contains a space, so a trailing comment never qualifies even when the
+    // comment itself is one unbreakable URL.
+    let input = "key: value # http://example.com/super/long/url/with/no/spaces\n";
+    let hits = line_length::check(input, &resolved);
+    assert_eq!(hits.len(), 1, "long trailing comment should be flagged");
+    assert_eq!(hits[0].column, 21);
+}
+
 #[test]
 fn inline_option_implies_non_breakable_words() {
     let resolved = build_config(
@@ -198,3 +227,50 @@ fn inline_mapping_guard_handles_plain_text() {
         "non-mapping lines should still report violations"
     );
 }
+
+#[test]
+fn leading_directive_overrides_max_for_the_file() {
+    let resolved = build_config("rules:\n  line-length: {max: 20}\n");
+    let long = format!("key: {}\n", "word ".repeat(8));
+    let input = format!("# generated file\n\n# ryl line-length max: 200\n{long}");
+    assert!(line_length::check(&input, &resolved).is_empty());
+
+    let lowered = "# ryl line-length max: 5\nkey: value\n";
+    let hits = line_length::check(lowered, &resolved);
+    assert_eq!(hits.len(), 2, "{hits:?}");
+    assert_eq!(hits[1].message, "line too long (10 > 5 characters)");
+}
+
+#[test]
+fn directive_after_content_is_ignored() {
+    let resolved = build_config("rules:\n  line-length: {max: 20}\n");
+    let input = format!("key: {}\n# ryl line-length max: 200\n", "word ".repeat(8));
+    let hits = line_length::check(&input, &resolved);
+    // Both the content line and the (inert) 26-character comment exceed 20.
+    assert_eq!(hits.len(), 2, "{hits:?}");
+    assert_eq!(hits[0].line, 1);
+}
+
+#[test]
+fn key_value_url_needs_the_inline_mappings_option() {
+    // A `key: url` line holds a space, so it is not one non-breakable word: only the
+    // inline-mappings option (which looks at the value alone) excuses it.
+    let url = "https://example.com/a/very/long/path/that/cannot/be/broken";
+    let input = format!("list:\n  - {url}\nkey: {url}\nnested:\n  key: {url}\n");
+
+    let words_only = build_config("rules:\n  line-length:\n    max: 40\n");
+    let hits = line_length::check(&input, &words_only);
+    let positions: Vec<(usize, usize)> =
+        hits.iter().map(|hit| (hit.line, hit.column)).collect();
+    assert_eq!(
+        positions,
+        [(3, 41), (5, 41)],
+        "only the list item is one non-breakable word: {hits:?}"
+    );
+
+    let inline = build_config(
+        "rules:\n  line-length:\n    max: 40\n    allow-non-breakable-inline-mappings: true\n",
+    );
+    let hits = line_length::check(&input, &inline);
+    assert!(hits.is_empty(), "mapping values are excused: {hits:?}");
+}
@@ -76,3 +76,31 @@ fn fix_returns_none_when_newlines_already_match() {
     let fixed = new_lines::fix("alpha\nbeta\n", resolved, "\n");
     assert_eq!(fixed, None);
 }
+
+#[test]
+fn fix_normalizes_mixed_endings_to_each_type_without_doubling_cr() {
+    let mixed = "a: 1\r\nb: 2\nc: 3\r\n";
+    for (kind, platform, expected) in [
+        ("unix", "\r\n", "a: 1\nb: 2\nc: 3\n"),
+        ("dos", "\n", "a: 1\r\nb: 2\r\nc: 3\r\n"),
+        ("platform", "\n", "a: 1\nb: 2\nc: 3\n"),
+        ("platform", "\r\n", "a: 1\r\nb: 2\r\nc: 3\r\n"),
+    ] {
+        let cfg = YamlLintConfig::from_yaml_str(&format!(
+            "rules:\n  new-lines:\n    type: {kind}\n"
+        ))
+        .expect("config parses");
+        let resolved = Config::resolve(&cfg);
+        let fixed = new_lines::fix(mixed, resolved, platform);
+        assert_eq!(
+            fixed.as_deref(),
+            Some(expected),
+            "type {kind} on {platform:?}"
+        );
+        assert_eq!(
+            new_lines::fix(expected, resolved, platform),
+            None,
+            "already normalized for {kind}"
+        );
+    }
+}
@@ -880,3 +880,128 @@ fn strips_yaml_1_1_words_under_explicit_yaml_1_2() {
         Some("%YAML 1.2\n---\nkey: no\n"),
     );
 }
+
+#[test]
+fn block_scalars_are_never_required_to_be_quoted() {
+    let yaml = "literal: |\n  text\nfolded: >-\n  text\n? |\n  block key\n: 'v'\n";
+    for options in [
+        "required: true",
+        "required: true\n    check-keys: true",
+        "required: false\n    extra-required: ['.*']",
+    ] {
+        let cfg = build_config(&format!(
+            "rules:\n  document-start: disable\n  quoted-strings:\n    {options}\n"
+        ));
+        let hits = quoted_strings::check(yaml, &cfg);
+        let flagged: Vec<usize> = hits.iter().map(|hit| hit.line).collect();
+        let expected: &[usize] = if options.contains("check-keys") {
+            &[1, 3]
+        } else {
+            &[]
+        };
+        assert_eq!(flagged, expected, "{options}: {hits:?}");
+    }
+}
+
+#[test]
+fn option_matrix_reports_each_option_on_one_document() {
+    let input = "plain: text\nsingle: 'text'\ndouble: \"text\"\n'key': 1\nurl: 'http://x'\nquoted: 'say \"hi\"'\n";
+    type Hits = &'static [(usize, usize, &'static str)];
+    let cases: [(&str, Hits); 8] = [
+        (
+            "quoted-strings: enable",
+            &[(1, 8, "string value is not quoted with any quotes")],
+        ),
+        (
+            "quoted-strings: {quote-type: double}",
+            &[
+                (1, 8, "string value is not quoted with double quotes"),
+                (2, 9, "string value is not quoted with double quotes"),
+                (5, 6, "string value is not quoted with double quotes"),
+                (6, 9, "string value is not quoted with double quotes"),
+            ],
+        ),
+        (
+            "quoted-strings: {quote-type: double, allow-quoted-quotes: true}",
+            &[
+                (1, 8, "string value is not quoted with double quotes"),
+                (2, 9, "string value is not quoted with double quotes"),
+                (5, 6, "string value is not quoted with double quotes"),
+            ],
+        ),
+        (
+            "quoted-strings: {required: false, quote-type: single}",
+            &[(3, 9, "string value is not quoted with single quotes")],
+        ),
+        (
+            "quoted-strings: {required: only-when-needed}",
+            &[
+                (2, 9, "string value is redundantly quoted with any quotes"),
+                (3, 9, "string value is redundantly quoted with any quotes"),
+                (5, 6, "string value is redundantly quoted with any quotes"),
+                (6, 9, "string value is redundantly quoted with any quotes"),
+            ],
+        ),
+        (
+            "quoted-strings: {required: only-when-needed, extra-allowed: ['^http']}",
+            &[
+                (2, 9, "string value is redundantly quoted with any quotes"),
+                (3, 9, "string value is redundantly quoted with any quotes"),
+                (6, 9, "string value is redundantly quoted with any quotes"),
+            ],
+        ),
+        (
+            "quoted-strings: {required: false, extra-required: ['^te']}",
+            &[(1, 8, "string value is not quoted")],
+        ),
+        (
+            "quoted-strings: {required: only-when-needed, check-keys: true}",
+            &[
+                (2, 9, "string value is redundantly quoted with any quotes"),
+                (3, 9, "string value is redundantly quoted with any quotes"),
+                (4, 1, "string key is redundantly quoted with any quotes"),
+                (5, 6, "string value is redundantly quoted with any quotes"),
+                (6, 9, "string value is redundantly quoted with any quotes"),
+            ],
+        ),
+    ];
+    for (rule, expected) in cases {
+        let cfg = build_config(&format!("rules:\n  {rule}\n"));
+        let hits = quoted_strings::check(input, &cfg);
+        let reported: Vec<(usize, usize, &str)> = hits
+            .iter()
+            .map(|hit| (hit.line, hit.column, hit.message.as_str()))
+            .collect();
+        assert_eq!(reported, expected, "{rule}");
+    }
+}
+
+#[test]
+fn only_when_needed_keeps_quotes_for_every_unsafe_leading_character() {
+    let cfg = only_when_needed();
+    // `-`, `?`, and `:` only start an indicator when followed by a space.
+    let cases = [
+        "'- x'", "'? x'", "': x'", "',x'", "'[x'", "'{x'", "'#x'", "'&x'", "'*x'",
+        "'!x'", "'|x'", "'>x'", "\"'x\"", "'\"x'", "'%x'", "'@x'", "'`x'",
+    ];
+    for value in cases {
+        let yaml = format!("key: {value}\n");
+        let hits = quoted_strings::check(&yaml, &cfg);
+        assert!(hits.is_empty(), "{value} needs its quotes: {hits:?}");
+        assert_eq!(quoted_strings::fix(&yaml, &cfg), None, "{value} is kept");
+    }
+}
+
+#[test]
+fn only_when_needed_flags_dash_question_colon_glued_to_text() {
+    let cfg = only_when_needed();
+    for value in ["'-x'", "'?x'", "':x'"] {
+        let yaml = format!("key: {value}\n");
+        let hits = quoted_strings::check(&yaml, &cfg);
+        assert_eq!(hits.len(), 1, "{value} is a valid plain scalar: {hits:?}");
+        assert_eq!(
+            hits[0].message,
+            "string value is redundantly quoted with any quotes"
+        );
+    }
+}
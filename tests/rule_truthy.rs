@@ -31,6 +31,20 @@ fn skips_quoted_or_explicitly_tagged_values() {
     assert!(hits.is_empty(), "quoted/tagged values should be ignored");
 }
 
+#[test]
+fn skips_tagged_values_with_extra_spacing_or_verbatim_tags() {
+    let resolved = build_config("rules:\n  truthy: enable\n");
+    let hits = truthy::check(
+        "---\nspaced: !!str  yes\nverbatim: !<tag:yaml.org,2002:str> yes\n\
+         flow: [!!str   on, !<tag:yaml.org,2002:str> off]\n",
+        &resolved,
+    );
+    assert!(
+        hits.is_empty(),
+        "any tag marks the scalar as explicit: {hits:?}"
+    );
+}
+
 #[test]
 fn respects_allowed_values_override() {
     let resolved =
@@ -42,6 +56,26 @@ fn respects_allowed_values_override() {
     assert_eq!(hits[0].message, "truthy value should be one of [no, yes]");
 }
 
+#[test]
+fn allowed_values_message_is_sorted_by_code_point() {
+    for (allowed, expected) in [
+        ("[\"true\", \"false\"]", "[false, true]"),
+        ("[\"on\", \"True\", \"false\"]", "[True, false, on]"),
+        ("[\"yes\", \"false\", \"yes\"]", "[false, yes, yes]"),
+    ] {
+        let resolved = build_config(&format!(
+            "rules:\n  truthy:\n    allowed-values: {allowed}\n"
+        ));
+        let hits = truthy::check("key: off\n", &resolved);
+        assert_eq!(hits.len(), 1, "{allowed}");
+        assert_eq!(
+            hits[0].message,
+            format!("truthy value should be one of {expected}"),
+            "{allowed}"
+        );
+    }
+}
+
 #[test]
 fn respects_yaml_version_directive() {
     let resolved = build_config("rules:\n  truthy: enable\n");
@@ -146,3 +180,70 @@ fn ignores_yaml_directive_with_non_numeric_major() {
 // Inline rule-disable directives (`# yamllint/ryl disable…`) are handled globally by
 // the lint engine, not per-rule; their behaviour is covered in tests/cli_directives.rs
 // and tests/yamllint_compat_directives.rs.
+
+#[test]
+fn flags_flow_and_block_values_identically() {
+    let resolved = build_config("rules:\n  truthy: enable\n");
+    let positions = |yaml: &str| -> Vec<(usize, usize)> {
+        truthy::check(yaml, &resolved)
+            .iter()
+            .map(|hit| (hit.line, hit.column))
+            .collect()
+    };
+
+    assert_eq!(positions("enabled: yes\n"), vec![(1, 10)]);
+    assert_eq!(positions("{enabled: yes}\n"), vec![(1, 11)]);
+    assert_eq!(positions("flow: {enabled: yes}\n"), vec![(1, 17)]);
+    assert_eq!(positions("- yes\n- no\n"), vec![(1, 3), (2, 3)]);
+    assert_eq!(positions("[yes, no]\n"), vec![(1, 2), (1, 7)]);
+    assert_eq!(
+        positions("list: [\n  yes,\n  {on: no},\n]\n"),
+        vec![(2, 3), (3, 4), (3, 8)]
+    );
+}
+
+#[test]
+fn flow_mapping_keys_follow_check_keys() {
+    let yaml = "{on: 1, off: 2}\n";
+    let disabled = build_config("rules:\n  truthy:\n    check-keys: false\n");
+    assert!(truthy::check(yaml, &disabled).is_empty());
+
+    let enabled = build_config("rules:\n  truthy:\n    check-keys: true\n");
+    let columns: Vec<usize> = truthy::check(yaml, &enabled)
+        .iter()
+        .map(|hit| hit.column)
+        .collect();
+    assert_eq!(columns, vec![2, 9]);
+}
+
+#[test]
+fn checks_keys_by_default() {
+    let resolved = build_config("rules:\n  truthy: enable\n");
+    assert!(
+        resolved.check_keys,
+        "check-keys defaults to true, as in yamllint"
+    );
+    let hits = truthy::check("on: value\n", &resolved);
+    assert_eq!(hits.len(), 1, "the `on` key should be flagged");
+    assert_eq!(hits[0].line, 1);
+    assert_eq!(hits[0].column, 1);
+}
+
+#[test]
+fn empty_allowed_values_flags_every_truthy_spelling() {
+    let resolved = build_config("rules:\n  truthy:\n    allowed-values: []\n");
+    let spellings = [
+        "YES", "Yes", "yes", "NO", "No", "no", "TRUE", "True", "true", "FALSE",
+        "False", "false", "ON", "On", "on", "OFF", "Off", "off",
+    ];
+    let input: String = spellings
+        .iter()
+        .map(|value| format!("- {value}\n"))
+        .collect();
+    let hits = truthy::check(&input, &resolved);
+    assert_eq!(hits.len(), spellings.len(), "{hits:?}");
+    for (line, hit) in hits.iter().enumerate() {
+        assert_eq!((hit.line, hit.column), (line + 1, 3));
+        assert_eq!(hit.message, "truthy value should be one of []");
+    }
+}
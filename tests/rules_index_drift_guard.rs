@@ -0,0 +1,46 @@
+//! Guards the hand-maintained `rules::rule_summary` table against the `docs/rules.md`
+//! index it copies: each built-in rule's summary (used by report formats such as SARIF's
+//! `shortDescription`) must equal the description in that rule's index row, so editing
+//! one without the other fails the build.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Map each rule id in the index tables to its description cell, from rows shaped
+/// ``| [`<id>`](rules/<id>.md) | <description> | <fix> |``.
+fn index_descriptions(index: &str) -> BTreeMap<&str, &str> {
+    index
+        .lines()
+        .filter_map(|line| {
+            let rest = line.strip_prefix("| [`")?;
+            let (id, rest) = rest.split_once("`](")?;
+            let (_, rest) = rest.split_once(") | ")?;
+            let (description, _) = rest.split_once(" |")?;
+            Some((id, description))
+        })
+        .collect()
+}
+
+#[test]
+fn rule_summaries_match_the_docs_index() {
+    let index = Path::new(env!("CARGO_MANIFEST_DIR")).join("docs/rules.md");
+    // docs/ is not packaged in the published crate (Cargo.toml `include`), so skip when
+    // the index is absent (e.g. `cargo test` against a packaged crate).
+    if !index.is_file() {
+        return;
+    }
+    let index = std::fs::read_to_string(index).unwrap();
+    let descriptions = index_descriptions(&index);
+    assert_eq!(
+        descriptions.len(),
+        ryl::rules::ALL_RULE_IDS.len(),
+        "the index should have one row per rule: {descriptions:?}"
+    );
+    for id in ryl::rules::ALL_RULE_IDS {
+        assert_eq!(
+            ryl::rules::rule_summary(id),
+            descriptions.get(id).copied(),
+            "`rule_summary(\"{id}\")` and its docs/rules.md row disagree"
+        );
+    }
+}
@@ -0,0 +1,119 @@
+//! `lint_str` parses each buffer once and replays the recorded events into every
+//! event-driven rule, while each rule's public `check` still parses on its own. Both
+//! paths must report the same diagnostics.
+
+use std::path::Path;
+
+use ryl::config::YamlLintConfig;
+use ryl::lint_str;
+use ryl::rules::{
+    braces, brackets, colons, commas, document_end, document_start, empty_values,
+    float_values, key_duplicates, key_ordering, merge_keys, octal_values,
+    quoted_strings, tags, truthy,
+};
+
+const CONFIG: &str = "[rules]\n\
+    document-start = 'enable'\n\
+    document-end = 'enable'\n\
+    commas = 'enable'\n\
+    colons = 'enable'\n\
+    braces = 'enable'\n\
+    brackets = 'enable'\n\
+    octal-values = 'enable'\n\
+    empty-values = 'enable'\n\
+    quoted-strings = 'enable'\n\
+    truthy = 'enable'\n\
+    key-duplicates = 'enable'\n\
+    key-ordering = 'enable'\n\
+    merge-keys = 'enable'\n\
+    [rules.tags]\n\
+    forbid-removed-types = true\n\
+    [rules.float-values]\n\
+    require-numeral-before-decimal = true\n";
+
+// One document trips every rule above; the second checks that replay crosses documents.
+const FIXTURE: &str = "key: yes\n\
+    octal: 010\n\
+    float: .5\n\
+    empty:\n\
+    quoted: \"double\"\n\
+    list: [ 1,2 ]\n\
+    map: { a: 1 , b:  2 }\n\
+    tagged: !!set {x}\n\
+    base: &base {x: 1}\n\
+    merged:\n  <<: *base\n  y: 2\n\
+    a: 1\n\
+    a: 2\n\
+    ---\n\
+    second: on\n\
+    ...\n";
+
+type Diagnostic = (usize, usize, String);
+
+fn shared(problems: &[ryl::LintProblem], rule: &str) -> Vec<Diagnostic> {
+    problems
+        .iter()
+        .filter(|problem| problem.rule == Some(rule))
+        .map(|problem| (problem.line, problem.column, problem.message.clone()))
+        .collect()
+}
+
+macro_rules! own {
+    ($m:ident, $cfg:expr) => {
+        $m::check(FIXTURE, &$m::Config::resolve($cfg))
+            .into_iter()
+            .map(|hit| (hit.line, hit.column, hit.message))
+            .collect::<Vec<Diagnostic>>()
+    };
+}
+
+#[test]
+fn shared_parse_matches_each_rule_parsing_on_its_own() {
+    let cfg = YamlLintConfig::from_toml_str(CONFIG).expect("config parses");
+    let problems = lint_str(FIXTURE, Path::new("fixture.yaml"), &cfg, Path::new("."));
+    assert!(
+        problems.iter().all(|problem| problem.rule.is_some()),
+        "the fixture must parse: {problems:?}"
+    );
+
+    let cases: Vec<(&str, Vec<Diagnostic>)> = vec![
+        (document_start::ID, own!(document_start, &cfg)),
+        (document_end::ID, own!(document_end, &cfg)),
+        (commas::ID, own!(commas, &cfg)),
+        (colons::ID, own!(colons, &cfg)),
+        (braces::ID, own!(braces, &cfg)),
+        (brackets::ID, own!(brackets, &cfg)),
+        (tags::ID, own!(tags, &cfg)),
+        (octal_values::ID, own!(octal_values, &cfg)),
+        (float_values::ID, own!(float_values, &cfg)),
+        (empty_values::ID, own!(empty_values, &cfg)),
+        (quoted_strings::ID, own!(quoted_strings, &cfg)),
+        (truthy::ID, own!(truthy, &cfg)),
+        (key_duplicates::ID, own!(key_duplicates, &cfg)),
+        (key_ordering::ID, own!(key_ordering, &cfg)),
+        (
+            merge_keys::ID,
+            merge_keys::check(FIXTURE)
+                .into_iter()
+                .map(|hit| (hit.line, hit.column, hit.message))
+                .collect(),
+        ),
+    ];
+    for (rule, own) in cases {
+        assert!(!own.is_empty(), "the fixture should exercise {rule}");
+        assert_eq!(shared(&problems, rule), own, "{rule}");
+    }
+}
+
+#[test]
+fn shared_parse_still_reports_a_syntax_error_alone() {
+    let cfg = YamlLintConfig::from_toml_str(CONFIG).expect("config parses");
+    let content = format!("{FIXTURE}bad: [1\n");
+    let problems = lint_str(&content, Path::new("fixture.yaml"), &cfg, Path::new("."));
+    assert_eq!(problems.len(), 1, "{problems:?}");
+    assert_eq!(problems[0].rule, None);
+    assert!(
+        problems[0].message.starts_with("syntax error:"),
+        "{problems:?}"
+    );
+}
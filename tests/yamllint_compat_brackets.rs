@@ -362,3 +362,84 @@ fn brackets_rule_matches_yamllint() {
         );
     }
 }
+
+#[test]
+fn nested_forbidden_sequences_match_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let input = dir.path().join("nested.yaml");
+    fs::write(&input, "---\na: [[1], [2, [3]]]\nb: [[], [1]]\n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for forbid in ["true", "non-empty"] {
+        let cfg = dir.path().join(format!("brackets-forbid-{forbid}.yml"));
+        fs::write(
+            &cfg,
+            format!(
+                "rules:\n  document-start: disable\n  brackets:\n    forbid: {forbid}\n"
+            ),
+        )
+        .unwrap();
+        for scenario in SCENARIOS {
+            let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+            ryl_cmd.arg("-c").arg(&cfg).arg(&input);
+            let (ryl_code, ryl_output) = capture_with_env(ryl_cmd, scenario.envs);
+
+            let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+            yam_cmd.arg("-c").arg(&cfg).arg(&input);
+            let (yam_code, yam_output) = capture_with_env(yam_cmd, scenario.envs);
+
+            assert_eq!(ryl_code, 1, "ryl exit ({}/{forbid})", scenario.label);
+            assert_eq!(
+                ryl_code, yam_code,
+                "exit mismatch ({}/{forbid})",
+                scenario.label
+            );
+            assert_eq!(
+                ryl_output, yam_output,
+                "nested forbid diagnostics mismatch ({}/{forbid})",
+                scenario.label
+            );
+        }
+    }
+}
+
+#[test]
+fn forbid_non_empty_multiline_sequences_match_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let input = dir.path().join("multiline.yaml");
+    fs::write(
+        &input,
+        "---\nempty: [\n]\nblank: [\n\n  ]\ncommented: [  # none\n]\n\
+         item: [\n  1\n]\nflat: [1]\n",
+    )
+    .unwrap();
+    let cfg = dir.path().join("brackets-forbid-non-empty.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  document-start: disable\n  brackets:\n    forbid: non-empty\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for scenario in SCENARIOS {
+        let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+        ryl_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (ryl_code, ryl_output) = capture_with_env(ryl_cmd, scenario.envs);
+
+        let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+        yam_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (yam_code, yam_output) = capture_with_env(yam_cmd, scenario.envs);
+
+        assert_eq!(ryl_code, 1, "ryl exit ({})", scenario.label);
+        assert_eq!(ryl_code, yam_code, "exit mismatch ({})", scenario.label);
+        assert_eq!(
+            ryl_output, yam_output,
+            "multi-line forbid diagnostics mismatch ({})",
+            scenario.label
+        );
+    }
+}
@@ -346,3 +346,43 @@ fn inline_comment_after_value_matches_yamllint() {
         );
     }
 }
+
+#[test]
+fn flow_mapping_and_block_scalar_colons_match_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg_path = dir.path().join("colons.yml");
+    fs::write(
+        &cfg_path,
+        "rules:\n  document-start: disable\n  colons: enable\n",
+    )
+    .unwrap();
+
+    let yaml_path = dir.path().join("flow.yaml");
+    fs::write(
+        &yaml_path,
+        "a: {b : 1, c:   2}\n# d  :   e\nf: |\n  g  :   h\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+
+    for scenario in SCENARIOS {
+        let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+        ryl_cmd.arg("-c").arg(&cfg_path).arg(&yaml_path);
+        let (ryl_code, ryl_output) = capture_with_env(ryl_cmd, scenario.envs);
+
+        let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+        yam_cmd.arg("-c").arg(&cfg_path).arg(&yaml_path);
+        let (yam_code, yam_output) = capture_with_env(yam_cmd, scenario.envs);
+
+        assert_eq!(ryl_code, 1, "ryl exit ({})", scenario.label);
+        assert_eq!(yam_code, 1, "yamllint exit ({})", scenario.label);
+        assert_eq!(
+            ryl_output, yam_output,
+            "output mismatch ({})",
+            scenario.label
+        );
+    }
+}
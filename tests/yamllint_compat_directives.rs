@@ -51,6 +51,11 @@ const CASES: &[(&str, &str)] = &[
         "disable-then-enable.yaml",
         "# yamllint disable\n# yamllint enable rule:colons\na:  yes\n",
     ),
+    (
+        "overlapping.yaml",
+        "# yamllint disable rule:colons\na:  yes\n# yamllint disable rule:truthy\nb:  no\n\
+         # yamllint enable rule:colons\nc:  yes\n# yamllint enable\nd:  no\n",
+    ),
     (
         "multiple.yaml",
         "a:  yes  # yamllint disable-line rule:colons rule:truthy\n",
@@ -134,3 +134,84 @@ fn document_start_rule_matches_yamllint() {
         );
     }
 }
+
+#[test]
+fn missing_marker_after_leading_comments_matches_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("commented.yaml");
+    fs::write(
+        &file,
+        "# license header\n# second line\n\n  # indented note\nfoo:\n  bar: baz\n",
+    )
+    .unwrap();
+
+    let cfg = dir.path().join("require.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  document-start:\n    level: error\n    present: true\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+
+    for scenario in SCENARIOS {
+        let mut ryl = build_ryl_command(exe, scenario.ryl_format);
+        ryl.arg("-c").arg(&cfg).arg(&file);
+        let (ryl_code, ryl_msg) = capture_with_env(ryl, scenario.envs);
+
+        let mut yam = build_yamllint_command(scenario.yam_format);
+        yam.arg("-c").arg(&cfg).arg(&file);
+        let (yam_code, yam_msg) = capture_with_env(yam, scenario.envs);
+
+        assert_eq!(ryl_code, 1, "ryl exit ({})", scenario.label);
+        assert_eq!(yam_code, 1, "yamllint exit ({})", scenario.label);
+        assert_eq!(
+            ryl_msg, yam_msg,
+            "leading comment diagnostics mismatch ({})",
+            scenario.label
+        );
+    }
+}
+
+#[test]
+fn stream_without_documents_matches_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("require-both.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  document-start: {present: true}\n  document-end: {present: true}\n",
+    )
+    .unwrap();
+    let inputs = [
+        ("empty.yaml", ""),
+        ("blank.yaml", "\n\n"),
+        ("comment.yaml", "# only a comment\n"),
+    ];
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for (name, content) in inputs {
+        let file = dir.path().join(name);
+        fs::write(&file, content).unwrap();
+        for scenario in SCENARIOS {
+            let mut ryl = build_ryl_command(exe, scenario.ryl_format);
+            ryl.arg("-c").arg(&cfg).arg(&file);
+            let (ryl_code, ryl_msg) = capture_with_env(ryl, scenario.envs);
+
+            let mut yam = build_yamllint_command(scenario.yam_format);
+            yam.arg("-c").arg(&cfg).arg(&file);
+            let (yam_code, yam_msg) = capture_with_env(yam, scenario.envs);
+
+            assert_eq!(ryl_code, 0, "ryl {name} exit ({})", scenario.label);
+            assert_eq!(yam_code, 0, "yamllint {name} exit ({})", scenario.label);
+            assert_eq!(
+                ryl_msg, yam_msg,
+                "{name} diagnostics mismatch ({})",
+                scenario.label
+            );
+        }
+    }
+}
@@ -115,3 +115,128 @@ fn empty_lines_rule_matches_yamllint() {
         }
     }
 }
+
+#[test]
+fn trailing_blanks_after_document_end_match_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("config.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  document-end: enable\n  empty-lines: {max-end: 0}\n  new-line-at-end-of-file: enable\n",
+    )
+    .unwrap();
+    let inputs = [
+        ("two", "---\nkey: value\n...\n\n\n"),
+        ("one", "---\nkey: value\n...\n\n"),
+        ("between", "---\na: 1\n...\n\n\n---\nb: 2\n...\n\n"),
+    ];
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for (label, content) in inputs {
+        let file = dir.path().join(format!("{label}.yaml"));
+        fs::write(&file, content).unwrap();
+        for scenario in SCENARIOS {
+            let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+            ryl_cmd.arg("-c").arg(&cfg).arg(&file);
+            let (ryl_code, ryl_output) = capture_with_env(ryl_cmd, scenario.envs);
+
+            let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+            yam_cmd.arg("-c").arg(&cfg).arg(&file);
+            let (yam_code, yam_output) = capture_with_env(yam_cmd, scenario.envs);
+
+            assert_eq!(
+                ryl_code, yam_code,
+                "exit mismatch ({}/{label})",
+                scenario.label
+            );
+            assert_eq!(
+                ryl_output, yam_output,
+                "diagnostics mismatch ({}/{label})",
+                scenario.label
+            );
+        }
+    }
+}
+
+#[test]
+fn single_final_newline_is_not_a_blank_line_like_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("config.yml");
+    fs::write(&cfg, "rules:\n  empty-lines: {max-end: 0}\n").unwrap();
+    let inputs = [
+        ("no-newline", "key: v"),
+        ("newline", "key: v\n"),
+        ("one-blank", "key: v\n\n"),
+        ("one-blank-crlf", "key: v\r\n\r\n"),
+    ];
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for (label, content) in inputs {
+        let file = dir.path().join(format!("{label}.yaml"));
+        fs::write(&file, content).unwrap();
+        for scenario in SCENARIOS {
+            let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+            ryl_cmd.arg("-c").arg(&cfg).arg(&file);
+            let (ryl_code, ryl_output) = capture_with_env(ryl_cmd, scenario.envs);
+
+            let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+            yam_cmd.arg("-c").arg(&cfg).arg(&file);
+            let (yam_code, yam_output) = capture_with_env(yam_cmd, scenario.envs);
+
+            assert_eq!(
+                ryl_code, yam_code,
+                "exit mismatch ({}/{label})",
+                scenario.label
+            );
+            assert_eq!(
+                ryl_output, yam_output,
+                "diagnostics mismatch ({}/{label})",
+                scenario.label
+            );
+        }
+    }
+}
+
+#[test]
+fn crlf_and_all_blank_files_match_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("config.yml");
+    fs::write(&cfg, "rules:\n  empty-lines: enable\n").unwrap();
+    let inputs = [
+        ("interior-crlf", "a: 1\r\n\r\n\r\n\r\nb: 2\r\n\r\n"),
+        ("blank-lf", "\n\n\n"),
+        ("blank-crlf", "\r\n\r\n\r\n"),
+    ];
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for (label, content) in inputs {
+        let file = dir.path().join(format!("{label}.yaml"));
+        fs::write(&file, content).unwrap();
+        for scenario in SCENARIOS {
+            let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+            ryl_cmd.arg("-c").arg(&cfg).arg(&file);
+            let (ryl_code, ryl_output) = capture_with_env(ryl_cmd, scenario.envs);
+
+            let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+            yam_cmd.arg("-c").arg(&cfg).arg(&file);
+            let (yam_code, yam_output) = capture_with_env(yam_cmd, scenario.envs);
+
+            assert_eq!(
+                ryl_code, yam_code,
+                "exit mismatch ({}/{label})",
+                scenario.label
+            );
+            assert_eq!(
+                ryl_output, yam_output,
+                "diagnostics mismatch ({}/{label})",
+                scenario.label
+            );
+        }
+    }
+}
@@ -177,3 +177,79 @@ fn empty_values_rule_matches_yamllint() {
         );
     }
 }
+
+#[test]
+fn empty_value_with_trailing_comment_matches_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("empty-block.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  document-start: disable\n  empty-values:\n    forbid-in-block-mappings: true\n",
+    )
+    .unwrap();
+    let input = dir.path().join("commented.yaml");
+    fs::write(
+        &input,
+        "key: # note\nnested:\n  inner:  # note\nset: # note\n  value\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for scenario in SCENARIOS {
+        let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+        ryl_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+        let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+        yam_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+        assert_eq!(ryl_code, 1, "ryl exit ({})", scenario.label);
+        assert_eq!(yam_code, 1, "yamllint exit ({})", scenario.label);
+        assert_eq!(
+            ryl_msg, yam_msg,
+            "commented empty value diagnostics mismatch ({})",
+            scenario.label
+        );
+    }
+}
+
+#[test]
+fn empty_values_edge_cases_match_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let file = dir.path().join("edges.yaml");
+    fs::write(
+        &file,
+        "flow: {a: , b:   }\nseq:\n  - \n  -   # note\n  - - \n  - x\nlast:",
+    )
+    .unwrap();
+    let cfg = dir.path().join("config.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  document-start: disable\n  empty-values: enable\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for scenario in SCENARIOS {
+        let mut ryl = build_ryl_command(exe, scenario.ryl_format);
+        ryl.arg("-c").arg(&cfg).arg(&file);
+        let (ryl_code, ryl_msg) = capture_with_env(ryl, scenario.envs);
+
+        let mut yam = build_yamllint_command(scenario.yam_format);
+        yam.arg("-c").arg(&cfg).arg(&file);
+        let (yam_code, yam_msg) = capture_with_env(yam, scenario.envs);
+
+        assert_eq!(ryl_code, 1, "ryl exit ({})", scenario.label);
+        assert_eq!(yam_code, 1, "yamllint exit ({})", scenario.label);
+        assert_eq!(
+            ryl_msg, yam_msg,
+            "diagnostics mismatch ({})",
+            scenario.label
+        );
+    }
+}
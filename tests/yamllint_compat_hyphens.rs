@@ -221,3 +221,46 @@ fn hyphens_rule_matches_yamllint() {
         );
     }
 }
+
+#[test]
+fn nested_sequences_and_scalar_dashes_match_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("hyphens.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  document-start: disable\n  hyphens: enable\n",
+    )
+    .unwrap();
+
+    let input = dir.path().join("nested.yaml");
+    fs::write(
+        &input,
+        "- -  a\n  -   b\n-  - c\n- block: |\n    -    literal\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for scenario in SCENARIOS {
+        let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+        ryl_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+        let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+        yam_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+        assert_eq!(ryl_code, 1, "ryl exit ({})", scenario.label);
+        assert_eq!(
+            ryl_code, yam_code,
+            "nested exit mismatch ({})",
+            scenario.label
+        );
+        assert_eq!(
+            ryl_msg, yam_msg,
+            "nested diagnostics mismatch ({})",
+            scenario.label
+        );
+    }
+}
@@ -312,6 +312,51 @@ fn root_sequence_of_inline_mappings_matches_yamllint() {
     }
 }
 
+#[test]
+fn root_sequence_under_each_indent_sequences_setting_matches_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let yaml_path = dir.path().join("input.yaml");
+    fs::write(&yaml_path, "- a\n- - b\n  - c\n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+
+    for setting in ["true", "false", "whatever", "consistent"] {
+        let cfg_path = dir.path().join(format!("cfg-{setting}.yaml"));
+        fs::write(
+            &cfg_path,
+            format!(
+                "rules:\n  document-start: disable\n  indentation:\n    \
+                 indent-sequences: {setting}\n"
+            ),
+        )
+        .unwrap();
+
+        for scenario in SCENARIOS {
+            let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+            ryl_cmd.arg("-c").arg(&cfg_path).arg(&yaml_path);
+            let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+            let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+            yam_cmd.arg("-c").arg(&cfg_path).arg(&yaml_path);
+            let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+            assert_eq!(
+                ryl_code, yam_code,
+                "exit mismatch ({setting}, {})",
+                scenario.label
+            );
+            assert_eq!(ryl_code, 0, "ryl should accept a root sequence ({setting})");
+            assert_eq!(
+                ryl_msg, yam_msg,
+                "output mismatch ({setting}, {})",
+                scenario.label
+            );
+        }
+    }
+}
+
 #[test]
 fn sequence_entry_mapping_nested_sequence_matches_yamllint() {
     ensure_yamllint_installed();
@@ -477,3 +522,186 @@ fn multiline_double_quoted_template_indentation_matches_yamllint() {
         );
     }
 }
+
+#[test]
+fn blank_lines_between_siblings_match_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+
+    let cfg_path = dir.path().join("cfg.yaml");
+    fs::write(
+        &cfg_path,
+        "rules:\n  document-start: disable\n  indentation:\n    spaces: 2\n",
+    )
+    .unwrap();
+
+    let yaml_path = dir.path().join("input.yaml");
+    fs::write(
+        &yaml_path,
+        "root:\n  first: 1\n\n  nested:\n\n    - a\n\n    - b:\n\n        c: d\n\n  note: plain\n\n   continued\n\n  bad:\n\n     deep: 1\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+
+    for scenario in SCENARIOS {
+        let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+        ryl_cmd.arg("-c").arg(&cfg_path).arg(&yaml_path);
+        let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+        let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+        yam_cmd.arg("-c").arg(&cfg_path).arg(&yaml_path);
+        let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+        assert_eq!(yam_code, 1, "yamllint exit ({})", scenario.label);
+        assert_eq!(ryl_code, 1, "ryl exit ({})\n{}", scenario.label, ryl_msg);
+        assert_eq!(ryl_msg, yam_msg, "output mismatch ({})", scenario.label);
+    }
+}
+
+#[test]
+fn explicit_key_indentation_matches_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg_path = dir.path().join("cfg.yaml");
+    fs::write(
+        &cfg_path,
+        "rules:\n  document-start: disable\n  indentation:\n    spaces: 4\n",
+    )
+    .unwrap();
+
+    let inputs = [
+        (
+            "clean",
+            "? a: 1\n  b: 2\n: c: 3\n  d: 4\nlist:\n    - ? - x\n        - y\n      : z\n",
+        ),
+        ("over", "? a: 1\n  b:\n     c: 1\n: d\n"),
+    ];
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for (label, content) in inputs {
+        let yaml_path = dir.path().join(format!("{label}.yaml"));
+        fs::write(&yaml_path, content).unwrap();
+        for scenario in SCENARIOS {
+            let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+            ryl_cmd.arg("-c").arg(&cfg_path).arg(&yaml_path);
+            let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+            let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+            yam_cmd.arg("-c").arg(&cfg_path).arg(&yaml_path);
+            let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+            assert_eq!(
+                ryl_code, yam_code,
+                "exit mismatch ({}/{label})",
+                scenario.label
+            );
+            assert_eq!(
+                ryl_msg, yam_msg,
+                "output mismatch ({}/{label})",
+                scenario.label
+            );
+        }
+    }
+}
+
+#[test]
+fn multi_line_string_message_matches_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg_path = dir.path().join("cfg.yaml");
+    fs::write(
+        &cfg_path,
+        "rules:\n  document-start: disable\n  indentation:\n    spaces: 4\n    check-multi-line-strings: true\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for (label, indicator) in [("literal", "|"), ("folded", ">")] {
+        let yaml_path = dir.path().join(format!("{label}.yaml"));
+        fs::write(
+            &yaml_path,
+            format!("quote: {indicator}\n    good\n     bad\n"),
+        )
+        .unwrap();
+        for scenario in SCENARIOS {
+            let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+            ryl_cmd.arg("-c").arg(&cfg_path).arg(&yaml_path);
+            let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+            let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+            yam_cmd.arg("-c").arg(&cfg_path).arg(&yaml_path);
+            let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+            assert!(
+                ryl_msg.contains("wrong indentation: expected 4 but found 5"),
+                "({}/{label}): {ryl_msg}",
+                scenario.label
+            );
+            assert_eq!(
+                ryl_code, yam_code,
+                "exit mismatch ({}/{label})",
+                scenario.label
+            );
+            assert_eq!(
+                ryl_msg, yam_msg,
+                "output mismatch ({}/{label})",
+                scenario.label
+            );
+        }
+    }
+}
+
+#[test]
+fn comment_between_key_and_nested_block_matches_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg_path = dir.path().join("cfg.yaml");
+    fs::write(
+        &cfg_path,
+        "rules:\n  document-start: disable\n  indentation:\n    spaces: consistent\n  comments-indentation: enable\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for (label, yaml, expected_code) in [
+        ("aligned", "parent:\n  # note\n  child: 1\n", 0),
+        ("under", "parent:\n# note\n  child: 1\n", 1),
+        ("over", "parent:\n    # note\n  child: 1\n", 1),
+        ("deeper-child", "parent:\n  # note\n    child: 1\n", 1),
+        ("sequence", "parent:\n  # note\n\n  - 1\n", 0),
+        ("nested", "a:\n  # c\n  b:\n    # d\n    c: 1\n", 0),
+    ] {
+        let yaml_path = dir.path().join(format!("{label}.yaml"));
+        fs::write(&yaml_path, yaml).unwrap();
+        for scenario in SCENARIOS {
+            let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+            ryl_cmd.arg("-c").arg(&cfg_path).arg(&yaml_path);
+            let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+            let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+            yam_cmd.arg("-c").arg(&cfg_path).arg(&yaml_path);
+            let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+            assert_eq!(
+                ryl_code, expected_code,
+                "ryl exit ({}/{label}): {ryl_msg}",
+                scenario.label
+            );
+            assert_eq!(
+                ryl_code, yam_code,
+                "exit mismatch ({}/{label})",
+                scenario.label
+            );
+            assert_eq!(
+                ryl_msg, yam_msg,
+                "output mismatch ({}/{label})",
+                scenario.label
+            );
+        }
+    }
+}
@@ -137,3 +137,75 @@ fn key_duplicates_rule_matches_yamllint() {
         );
     }
 }
+
+#[test]
+fn three_occurrences_report_the_later_two_like_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("key-duplicates.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  document-start: disable\n  key-duplicates: enable\n",
+    )
+    .unwrap();
+    let input = dir.path().join("triple.yaml");
+    fs::write(&input, "dup: 1\nother: 2\ndup: 3\ndup: 4\n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for scenario in SCENARIOS {
+        let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+        ryl_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+        let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+        yam_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+        assert_eq!(ryl_code, 1, "ryl exit ({})", scenario.label);
+        assert_eq!(yam_code, 1, "yamllint exit ({})", scenario.label);
+        assert_eq!(
+            ryl_msg, yam_msg,
+            "triple duplicate diagnostics mismatch ({})",
+            scenario.label
+        );
+    }
+}
+
+#[test]
+fn nested_and_flow_mappings_track_keys_independently_like_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("key-duplicates.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  document-start: disable\n  key-duplicates: enable\n",
+    )
+    .unwrap();
+    let input = dir.path().join("nested.yaml");
+    fs::write(
+        &input,
+        "a:\n  x: 1\n  a: 2\nb:\n  x: 3\n  y: {x: 4, x: 5}\nc: [{k: 1}, {k: 2, k: 3}]\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for scenario in SCENARIOS {
+        let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+        ryl_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+        let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+        yam_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+        assert_eq!(ryl_code, 1, "ryl exit ({})", scenario.label);
+        assert_eq!(yam_code, 1, "yamllint exit ({})", scenario.label);
+        assert_eq!(
+            ryl_msg, yam_msg,
+            "nested mapping diagnostics mismatch ({})",
+            scenario.label
+        );
+    }
+}
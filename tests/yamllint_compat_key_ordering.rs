@@ -59,6 +59,13 @@ fn key_ordering_matches_yamllint() {
     )
     .unwrap();
 
+    let merge_file = dir.path().join("merge.yaml");
+    fs::write(
+        &merge_file,
+        "base: &base {x: 1}\nleading:\n  <<: *base\n  alpha: 1\n  beta: 2\ninterleaved:\n  alpha: 1\n  <<: *base\n  beta: 2\n",
+    )
+    .unwrap();
+
     let locale_file = dir.path().join("locale.yaml");
     fs::write(
         &locale_file,
@@ -141,6 +148,29 @@ fn key_ordering_matches_yamllint() {
             scenario.label
         );
 
+        // the merge key is ordered like any other key: flagged only when interleaved
+        let mut ryl_merge = build_ryl_command(exe, scenario.ryl_format);
+        ryl_merge.arg("-c").arg(&default_cfg).arg(&merge_file);
+        let (ryl_merge_code, ryl_merge_msg) =
+            capture_with_env(ryl_merge, scenario.envs);
+
+        let mut yam_merge = build_yamllint_command(scenario.yam_format);
+        yam_merge.arg("-c").arg(&default_cfg).arg(&merge_file);
+        let (yam_merge_code, yam_merge_msg) =
+            capture_with_env(yam_merge, scenario.envs);
+
+        assert_eq!(ryl_merge_code, 1, "ryl merge exit ({})", scenario.label);
+        assert_eq!(
+            yam_merge_code, 1,
+            "yamllint merge exit ({})",
+            scenario.label
+        );
+        assert_eq!(
+            ryl_merge_msg, yam_merge_msg,
+            "merge key diagnostics mismatch ({})",
+            scenario.label
+        );
+
         if skip_locale {
             continue;
         }
@@ -168,3 +198,43 @@ fn key_ordering_matches_yamllint() {
         );
     }
 }
+
+#[test]
+fn anchored_tagged_and_alias_keys_match_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("key-ordering.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  document-start: disable\n  key-ordering: enable\n",
+    )
+    .unwrap();
+    let input = dir.path().join("props.yaml");
+    fs::write(
+        &input,
+        "a-ordered:\n  &z alpha: 1\n  !!str beta: 2\n  !custom &y gamma: 3\n\
+         b-unordered:\n  !!str &a beta: 1\n  &b alpha: 2\n  flow: {!!str d: 1, &c c: 2}\n\
+         c-aliased:\n  &k b: 1\n  c: 2\n  *k : 3\n  d: 4\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for scenario in SCENARIOS {
+        let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+        ryl_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+        let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+        yam_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+        assert_eq!(ryl_code, 1, "ryl exit ({})", scenario.label);
+        assert_eq!(yam_code, 1, "yamllint exit ({})", scenario.label);
+        assert_eq!(
+            ryl_msg, yam_msg,
+            "anchored/tagged key diagnostics mismatch ({})",
+            scenario.label
+        );
+    }
+}
@@ -373,3 +373,138 @@ fn line_length_disable_line_is_consumed_by_blank_line() {
         );
     }
 }
+
+#[test]
+fn long_trailing_comment_with_non_breakable_words_matches_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("line-20.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  line-length:\n    max: 20\n    allow-non-breakable-words: true\n",
+    )
+    .unwrap();
+
+    let input = dir.path().join("trailing-comment.yaml");
+    fs::write(
+        &input,
+        "key: value # http://example.com/super/long/url/with/no/spaces\n\
+         # http://example.com/super/long/url/with/no/spaces\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+
+    for scenario in SCENARIOS {
+        let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+        ryl_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+        let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+        yam_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+        assert_eq!(ryl_code, 1, "ryl exit ({})", scenario.label);
+        assert_eq!(
+            ryl_code, yam_code,
+            "exit mismatch for trailing comment ({})",
+            scenario.label
+        );
+        assert_eq!(
+            ryl_msg, yam_msg,
+            "diagnostics mismatch for trailing comment ({})",
+            scenario.label
+        );
+    }
+}
+
+#[test]
+fn column_is_max_plus_one_in_characters_like_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("line-10.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  document-start: disable\n  line-length:\n    max: 10\n",
+    )
+    .unwrap();
+
+    // An 11-character ASCII line, then 10 and 11 characters of two-byte text.
+    let input = dir.path().join("columns.yaml");
+    fs::write(&input, "k: 1234 678\nk: éééé éé\nk: éééé ééé\n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+
+    for scenario in SCENARIOS {
+        let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+        ryl_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+        let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+        yam_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+        assert_eq!(ryl_code, 1, "ryl exit ({})", scenario.label);
+        assert_eq!(
+            ryl_code, yam_code,
+            "exit mismatch for columns ({})",
+            scenario.label
+        );
+        assert_eq!(
+            ryl_msg, yam_msg,
+            "diagnostics mismatch for columns ({})",
+            scenario.label
+        );
+    }
+}
+
+#[test]
+fn key_value_urls_match_yamllint_with_and_without_inline_mappings() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let words_cfg = dir.path().join("words.yml");
+    fs::write(&words_cfg, "rules:\n  line-length:\n    max: 40\n").unwrap();
+    let inline_cfg = dir.path().join("inline.yml");
+    fs::write(
+        &inline_cfg,
+        "rules:\n  line-length:\n    max: 40\n    allow-non-breakable-inline-mappings: true\n",
+    )
+    .unwrap();
+
+    let url = "https://example.com/a/very/long/path/that/cannot/be/broken";
+    let input = dir.path().join("urls.yaml");
+    fs::write(
+        &input,
+        format!("list:\n  - {url}\nkey: {url}\nnested:\n  key: {url}\n"),
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+
+    for (cfg, expected_code) in [(&words_cfg, 1), (&inline_cfg, 0)] {
+        for scenario in SCENARIOS {
+            let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+            ryl_cmd.arg("-c").arg(cfg).arg(&input);
+            let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+            let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+            yam_cmd.arg("-c").arg(cfg).arg(&input);
+            let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+            assert_eq!(ryl_code, expected_code, "ryl exit ({})", scenario.label);
+            assert_eq!(
+                ryl_code, yam_code,
+                "exit mismatch for key-value urls ({})",
+                scenario.label
+            );
+            assert_eq!(
+                ryl_msg, yam_msg,
+                "diagnostics mismatch for key-value urls ({})",
+                scenario.label
+            );
+        }
+    }
+}
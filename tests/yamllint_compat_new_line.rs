@@ -131,3 +131,37 @@ fn new_line_rule_matches_yamllint() {
         );
     }
 }
+
+#[test]
+fn extra_trailing_newlines_match_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("config.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  document-start: disable\n  new-line-at-end-of-file: enable\n  empty-lines: enable\n",
+    )
+    .unwrap();
+    let input = dir.path().join("blank-end.yaml");
+    fs::write(&input, "key: v\n\n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for scenario in SCENARIOS {
+        let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+        ryl_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+        let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+        yam_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+        assert_eq!(ryl_code, 1, "ryl exit ({})", scenario.label);
+        assert_eq!(yam_code, 1, "yamllint exit ({})", scenario.label);
+        assert_eq!(
+            ryl_msg, yam_msg,
+            "trailing blank line diagnostics mismatch ({})",
+            scenario.label
+        );
+    }
+}
@@ -181,3 +181,97 @@ fn quoted_strings_rule_matches_yamllint() {
         }
     }
 }
+
+#[test]
+fn option_matrix_matches_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let input = dir.path().join("matrix.yaml");
+    fs::write(
+        &input,
+        "plain: text\nsingle: 'text'\ndouble: \"text\"\n'key': 1\nurl: 'http://x'\nquoted: 'say \"hi\"'\n",
+    )
+    .unwrap();
+    let rules = [
+        "quoted-strings: enable",
+        "quoted-strings: {quote-type: double}",
+        "quoted-strings: {quote-type: double, allow-quoted-quotes: true}",
+        "quoted-strings: {required: false, quote-type: single}",
+        "quoted-strings: {required: only-when-needed}",
+        "quoted-strings: {required: only-when-needed, extra-allowed: ['^http']}",
+        "quoted-strings: {required: false, extra-required: ['^te']}",
+        "quoted-strings: {required: only-when-needed, check-keys: true}",
+    ];
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for (idx, rule) in rules.iter().enumerate() {
+        let cfg = dir.path().join(format!("matrix-{idx}.yml"));
+        fs::write(
+            &cfg,
+            format!("rules:\n  document-start: disable\n  {rule}\n"),
+        )
+        .unwrap();
+        for scenario in SCENARIOS {
+            let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+            ryl_cmd.arg("-c").arg(&cfg).arg(&input);
+            let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+            let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+            yam_cmd.arg("-c").arg(&cfg).arg(&input);
+            let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+            assert_eq!(ryl_code, 1, "ryl exit {rule} ({})", scenario.label);
+            assert_eq!(yam_code, 1, "yamllint exit {rule} ({})", scenario.label);
+            assert_eq!(
+                ryl_msg, yam_msg,
+                "diagnostics mismatch {rule} ({})",
+                scenario.label
+            );
+        }
+    }
+}
+
+#[test]
+fn only_when_needed_leading_indicators_match_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("only-needed.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  document-start: disable\n  quoted-strings:\n    required: only-when-needed\n",
+    )
+    .unwrap();
+    let values = [
+        "'- x'", "'? x'", "': x'", "',x'", "'[x'", "'{x'", "'#x'", "'&x'", "'*x'",
+        "'!x'", "'|x'", "'>x'", "\"'x\"", "'\"x'", "'%x'", "'@x'", "'`x'", "'-x'",
+        "'?x'", "':x'",
+    ];
+    let input = dir.path().join("leading.yaml");
+    let body: String = values
+        .iter()
+        .enumerate()
+        .map(|(idx, value)| format!("k{idx}: {value}\n"))
+        .collect();
+    fs::write(&input, body).unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for scenario in SCENARIOS {
+        let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+        ryl_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+        let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+        yam_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+        assert_eq!(ryl_code, 1, "ryl exit ({})", scenario.label);
+        assert_eq!(yam_code, 1, "yamllint exit ({})", scenario.label);
+        assert_eq!(
+            ryl_msg, yam_msg,
+            "leading indicator diagnostics mismatch ({})",
+            scenario.label
+        );
+    }
+}
@@ -160,3 +160,47 @@ fn trailing_spaces_rule_matches_yamllint() {
         );
     }
 }
+
+#[test]
+fn strict_and_no_warnings_match_yamllint_for_warning_only_input() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("trailing-warning.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  document-start: disable\n  trailing-spaces:\n    level: warning\n",
+    )
+    .unwrap();
+    let file = dir.path().join("warn.yaml");
+    fs::write(&file, "key: value \n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for flags in [
+        &[][..],
+        &["--strict"][..],
+        &["--no-warnings"][..],
+        &["--strict", "--no-warnings"][..],
+    ] {
+        for scenario in SCENARIOS {
+            let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+            ryl_cmd.args(flags).arg("-c").arg(&cfg).arg(&file);
+            let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+            let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+            yam_cmd.args(flags).arg("-c").arg(&cfg).arg(&file);
+            let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+            assert_eq!(
+                ryl_code, yam_code,
+                "exit mismatch {flags:?} ({})",
+                scenario.label
+            );
+            assert_eq!(
+                ryl_msg, yam_msg,
+                "output mismatch {flags:?} ({})",
+                scenario.label
+            );
+        }
+    }
+}
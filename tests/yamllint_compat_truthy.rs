@@ -44,7 +44,11 @@ fn truthy_rule_matches_yamllint() {
     fs::write(&allowed_file, "- yes\n- no\n- true\n- on\n").unwrap();
 
     let ok_file = dir.path().join("ok.yaml");
-    fs::write(&ok_file, "foo: false\nbar: true\n").unwrap();
+    fs::write(
+        &ok_file,
+        "foo: false\nbar: true\nspaced: !!str  yes\nverbatim: !<tag:yaml.org,2002:str> yes\n",
+    )
+    .unwrap();
 
     let exe = env!("CARGO_BIN_EXE_ryl");
 
@@ -128,3 +132,172 @@ fn truthy_rule_matches_yamllint() {
         );
     }
 }
+
+#[test]
+fn flow_context_truthy_columns_match_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("truthy.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  document-start: disable\n  truthy: enable\n",
+    )
+    .unwrap();
+
+    let input = dir.path().join("flow.yaml");
+    fs::write(
+        &input,
+        "flow: {enabled: yes, on: no}\nlist: [yes, no, True]\nnested: [\n  {a: off},\n]\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for scenario in SCENARIOS {
+        let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+        ryl_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+        let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+        yam_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+        assert_eq!(
+            ryl_code, yam_code,
+            "flow exit mismatch ({})",
+            scenario.label
+        );
+        assert_eq!(
+            ryl_msg, yam_msg,
+            "flow diagnostics mismatch ({})",
+            scenario.label
+        );
+    }
+}
+
+#[test]
+fn default_config_checks_truthy_keys_like_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let input = dir.path().join("on.yaml");
+    fs::write(&input, "---\non: value\n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for scenario in SCENARIOS {
+        // No `-c`: both tools fall back to their built-in default config.
+        let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+        ryl_cmd.current_dir(dir.path()).arg(&input);
+        let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+        let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+        yam_cmd.current_dir(dir.path()).arg(&input);
+        let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+        assert_eq!(
+            ryl_code, yam_code,
+            "default key exit mismatch ({})",
+            scenario.label
+        );
+        assert!(
+            ryl_msg.contains("(truthy)"),
+            "the `on` key should be flagged ({}): {ryl_msg}",
+            scenario.label
+        );
+        assert_eq!(
+            ryl_msg, yam_msg,
+            "default key diagnostics mismatch ({})",
+            scenario.label
+        );
+    }
+}
+
+#[test]
+fn custom_allowed_values_message_matches_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("truthy.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  document-start: disable\n  truthy:\n    allowed-values: [\"on\", \"True\", \"false\"]\n",
+    )
+    .unwrap();
+
+    let input = dir.path().join("values.yaml");
+    fs::write(&input, "- on\n- True\n- false\n- yes\n- Off\n").unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for scenario in SCENARIOS {
+        let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+        ryl_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+        let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+        yam_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+        assert_eq!(
+            ryl_code, yam_code,
+            "allowed-values exit mismatch ({})",
+            scenario.label
+        );
+        assert!(
+            ryl_msg.contains("truthy value should be one of [True, false, on]"),
+            "sorted allowed-values message ({}): {ryl_msg}",
+            scenario.label
+        );
+        assert_eq!(
+            ryl_msg, yam_msg,
+            "allowed-values diagnostics mismatch ({})",
+            scenario.label
+        );
+    }
+}
+
+#[test]
+fn empty_allowed_values_message_matches_yamllint() {
+    ensure_yamllint_installed();
+
+    let dir = tempdir().unwrap();
+    let cfg = dir.path().join("truthy.yml");
+    fs::write(
+        &cfg,
+        "rules:\n  document-start: disable\n  truthy:\n    allowed-values: []\n",
+    )
+    .unwrap();
+
+    let input = dir.path().join("values.yaml");
+    fs::write(
+        &input,
+        "a: yes\nb: No\nc: TRUE\nd: false\ne: on\nf: OFF\nTrue: off\n",
+    )
+    .unwrap();
+
+    let exe = env!("CARGO_BIN_EXE_ryl");
+    for scenario in SCENARIOS {
+        let mut ryl_cmd = build_ryl_command(exe, scenario.ryl_format);
+        ryl_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (ryl_code, ryl_msg) = capture_with_env(ryl_cmd, scenario.envs);
+
+        let mut yam_cmd = build_yamllint_command(scenario.yam_format);
+        yam_cmd.arg("-c").arg(&cfg).arg(&input);
+        let (yam_code, yam_msg) = capture_with_env(yam_cmd, scenario.envs);
+
+        assert_eq!(
+            ryl_code, yam_code,
+            "empty allowed-values exit mismatch ({})",
+            scenario.label
+        );
+        assert!(
+            ryl_msg.contains("truthy value should be one of []"),
+            "empty allowed-values message ({}): {ryl_msg}",
+            scenario.label
+        );
+        assert_eq!(
+            ryl_msg, yam_msg,
+            "empty allowed-values diagnostics mismatch ({})",
+            scenario.label
+        );
+    }
+}